@@ -12,7 +12,8 @@ pub const OUR_GALAXYS_CATEGORY: GalaxyCategory = GalaxyCategory::Spiral(16203, 1
 pub const OUR_GALAXYS_SUB_CATEGORY: GalaxySubCategory = GalaxySubCategory::BarredSpiral;
 /// The current age of the universe.
 pub const NO_SPECIAL_TRAIT: GalaxySpecialTrait = GalaxySpecialTrait::NoPeculiarity;
-/// TODO List of all galaxies in our local group
+/// The galaxies of our local group, with their approximate categories, sizes, ages and positions. Positions are in kiloparsecs,
+/// relative to the center of the Milky Way, with the Sun lying on the negative side of the x axis.
 pub const LOCAL_GROUP_GALAXIES: [GalaxyWithoutTraits; 38] = [
     GalaxyWithoutTraits {
         index: 0,
@@ -25,23 +26,33 @@ pub const LOCAL_GROUP_GALAXIES: [GalaxyWithoutTraits; 38] = [
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
     },
     GalaxyWithoutTraits {
         index: 1,
         name: "Andromeda",
-        age: -1.0,
+        age: 13.0,
         is_dominant: false,
         is_major: true,
         category: GalaxyCategory::Spiral(23280, 230),
-        sub_category: OUR_GALAXYS_SUB_CATEGORY,
-        first_trait: NO_SPECIAL_TRAIT,
+        sub_category: GalaxySubCategory::ClassicSpiral,
+        first_trait: GalaxySpecialTrait::ExtendedHalo,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -376.4,
+            y: 608.7,
+            z: -281.2,
+        },
     },
     GalaxyWithoutTraits {
         index: 2,
         name: "Triangulum",
-        age: -1.0,
+        age: 12.0,
         is_dominant: false,
         is_major: false,
         category: GalaxyCategory::Spiral(9370, 94),
@@ -49,426 +60,606 @@ pub const LOCAL_GROUP_GALAXIES: [GalaxyWithoutTraits; 38] = [
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -503.1,
+            y: 519.5,
+            z: -436.8,
+        },
     },
     GalaxyWithoutTraits {
         index: 3,
-        name: "TODO",
-        age: -1.0,
+        name: "Large Magellanic Cloud",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
-        second_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Spiral(4300, 43),
+        sub_category: GalaxySubCategory::DwarfSpiral,
+        first_trait: GalaxySpecialTrait::Interacting,
+        second_trait: GalaxySpecialTrait::Tail,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -0.6,
+            y: -41.3,
+            z: -27.2,
+        },
     },
     GalaxyWithoutTraits {
         index: 4,
-        name: "TODO",
-        age: -1.0,
+        name: "Small Magellanic Cloud",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(3500, 2500, 2000),
         sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
-        second_trait: NO_SPECIAL_TRAIT,
-        third_trait: NO_SPECIAL_TRAIT,
+        first_trait: GalaxySpecialTrait::Interacting,
+        second_trait: GalaxySpecialTrait::Tail,
+        third_trait: GalaxySpecialTrait::GasRich,
+        position: NeighborhoodCoordinates {
+            x: 15.8,
+            y: -37.3,
+            z: -43.3,
+        },
     },
     GalaxyWithoutTraits {
         index: 5,
-        name: "TODO",
-        age: -1.0,
+        name: "Sagittarius Dwarf Spheroidal",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
-        second_trait: NO_SPECIAL_TRAIT,
-        third_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(2600),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::Interacting,
+        second_trait: GalaxySpecialTrait::Tail,
+        third_trait: GalaxySpecialTrait::GasPoor,
+        position: NeighborhoodCoordinates {
+            x: 16.9,
+            y: 2.4,
+            z: -6.4,
+        },
     },
     GalaxyWithoutTraits {
         index: 6,
-        name: "TODO",
-        age: -1.0,
+        name: "Ursa Minor Dwarf",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(600),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -22.1,
+            y: 52.1,
+            z: 53.6,
+        },
     },
     GalaxyWithoutTraits {
         index: 7,
-        name: "TODO",
-        age: -1.0,
+        name: "Draco Dwarf",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(500),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -4.2,
+            y: 62.3,
+            z: 43.3,
+        },
     },
     GalaxyWithoutTraits {
         index: 8,
-        name: "TODO",
-        age: -1.0,
+        name: "Sculptor Dwarf",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(700),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -5.1,
+            y: -9.8,
+            z: -85.4,
+        },
     },
     GalaxyWithoutTraits {
         index: 9,
-        name: "TODO",
-        age: -1.0,
+        name: "Sextans Dwarf",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(1500),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -36.6,
+            y: -57.0,
+            z: 57.8,
+        },
     },
     GalaxyWithoutTraits {
         index: 10,
-        name: "TODO",
-        age: -1.0,
+        name: "Carina Dwarf",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(550),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -24.9,
+            y: -95.8,
+            z: -39.7,
+        },
     },
     GalaxyWithoutTraits {
         index: 11,
-        name: "TODO",
-        age: -1.0,
+        name: "Fornax Dwarf",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(1400),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -41.1,
+            y: -50.9,
+            z: -133.9,
+        },
     },
     GalaxyWithoutTraits {
         index: 12,
-        name: "TODO",
-        age: -1.0,
+        name: "Leo I",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(500),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -123.7,
+            y: -119.6,
+            z: 192.0,
+        },
     },
     GalaxyWithoutTraits {
         index: 13,
-        name: "TODO",
-        age: -1.0,
+        name: "Leo II",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(300),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -77.1,
+            y: -58.2,
+            z: 214.8,
+        },
     },
     GalaxyWithoutTraits {
         index: 14,
-        name: "TODO",
-        age: -1.0,
+        name: "Bootes I",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(500),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: 14.8,
+            y: -0.8,
+            z: 61.9,
+        },
     },
     GalaxyWithoutTraits {
         index: 15,
-        name: "TODO",
-        age: -1.0,
+        name: "Canes Venatici I",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(1100),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: 2.2,
+            y: 37.1,
+            z: 214.6,
+        },
     },
     GalaxyWithoutTraits {
         index: 16,
-        name: "TODO",
-        age: -1.0,
+        name: "M32",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(3250),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::Compact(150),
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -374.2,
+            y: 605.5,
+            z: -285.6,
+        },
     },
     GalaxyWithoutTraits {
         index: 17,
-        name: "TODO",
-        age: -1.0,
+        name: "M110",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(2700),
+        sub_category: GalaxySubCategory::DwarfElliptical,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -400.8,
+            y: 660.7,
+            z: -297.2,
+        },
     },
     GalaxyWithoutTraits {
         index: 18,
-        name: "TODO",
-        age: -1.0,
+        name: "NGC 185",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(1500),
+        sub_category: GalaxySubCategory::DwarfElliptical,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -314.0,
+            y: 513.2,
+            z: -154.3,
+        },
     },
     GalaxyWithoutTraits {
         index: 19,
-        name: "TODO",
-        age: -1.0,
+        name: "NGC 147",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(1600),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -334.0,
+            y: 568.4,
+            z: -166.4,
+        },
     },
     GalaxyWithoutTraits {
         index: 20,
-        name: "TODO",
-        age: -1.0,
+        name: "Andromeda I",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(1000),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -354.8,
+            y: 561.5,
+            z: -305.2,
+        },
     },
     GalaxyWithoutTraits {
         index: 21,
-        name: "TODO",
-        age: -1.0,
+        name: "Andromeda II",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(1200),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -365.5,
+            y: 443.3,
+            z: -317.7,
+        },
     },
     GalaxyWithoutTraits {
         index: 22,
-        name: "TODO",
-        age: -1.0,
+        name: "Andromeda III",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(700),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -337.6,
+            y: 584.4,
+            z: -330.8,
+        },
     },
     GalaxyWithoutTraits {
         index: 23,
-        name: "TODO",
-        age: -1.0,
+        name: "Andromeda V",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(500),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -449.1,
+            y: 602.0,
+            z: -201.6,
+        },
     },
     GalaxyWithoutTraits {
         index: 24,
-        name: "TODO",
-        age: -1.0,
+        name: "Cassiopeia Dwarf",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(900),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -258.2,
+            y: 707.7,
+            z: -131.5,
+        },
     },
     GalaxyWithoutTraits {
         index: 25,
-        name: "TODO",
-        age: -1.0,
+        name: "IC 10",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(1600, 1600, 1200),
         sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        first_trait: GalaxySpecialTrait::Starburst,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -392.1,
+            y: 693.5,
+            z: -46.1,
+        },
     },
     GalaxyWithoutTraits {
         index: 26,
-        name: "TODO",
-        age: -1.0,
+        name: "LGS 3",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(400, 400, 300),
         sub_category: GalaxySubCategory::DwarfAmorphous,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -356.2,
+            y: 465.7,
+            z: -503.3,
+        },
     },
     GalaxyWithoutTraits {
         index: 27,
-        name: "TODO",
-        age: -1.0,
+        name: "Pegasus Dwarf Spheroidal",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(800),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -182.2,
+            y: 606.6,
+            z: -463.5,
+        },
     },
     GalaxyWithoutTraits {
         index: 28,
-        name: "TODO",
-        age: -1.0,
+        name: "Barnard's Galaxy",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(2300, 2300, 1700),
         sub_category: GalaxySubCategory::DwarfAmorphous,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: 385.4,
+            y: 186.4,
+            z: -144.9,
+        },
     },
     GalaxyWithoutTraits {
         index: 29,
-        name: "TODO",
-        age: -1.0,
+        name: "IC 1613",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(2200, 2200, 1600),
         sub_category: GalaxySubCategory::DwarfAmorphous,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -246.2,
+            y: 286.4,
+            z: -660.3,
+        },
     },
     GalaxyWithoutTraits {
         index: 30,
-        name: "TODO",
-        age: -1.0,
+        name: "Wolf-Lundmark-Melotte",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(3400, 1200, 1000),
         sub_category: GalaxySubCategory::DwarfAmorphous,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: 56.1,
+            y: 255.1,
+            z: -895.1,
+        },
     },
     GalaxyWithoutTraits {
         index: 31,
-        name: "TODO",
-        age: -1.0,
+        name: "Leo A",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(1400, 800, 700),
         sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        first_trait: GalaxySpecialTrait::GasRich,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -473.9,
+            y: -141.5,
+            z: 632.4,
+        },
     },
     GalaxyWithoutTraits {
         index: 32,
-        name: "TODO",
-        age: -1.0,
+        name: "Pegasus Dwarf Irregular",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(1100, 600, 500),
         sub_category: GalaxySubCategory::DwarfAmorphous,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -63.6,
+            y: 664.5,
+            z: -633.9,
+        },
     },
     GalaxyWithoutTraits {
         index: 33,
-        name: "TODO",
-        age: -1.0,
+        name: "Phoenix Dwarf",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(500, 500, 400),
         sub_category: GalaxySubCategory::DwarfAmorphous,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -2.6,
+            y: -149.0,
+            z: -387.3,
+        },
     },
     GalaxyWithoutTraits {
         index: 34,
-        name: "TODO",
-        age: -1.0,
+        name: "Tucana Dwarf",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(400),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: 471.0,
+            y: -362.3,
+            z: -652.6,
+        },
     },
     GalaxyWithoutTraits {
         index: 35,
-        name: "TODO",
-        age: -1.0,
+        name: "Cetus Dwarf",
+        age: 13.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
-        sub_category: GalaxySubCategory::DwarfAmorphous,
-        first_trait: NO_SPECIAL_TRAIT,
+        is_major: false,
+        category: GalaxyCategory::Elliptical(800),
+        sub_category: GalaxySubCategory::DwarfElliptical,
+        first_trait: GalaxySpecialTrait::GasPoor,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: -52.4,
+            y: 218.2,
+            z: -721.4,
+        },
     },
     GalaxyWithoutTraits {
         index: 36,
-        name: "TODO",
-        age: -1.0,
+        name: "Aquarius Dwarf",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(500, 300, 300),
         sub_category: GalaxySubCategory::DwarfAmorphous,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: 750.3,
+            y: 512.6,
+            z: -557.7,
+        },
     },
     GalaxyWithoutTraits {
         index: 37,
-        name: "TODO",
-        age: -1.0,
+        name: "Sagittarius Dwarf Irregular",
+        age: 12.0,
         is_dominant: false,
-        is_major: true,
-        category: GalaxyCategory::Irregular(10, 10, 10),
+        is_major: false,
+        category: GalaxyCategory::Irregular(700, 500, 400),
         sub_category: GalaxySubCategory::DwarfAmorphous,
         first_trait: NO_SPECIAL_TRAIT,
         second_trait: NO_SPECIAL_TRAIT,
         third_trait: NO_SPECIAL_TRAIT,
+        position: NeighborhoodCoordinates {
+            x: 947.6,
+            y: 368.0,
+            z: -299.3,
+        },
     },
 ];
 
@@ -495,6 +686,8 @@ pub struct GalaxyWithoutTraits<'a> {
     pub second_trait: GalaxySpecialTrait,
     /// The third peculiarity this galaxy has, if any.
     pub third_trait: GalaxySpecialTrait,
+    /// The position of this galaxy in its neighborhood.
+    pub position: NeighborhoodCoordinates,
 }
 /// Pairs traits that are incompatible with one another.
 #[derive(
@@ -531,3 +724,108 @@ pub fn get_opposite_traits() -> Vec<OppositeTraits> {
         ),
     ]
 }
+
+/// The highest number of the Messier-like catalog, which lists the most prominent galaxies.
+pub const MAJOR_CATALOG_SIZE: u16 = 110;
+/// The highest number of the NGC-like catalog, which lists the rest of the notable galaxies.
+pub const MINOR_CATALOG_SIZE: u16 = 7840;
+/// The highest number of the IC-like catalog, which lists the faintest and most diffuse galaxies.
+pub const FAINT_CATALOG_SIZE: u16 = 5386;
+
+/// Adjectives used to build the proper names of galaxies.
+pub const GALAXY_NAME_ADJECTIVES: [&str; 24] = [
+    "Silver",
+    "Black",
+    "Crimson",
+    "Pale",
+    "Burning",
+    "Silent",
+    "Hidden",
+    "Golden",
+    "Azure",
+    "Ashen",
+    "Frozen",
+    "Wandering",
+    "Broken",
+    "Ancient",
+    "Whispering",
+    "Shattered",
+    "Sleeping",
+    "Restless",
+    "Radiant",
+    "Veiled",
+    "Lonely",
+    "Scarlet",
+    "Emerald",
+    "Sapphire",
+];
+
+/// Returns the nouns used to build the proper name of a galaxy of the given category and sub-category.
+pub fn get_galaxy_name_nouns(
+    category: GalaxyCategory,
+    sub_category: GalaxySubCategory,
+) -> Vec<&'static str> {
+    match category {
+        GalaxyCategory::Spiral(_, _) => match sub_category {
+            GalaxySubCategory::DwarfSpiral => vec!["Eddy", "Swirl", "Curl", "Whorl", "Ripple"],
+            GalaxySubCategory::FlatSpiral => vec!["Needle", "Blade", "Ribbon", "Wake", "Pinwheel"],
+            GalaxySubCategory::BarredSpiral => vec!["Wheel", "Spindle", "Cross", "Crest", "Helm"],
+            _ => vec![
+                "Whirlpool",
+                "Pinwheel",
+                "Sunflower",
+                "Cartwheel",
+                "Vortex",
+                "Maelstrom",
+            ],
+        },
+        GalaxyCategory::Lenticular(_, _) => vec!["Sombrero", "Lens", "Disc", "Saucer", "Spindle"],
+        GalaxyCategory::Elliptical(_) => vec!["Eye", "Lantern", "Pearl", "Beacon", "Orb", "Egg"],
+        GalaxyCategory::DominantElliptical(_) => vec!["Crown", "Throne", "Colossus", "Titan"],
+        GalaxyCategory::Intergalactic(_, _, _)
+        | GalaxyCategory::Irregular(_, _, _)
+        | GalaxyCategory::Intracluster(_, _, _) => {
+            vec![
+                "Cloud", "Veil", "Cigar", "Mist", "Shoal", "Drift", "Tadpole", "Ember",
+            ]
+        }
+    }
+}
+
+/// Syllable onsets used to generate the names of galactic map divisions.
+pub const DIVISION_NAME_ONSETS: [&str; 20] = [
+    "", "b", "d", "g", "k", "l", "m", "n", "r", "s", "t", "v", "z", "sh", "th", "kh", "dr", "gr",
+    "tr", "vl",
+];
+/// Syllable nuclei used to generate the names of galactic map divisions.
+pub const DIVISION_NAME_NUCLEI: [&str; 9] = ["a", "e", "i", "o", "u", "a", "ae", "ia", "ou"];
+/// Syllable codas used to generate the names of galactic map divisions.
+pub const DIVISION_NAME_CODAS: [&str; 12] =
+    ["", "", "", "n", "r", "l", "s", "sh", "g", "k", "th", "m"];
+/// Adjectives used in the names of sectors.
+pub const SECTOR_NAME_ADJECTIVES: [&str; 12] = [
+    "Spinward", "Trailing", "Rimward", "Coreward", "Old", "Far", "Lost", "Outer", "Inner", "Dark",
+    "Great", "Silent",
+];
+/// Nouns used in the names of sectors.
+pub const SECTOR_NAME_NOUNS: [&str; 12] = [
+    "Marches",
+    "Reach",
+    "Rim",
+    "Expanses",
+    "Frontier",
+    "Drift",
+    "Verge",
+    "Gulf",
+    "Abyss",
+    "Main",
+    "Hinterworlds",
+    "Deep",
+];
+/// Nouns used in the names of divisions bigger than sectors, from the smallest to the biggest.
+pub const BIG_DIVISION_NAME_NOUNS: [&str; 7] = [
+    "Province", "Domain", "Dominion", "Realm", "Expanse", "Reaches", "Heart",
+];
+
+/// The level of [GalacticMapDivision]s for which nebulae are generated.
+pub const NEBULA_DIVISION_LEVEL: u8 = 2;
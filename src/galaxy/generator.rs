@@ -10,6 +10,37 @@ impl Galaxy {
         neighborhood: GalacticNeighborhood,
         index: u16,
         settings: &GenerationSettings,
+    ) -> Self {
+        Self::generate_with_hosts(neighborhood, index, None, settings)
+    }
+
+    /// Generates every [Galaxy] of the given neighborhood using the given seed and [GenerationSettings], only working out once which
+    /// major galaxies minor ones may orbit.
+    pub fn generate_all(
+        neighborhood: GalacticNeighborhood,
+        settings: &GenerationSettings,
+    ) -> Vec<Self> {
+        let number_of_galaxies = match neighborhood.density {
+            GalacticNeighborhoodDensity::Void(major, minor)
+            | GalacticNeighborhoodDensity::Group(major, minor) => major as u16 + minor,
+            GalacticNeighborhoodDensity::Cluster(dominant, major, minor) => {
+                dominant as u16 + major as u16 + minor
+            }
+        };
+        let host_satellites = get_host_satellites(neighborhood, &settings.seed, settings);
+        (0..number_of_galaxies)
+            .map(|index| {
+                Self::generate_with_hosts(neighborhood, index, Some(&host_satellites), settings)
+            })
+            .collect()
+    }
+
+    /// Generates a brand new [Galaxy], using the given **host_satellites** if they were already worked out for its neighborhood.
+    fn generate_with_hosts(
+        neighborhood: GalacticNeighborhood,
+        index: u16,
+        host_satellites: Option<&[Option<GalaxySatellites>]>,
+        settings: &GenerationSettings,
     ) -> Self {
         let seed = &settings.seed.clone();
         debug!(
@@ -23,6 +54,7 @@ impl Galaxy {
         let mut category;
         let sub_category;
         let special_traits;
+        let position;
 
         if settings.galaxy.use_ours && (index as usize) < LOCAL_GROUP_GALAXIES.len() {
             // Our universe and galaxy
@@ -37,44 +69,58 @@ impl Galaxy {
             };
             category = model.category;
             sub_category = model.sub_category;
-            special_traits = if model.third_trait != NO_SPECIAL_TRAIT {
-                vec![model.first_trait, model.second_trait, model.third_trait]
-            } else if model.second_trait != NO_SPECIAL_TRAIT {
-                vec![model.first_trait, model.second_trait]
-            } else {
-                vec![model.first_trait]
-            };
+            special_traits = get_special_traits_of_model(&model);
+            position = model.position;
         } else {
             // Generated galaxy
-            name = "Galaxy".into();
-            is_dominant = is_galaxy_dominant(neighborhood, index);
-            is_major = is_galaxy_major(neighborhood, index);
-            age = generate_age(neighborhood, index, seed, settings);
-            category = generate_category(
-                neighborhood,
-                index,
-                age,
+            GalaxyNature {
                 is_dominant,
                 is_major,
-                seed,
-                settings,
-            );
-            sub_category = generate_sub_category(category, index, age, is_major, seed, settings);
-            if settings.galaxy.fixed_category.is_none() {
-                category = get_category_with_size(category, sub_category, index, seed);
-            }
-            special_traits = generate_special_traits(
-                neighborhood,
+                age,
                 category,
                 sub_category,
+                special_traits,
+            } = generate_nature(neighborhood, index, seed, settings);
+            name = generate_name(
+                neighborhood,
                 index,
+                category,
+                sub_category,
+                is_dominant,
+                is_major,
                 seed,
-                settings,
             );
+            position = if is_major {
+                generate_position(
+                    neighborhood,
+                    index,
+                    is_dominant,
+                    is_major,
+                    &[],
+                    seed,
+                    settings,
+                )
+            } else {
+                let host_satellites = host_satellites.map_or_else(
+                    || get_host_satellites(neighborhood, seed, settings),
+                    |host_satellites| host_satellites.to_vec(),
+                );
+                generate_position(
+                    neighborhood,
+                    index,
+                    is_dominant,
+                    is_major,
+                    &host_satellites,
+                    seed,
+                    settings,
+                )
+            };
         }
+        let shape =
+            GalacticShape::generate(category, sub_category, &special_traits, index, settings);
         let division_levels = GalacticMapDivisionLevel::generate_division_levels(settings);
 
-        let galaxy = Self {
+        let mut galaxy = Self {
             settings: settings.clone(),
             neighborhood,
             index,
@@ -85,15 +131,220 @@ impl Galaxy {
             category,
             sub_category,
             special_traits,
+            position,
+            shape,
+            sub_regions: vec![],
             division_levels,
             divisions: vec![],
             hexes: vec![],
+            star_catalog: None,
+            pinned_systems: Vec::new(),
         };
+        galaxy.sub_regions = GalacticSubRegion::generate(&galaxy);
+        let sol = StarSystem::sol();
+        if settings.system.use_ours && index == 0 && sol.is_accepted_by(&settings.system) {
+            // Our own solar system only lies once in the first galaxy, every other system being generated as usual
+            galaxy
+                .pin_system(galaxy.get_home_coordinates(), PinnedSystem::System(sol))
+                .expect("The home coordinates should lie within the galaxy.");
+        }
         debug!("generated: {}", galaxy);
         galaxy
     }
 }
 
+/// Generates the position of a [Galaxy] in its neighborhood. Dominant galaxies lie near the center, major ones are scattered through
+/// the neighborhood and minor ones are either wandering on their own or orbiting a major galaxy, which is more or less likely to be
+/// picked as a host depending on its [GalaxySatellites] trait, as found in **host_satellites** for every major galaxy.
+fn generate_position(
+    neighborhood: GalacticNeighborhood,
+    index: u16,
+    is_dominant: bool,
+    is_major: bool,
+    host_satellites: &[Option<GalaxySatellites>],
+    seed: &Rc<str>,
+    settings: &GenerationSettings,
+) -> NeighborhoodCoordinates {
+    let mut rng = SeededDiceRoller::new(seed, &format!("gal_{}_pos", index));
+    let radius = match neighborhood.density {
+        GalacticNeighborhoodDensity::Void(_, _) => 5000.0,
+        GalacticNeighborhoodDensity::Group(_, _) => 1500.0,
+        GalacticNeighborhoodDensity::Cluster(_, _, _) => 3000.0,
+    };
+
+    if is_dominant {
+        return get_random_point_in_sphere(radius * 0.02, &mut rng);
+    } else if is_major {
+        let spread = if let GalacticNeighborhoodDensity::Cluster(_, _, _) = neighborhood.density {
+            radius
+        } else {
+            radius / 2.0
+        };
+        return get_random_point_in_sphere(spread, &mut rng);
+    }
+
+    let mut possible_hosts = vec![CopyableWeightedResult {
+        result: None,
+        weight: 4,
+    }];
+    for (host_index, satellites) in host_satellites.iter().enumerate() {
+        possible_hosts.push(CopyableWeightedResult {
+            result: Some(host_index as u16),
+            weight: match satellites {
+                Some(GalaxySatellites::MuchMore) => 24,
+                Some(GalaxySatellites::More) => 12,
+                Some(GalaxySatellites::Special) | None => 6,
+                Some(GalaxySatellites::Less) => 3,
+                Some(GalaxySatellites::MuchLess) => 1,
+                Some(GalaxySatellites::None) => 0,
+            },
+        });
+    }
+    let host = rng
+        .get_result(&CopyableRollToProcess {
+            possible_results: possible_hosts,
+            roll_method: RollMethod::SimpleRoll,
+        })
+        .expect("Should pick a host or none.");
+
+    if let Some(host_index) = host {
+        let host_position =
+            if settings.galaxy.use_ours && (host_index as usize) < LOCAL_GROUP_GALAXIES.len() {
+                LOCAL_GROUP_GALAXIES[host_index as usize].position
+            } else {
+                generate_position(
+                    neighborhood,
+                    host_index,
+                    is_galaxy_dominant(neighborhood, host_index),
+                    true,
+                    &[],
+                    seed,
+                    settings,
+                )
+            };
+        // Satellites orbit between 10 and 300 kiloparsecs from their host
+        let offset = get_random_point_in_sphere(1.0, &mut rng);
+        let length = (offset.x * offset.x + offset.y * offset.y + offset.z * offset.z)
+            .sqrt()
+            .max(0.01);
+        let distance = rng.roll(1, 291, 9) as f32;
+        NeighborhoodCoordinates::new(
+            host_position.x + offset.x / length * distance,
+            host_position.y + offset.y / length * distance,
+            host_position.z + offset.z / length * distance,
+        )
+    } else {
+        get_random_point_in_sphere(radius, &mut rng)
+    }
+}
+
+/// Returns the [GalaxySatellites] trait of every major galaxy of the given neighborhood, if they have one.
+fn get_host_satellites(
+    neighborhood: GalacticNeighborhood,
+    seed: &Rc<str>,
+    settings: &GenerationSettings,
+) -> Vec<Option<GalaxySatellites>> {
+    let number_of_major_galaxies = match neighborhood.density {
+        GalacticNeighborhoodDensity::Void(major, _)
+        | GalacticNeighborhoodDensity::Group(major, _) => major as u16,
+        GalacticNeighborhoodDensity::Cluster(dominant, major, _) => dominant as u16 + major as u16,
+    };
+    (0..number_of_major_galaxies)
+        .map(|host_index| {
+            get_special_traits_of_galaxy(neighborhood, host_index, seed, settings)
+                .iter()
+                .find_map(|special_trait| match special_trait {
+                    GalaxySpecialTrait::Satellites(satellites) => Some(*satellites),
+                    _ => None,
+                })
+        })
+        .collect()
+}
+
+/// Returns the special traits of the galaxy of the given index, without having to generate it entirely.
+fn get_special_traits_of_galaxy(
+    neighborhood: GalacticNeighborhood,
+    index: u16,
+    seed: &Rc<str>,
+    settings: &GenerationSettings,
+) -> Vec<GalaxySpecialTrait> {
+    if settings.galaxy.use_ours && (index as usize) < LOCAL_GROUP_GALAXIES.len() {
+        return get_special_traits_of_model(&LOCAL_GROUP_GALAXIES[index as usize]);
+    }
+    generate_nature(neighborhood, index, seed, settings).special_traits
+}
+
+/// Returns the special traits of a galaxy of the Local Group, leaving out the unused ones.
+fn get_special_traits_of_model(model: &GalaxyWithoutTraits) -> Vec<GalaxySpecialTrait> {
+    if model.third_trait != NO_SPECIAL_TRAIT {
+        vec![model.first_trait, model.second_trait, model.third_trait]
+    } else if model.second_trait != NO_SPECIAL_TRAIT {
+        vec![model.first_trait, model.second_trait]
+    } else {
+        vec![model.first_trait]
+    }
+}
+
+/// What a generated [Galaxy] is made of, before it gets a name, a position and a shape.
+struct GalaxyNature {
+    is_dominant: bool,
+    is_major: bool,
+    age: f32,
+    category: GalaxyCategory,
+    sub_category: GalaxySubCategory,
+    special_traits: Vec<GalaxySpecialTrait>,
+}
+
+/// Generates whether the galaxy of the given index is dominant and major, along with its age, category, sub-category and special
+/// traits.
+fn generate_nature(
+    neighborhood: GalacticNeighborhood,
+    index: u16,
+    seed: &Rc<str>,
+    settings: &GenerationSettings,
+) -> GalaxyNature {
+    let is_dominant = is_galaxy_dominant(neighborhood, index);
+    let is_major = is_galaxy_major(neighborhood, index);
+    let age = generate_age(neighborhood, index, seed, settings);
+    let mut category = generate_category(
+        neighborhood,
+        index,
+        age,
+        is_dominant,
+        is_major,
+        seed,
+        settings,
+    );
+    let sub_category = generate_sub_category(category, index, age, is_major, seed, settings);
+    if settings.galaxy.fixed_category.is_none() {
+        category = get_category_with_size(category, sub_category, index, seed);
+    }
+    let special_traits =
+        generate_special_traits(neighborhood, category, sub_category, index, seed, settings);
+    GalaxyNature {
+        is_dominant,
+        is_major,
+        age,
+        category,
+        sub_category,
+        special_traits,
+    }
+}
+
+/// Returns a random point within a sphere of the given radius centered on (0, 0, 0).
+fn get_random_point_in_sphere(radius: f32, rng: &mut SeededDiceRoller) -> NeighborhoodCoordinates {
+    loop {
+        let (x, y, z) = (
+            rng.gen_range(-1.0..=1.0_f32),
+            rng.gen_range(-1.0..=1.0_f32),
+            rng.gen_range(-1.0..=1.0_f32),
+        );
+        if x * x + y * y + z * z <= 1.0 {
+            return NeighborhoodCoordinates::new(x * radius, y * radius, z * radius);
+        }
+    }
+}
+
 /// Generates an age to use in a [Galaxy] while following the given [GenerationSettings].
 fn generate_age(
     neighborhood: GalacticNeighborhood,
@@ -1301,6 +1552,65 @@ fn get_number_of_random_traits(index: u16, seed: &Rc<str>) -> i32 {
     number_of_random_traits
 }
 
+/// Generates the name of a [Galaxy]. Every galaxy gets a catalog designation, Messier-like for the major ones, NGC-like for the
+/// others and IC-like for the faintest. Prominent galaxies might also get an evocative proper name, followed by their designation
+/// between parentheses, like "Silver Spindle (NGC 1234)".
+fn generate_name(
+    neighborhood: GalacticNeighborhood,
+    index: u16,
+    category: GalaxyCategory,
+    sub_category: GalaxySubCategory,
+    is_dominant: bool,
+    is_major: bool,
+    seed: &Rc<str>,
+) -> Rc<str> {
+    let mut rng = SeededDiceRoller::new(seed, &format!("gal_{}_nam", index));
+    let (major_galaxies, all_galaxies) = match neighborhood.density {
+        GalacticNeighborhoodDensity::Void(major, minor)
+        | GalacticNeighborhoodDensity::Group(major, minor) => (major as u16, major as u16 + minor),
+        GalacticNeighborhoodDensity::Cluster(dominant, major, minor) => (
+            dominant as u16 + major as u16,
+            dominant as u16 + major as u16 + minor,
+        ),
+    };
+    let is_faint = matches!(
+        category,
+        GalaxyCategory::Intergalactic(_, _, _) | GalaxyCategory::Intracluster(_, _, _)
+    ) || sub_category == GalaxySubCategory::DwarfAmorphous;
+
+    // Numbers are spread in steps of the neighborhood's size so that two of its galaxies never share the same designation
+    let (catalog, catalog_size, step) = if is_dominant || is_major {
+        ("M", MAJOR_CATALOG_SIZE, major_galaxies.max(1))
+    } else if is_faint {
+        ("IC", FAINT_CATALOG_SIZE, all_galaxies.max(1))
+    } else {
+        ("NGC", MINOR_CATALOG_SIZE, all_galaxies.max(1))
+    };
+    let steps = (catalog_size / step).max(1) as u32;
+    let number = index as u32 + 1 + step as u32 * (rng.roll(1, steps, -1) as u32);
+    let designation = format!("{} {}", catalog, number);
+
+    let has_proper_name = if is_dominant {
+        true
+    } else if is_major {
+        rng.roll(1, 3, 0) > 1
+    } else {
+        rng.roll(1, 6, 0) == 6
+    };
+    if has_proper_name {
+        let nouns = get_galaxy_name_nouns(category, sub_category);
+        format!(
+            "{} {} ({})",
+            GALAXY_NAME_ADJECTIVES[rng.gen_usize() % GALAXY_NAME_ADJECTIVES.len()],
+            nouns[rng.gen_usize() % nouns.len()],
+            designation
+        )
+        .into()
+    } else {
+        designation.into()
+    }
+}
+
 /// Retreives an age to use in [Galaxy] generation from the given [GenerationSettings].
 fn get_fixed_age(settings: &GenerationSettings) -> f32 {
     let fixed_age = if settings.galaxy.fixed_age.is_some() {
@@ -1345,6 +1655,133 @@ fn is_galaxy_major(neighborhood: GalacticNeighborhood, index: u16) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn generate_galaxies_with_unique_names() {
+        for i in 0..20 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                galaxy: GalaxySettings {
+                    fixed_neighborhood: Some(GalacticNeighborhoodDensity::Cluster(1, 8, 30)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(settings), settings);
+            let mut designations = Vec::new();
+            for index in 0..39 {
+                let galaxy = Galaxy::generate(neighborhood, index, settings);
+                assert!(galaxy.name.len() > 0);
+                let designation = match galaxy.name.split_once(" (") {
+                    Some((_, designation)) => designation
+                        .strip_suffix(')')
+                        .expect("Should end the designation with a parenthesis."),
+                    None => {
+                        assert!(!galaxy.is_dominant);
+                        &galaxy.name
+                    }
+                }
+                .to_string();
+                assert!(
+                    designation.starts_with("M ")
+                        || designation.starts_with("NGC ")
+                        || designation.starts_with("IC ")
+                );
+                assert!(!designations.contains(&designation));
+                designations.push(designation);
+            }
+        }
+    }
+
+    #[test]
+    fn generate_our_local_group() {
+        let settings = &GenerationSettings {
+            universe: UniverseSettings {
+                use_ours: true,
+                ..Default::default()
+            },
+            galaxy: GalaxySettings {
+                use_ours: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(settings), settings);
+        let milky_way = Galaxy::generate(neighborhood, 0, settings);
+        let andromeda = Galaxy::generate(neighborhood, 1, settings);
+        let large_magellanic_cloud = Galaxy::generate(neighborhood, 3, settings);
+        assert_eq!(milky_way.name, "Milky Way".into());
+        assert_eq!(milky_way.position, NeighborhoodCoordinates::default());
+        assert_eq!(andromeda.name, "Andromeda".into());
+        assert_eq!(andromeda.sub_category, GalaxySubCategory::ClassicSpiral);
+        assert!(andromeda.position.x < -300.0 && andromeda.position.y > 500.0);
+        assert_eq!(large_magellanic_cloud.name, "Large Magellanic Cloud".into());
+        assert!(large_magellanic_cloud.position.y.abs() < 100.0);
+
+        for index in 0..LOCAL_GROUP_GALAXIES.len() as u16 {
+            let galaxy = Galaxy::generate(neighborhood, index, settings);
+            assert_ne!(galaxy.name, "TODO".into());
+            assert!(galaxy.age > 0.0 && galaxy.age < neighborhood.universe.age);
+            assert_eq!(galaxy.is_major, index < 2);
+        }
+    }
+
+    #[test]
+    fn generate_galaxies_with_sensible_positions() {
+        for i in 0..20 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                galaxy: GalaxySettings {
+                    fixed_neighborhood: Some(GalacticNeighborhoodDensity::Cluster(1, 4, 20)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(settings), settings);
+            let galaxies = Galaxy::generate_all(neighborhood, settings);
+            let center = NeighborhoodCoordinates::default();
+            assert!(galaxies[0].position.distance_to(&center) <= 60.0);
+            for galaxy in galaxies.iter() {
+                assert!(galaxy.position.distance_to(&center) <= 3300.0);
+            }
+        }
+    }
+
+    #[test]
+    fn place_satellites_around_their_hosts() {
+        let settings = &GenerationSettings {
+            galaxy: GalaxySettings {
+                fixed_neighborhood: Some(GalacticNeighborhoodDensity::Group(1, 40)),
+                fixed_special_traits: Some(vec![GalaxySpecialTrait::Satellites(
+                    GalaxySatellites::MuchMore,
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(settings), settings);
+        let galaxies = Galaxy::generate_all(neighborhood, settings);
+        assert_eq!(galaxies.len(), 41);
+        assert_eq!(
+            galaxies[5].position,
+            Galaxy::generate(neighborhood, 5, settings).position
+        );
+        let host = &galaxies[0];
+        let satellites = galaxies
+            .iter()
+            .filter(|galaxy| galaxy.index != 0 && host.distance_to(galaxy) <= 300.0)
+            .count();
+        assert!(satellites >= 20);
+
+        let nearest = host.get_nearest_galaxies(&galaxies, 10);
+        assert_eq!(nearest.len(), 10);
+        assert!(nearest.iter().all(|galaxy| galaxy.index != 0));
+        for pair in nearest.windows(2) {
+            assert!(host.distance_to(pair[0]) <= host.distance_to(pair[1]));
+        }
+    }
+
     #[test]
     fn generate_a_galaxy_with_sensible_age() {
         for i in 0..10000 {
@@ -1,6 +1,9 @@
 use crate::internal::*;
 use crate::prelude::*;
 use std::collections::HashMap;
+#[path = "../../constants.rs"]
+mod constants;
+use constants::*;
 
 impl GalacticMapDivision {
     pub fn generate(
@@ -10,53 +13,153 @@ impl GalacticMapDivision {
         galaxy: &Galaxy,
     ) -> Self {
         let mut division = Self {
-            name: "GalaxyDivision".into(),
+            name: "".into(),
             region: GalacticRegion::Multiple,
             level,
             x: (index.x % parent_division_level.x_subdivisions as i64) as u8,
             y: (index.y % parent_division_level.y_subdivisions as i64) as u8,
             z: (index.z % parent_division_level.z_subdivisions as i64) as u8,
             index,
+            nebulae: vec![],
             size: SpaceCoordinates::new(-1, -1, -1),
         };
         division.region = get_region(&mut division, galaxy);
+        division.name = generate_name(&division, parent_division_level, galaxy);
+        if level == NEBULA_DIVISION_LEVEL {
+            division.nebulae = GalacticNebula::generate(&mut division, galaxy);
+        }
         division
     }
 }
 
+/// Generates the name of the given division, using the [DivisionNameSource] of the [SectorSettings] if there is one. Otherwise,
+/// hexes are numbered using their column and row in their sector, subsectors get a letter according to their cell in their sector
+/// and sectors or bigger divisions get a procedurally generated name.
+fn generate_name(
+    division: &GalacticMapDivision,
+    parent_division_level: &GalacticMapDivisionLevel,
+    galaxy: &Galaxy,
+) -> Rc<str> {
+    if let Some(name_source) = &galaxy.settings.sector.division_name_source {
+        if let Some(name) = name_source.get_name(
+            division.level,
+            (division.x, division.y, division.z),
+            division.index,
+            &galaxy.settings.seed,
+        ) {
+            return name;
+        }
+    }
+
+    match division.level {
+        0 => {
+            // Like in Traveller, hexes are numbered across their whole sector rather than their subsector.
+            let sector_size = galaxy
+                .division_levels
+                .iter()
+                .filter(|l| l.level == 1 || l.level == 2)
+                .fold(SpaceCoordinates::new(1, 1, 1), |size, l| {
+                    size * l.as_coord()
+                });
+            let layer = if sector_size.z > 1 {
+                format!("-{:02}", division.index.z % sector_size.z + 1)
+            } else {
+                String::new()
+            };
+            format!(
+                "{:02}{:02}{}",
+                division.index.x % sector_size.x + 1,
+                division.index.y % sector_size.y + 1,
+                layer
+            )
+            .into()
+        }
+        1 => {
+            let layer = if parent_division_level.z_subdivisions > 1 {
+                format!("-{:02}", division.z + 1)
+            } else {
+                String::new()
+            };
+            let cell =
+                division.y as u32 * parent_division_level.x_subdivisions as u32 + division.x as u32;
+            format!("{}{}", get_letters(cell), layer).into()
+        }
+        _ => generate_big_division_name(division.level, division.index, &galaxy.settings.seed),
+    }
+}
+
+/// Returns the letters matching the given cell number, A being 0, Z being 25, AA being 26 and so on.
+fn get_letters(cell: u32) -> String {
+    let letter = (b'A' + (cell % 26) as u8) as char;
+    if cell < 26 {
+        letter.to_string()
+    } else {
+        format!("{}{}", get_letters(cell / 26 - 1), letter)
+    }
+}
+
+/// Generates a Traveller-like name for a sector or any bigger division.
+fn generate_big_division_name(level: u8, index: SpaceCoordinates, seed: &str) -> Rc<str> {
+    let mut rng = SeededDiceRoller::new(seed, &format!("div_{}_{}_nam", level, index));
+    let word = generate_word(&mut rng);
+    if level > 2 {
+        let noun =
+            BIG_DIVISION_NAME_NOUNS[((level - 3) as usize).min(BIG_DIVISION_NAME_NOUNS.len() - 1)];
+        return format!("{} of {}", noun, word).into();
+    }
+
+    match rng.roll(1, 4, 0) {
+        1 => format!(
+            "{} {}",
+            SECTOR_NAME_ADJECTIVES[rng.gen_usize() % SECTOR_NAME_ADJECTIVES.len()],
+            SECTOR_NAME_NOUNS[rng.gen_usize() % SECTOR_NAME_NOUNS.len()]
+        )
+        .into(),
+        2 => format!(
+            "{} {}",
+            word,
+            SECTOR_NAME_NOUNS[rng.gen_usize() % SECTOR_NAME_NOUNS.len()]
+        )
+        .into(),
+        _ => word.into(),
+    }
+}
+
+/// Generates a capitalized word made of two or three syllables.
+fn generate_word(rng: &mut SeededDiceRoller) -> String {
+    let mut word = String::new();
+    for _ in 0..rng.roll(1, 2, 1) {
+        word.push_str(DIVISION_NAME_ONSETS[rng.gen_usize() % DIVISION_NAME_ONSETS.len()]);
+        word.push_str(DIVISION_NAME_NUCLEI[rng.gen_usize() % DIVISION_NAME_NUCLEI.len()]);
+        word.push_str(DIVISION_NAME_CODAS[rng.gen_usize() % DIVISION_NAME_CODAS.len()]);
+    }
+    let mut letters = word.chars();
+    match letters.next() {
+        Some(first) => first.to_uppercase().chain(letters).collect(),
+        None => word,
+    }
+}
+
+/// Samples the corners, edges' middles and center of the given division to find which [GalacticRegion] it belongs to.
 fn get_region(division: &mut GalacticMapDivision, galaxy: &Galaxy) -> GalacticRegion {
     let start = division.get_top_left_up(galaxy);
-    let size = division.get_size(galaxy);
-    let half_size = size / SpaceCoordinates::new(2, 2, 2);
+    let last_parsec = division.get_size(galaxy) - SpaceCoordinates::new(1, 1, 1);
     let mut region_count = HashMap::new();
+    let sub_regions: Vec<&GalacticSubRegion> = galaxy
+        .sub_regions
+        .iter()
+        .filter(|sub_region| sub_region.overlaps(start, start + last_parsec))
+        .collect();
 
     for xi in 0..3 {
-        let x = if xi == 0 {
-            start.x
-        } else if xi == 1 {
-            half_size.x
-        } else {
-            size.x
-        };
         for yi in 0..3 {
-            let y = if yi == 0 {
-                start.y
-            } else if yi == 1 {
-                half_size.y
-            } else {
-                size.y
-            };
             for zi in 0..3 {
-                let z = if zi == 0 {
-                    start.z
-                } else if zi == 1 {
-                    half_size.z
-                } else {
-                    size.z
-                };
+                let point = start
+                    + last_parsec * SpaceCoordinates::new(xi, yi, zi)
+                        / SpaceCoordinates::new(2, 2, 2);
 
                 // Finds which region this point belongs to and remembers it
-                let point_region = generate_region(SpaceCoordinates::new(x, y, z), galaxy);
+                let point_region = generate_region(point, &sub_regions, galaxy);
                 *region_count.entry(point_region).or_insert(0) += 1;
             }
         }
@@ -71,48 +174,88 @@ fn get_region(division: &mut GalacticMapDivision, galaxy: &Galaxy) -> GalacticRe
     }
 }
 
-/// Returns the proper region for a given coordinate.
-/// TODO: Generate regions properly
-fn generate_region(coord: SpaceCoordinates, galaxy: &Galaxy) -> GalacticRegion {
-    let spheroid_sizes = match galaxy.category {
-        GalaxyCategory::Spiral(r, _) | GalaxyCategory::Lenticular(r, _) => {
-            SpaceCoordinates::new(r as i64 * 2, r as i64 * 2, (r as i64 * 2) / 10)
-        }
-        GalaxyCategory::Elliptical(r) | GalaxyCategory::DominantElliptical(r) => {
-            SpaceCoordinates::new(r as i64 * 2, r as i64 * 2, r as i64 * 2)
-        }
-        _ => return GalacticRegion::Multiple,
-    };
-
-    let abs_coord = coord.abs(galaxy.get_galactic_start());
-    if is_within_sphere_in_non_equal_planes(abs_coord, spheroid_sizes, galaxy) {
-        GalacticRegion::Ellipse
-    } else {
-        GalacticRegion::Void
-    }
-}
-
-/// Returns true it the given point is within the area the given galaxy (that must be a spheroid).
-fn is_within_sphere_in_non_equal_planes(
+/// Returns the proper region for a given coordinate, checking the given sub-regions before the shape of the galaxy.
+fn generate_region(
     coord: SpaceCoordinates,
-    sizes: SpaceCoordinates,
+    sub_regions: &[&GalacticSubRegion],
     galaxy: &Galaxy,
-) -> bool {
-    let biggest_size = sizes.x.max(sizes.y).max(sizes.z);
-    let scaled_point = SpaceCoordinates {
-        x: coord.x * biggest_size / sizes.x,
-        y: coord.y * biggest_size / sizes.y,
-        z: coord.z * biggest_size / sizes.z,
-    };
-    let center = galaxy.get_galactic_center();
-
-    is_within_sphere(scaled_point, center, biggest_size)
+) -> GalacticRegion {
+    sub_regions
+        .iter()
+        .find(|sub_region| sub_region.contains(coord))
+        .map(|sub_region| sub_region.region)
+        .unwrap_or_else(|| galaxy.shape.get_region(galaxy.category, coord))
 }
 
-/// Returns true if the given point is within the area of the sphere whose center and radius are given in parameters.
-fn is_within_sphere(point: SpaceCoordinates, center: SpaceCoordinates, radius: i64) -> bool {
-    i64::pow(point.x - center.x, 2)
-        + i64::pow(point.y - center.y, 2)
-        + i64::pow(point.z - center.z, 2)
-        <= i64::pow(radius, 2)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_divisions_using_their_level_and_position() {
+        let settings = &GenerationSettings {
+            galaxy: GalaxySettings {
+                fixed_neighborhood: Some(GalacticNeighborhoodDensity::Group(1, 0)),
+                fixed_category: Some(GalaxyCategory::Spiral(1000, 100)),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(settings), settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, settings);
+        let start = galaxy.get_galactic_start();
+        let mut letters = Vec::new();
+        for x in 0..4 {
+            for y in 0..4 {
+                let coord = SpaceCoordinates::new(x * 10 + 3, y * 10 + 4, 0).rel(start);
+                let divisions = galaxy.get_divisions_for_coord(coord).unwrap();
+                assert_eq!(
+                    divisions[0].name,
+                    format!("{:02}{:02}", x * 10 + 4, y * 10 + 5).into()
+                );
+                letters.push(divisions[1].name.to_string());
+                assert!(divisions[2].name.len() > 0);
+                assert!(divisions[3].name.starts_with("Province of "));
+            }
+        }
+        letters.sort();
+        assert_eq!(
+            letters,
+            vec!["A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P"]
+        );
+
+        let other_galaxy = &mut Galaxy::generate(neighborhood, 0, settings);
+        let coord = SpaceCoordinates::new(0, 0, 0);
+        assert_eq!(
+            galaxy.get_division_at_level(coord, 2).unwrap().name,
+            other_galaxy.get_division_at_level(coord, 2).unwrap().name
+        );
+    }
+
+    #[test]
+    fn name_divisions_using_the_given_name_source() {
+        let sector_prefix: Rc<str> = "Sector".into();
+        let settings = &GenerationSettings {
+            sector: SectorSettings {
+                division_name_source: Some(DivisionNameSource::new(move |level, _, index, _| {
+                    if level == 2 {
+                        Some(format!("{} {}", sector_prefix, index).into())
+                    } else {
+                        None
+                    }
+                })),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(settings), settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, settings);
+        let coord = galaxy.get_galactic_start();
+        let divisions = galaxy.get_divisions_for_coord(coord).unwrap();
+        assert_eq!(divisions[1].name, "A".into());
+        assert_eq!(
+            divisions[2].name,
+            format!("Sector {}", SpaceCoordinates::new(0, 0, 0)).into()
+        );
+    }
 }
@@ -22,6 +22,9 @@ pub struct GalacticMapDivision {
     pub z: u8,
     /// The index of this division on the x, y and z axis.
     pub index: SpaceCoordinates,
+    /// The nebulae whose center lies inside this division. Only generated for divisions at the nebula level, see
+    /// [Galaxy::get_nebulae_near].
+    pub nebulae: Vec<GalacticNebula>,
     /// This division's size in parsecs
     size: SpaceCoordinates,
 }
@@ -45,26 +48,24 @@ impl GalacticMapDivision {
             y,
             z,
             index,
+            nebulae: vec![],
             size: SpaceCoordinates::new(-1, -1, -1),
         }
     }
 
     /// Returns the top up left coordinates of this division.
     pub fn get_top_left_up(&self, galaxy: &Galaxy) -> SpaceCoordinates {
-        self.index.rel(galaxy.get_galactic_start())
+        (self.index * self.calculate_size(galaxy)).rel(galaxy.get_galactic_start())
     }
 
     /// Returns the center coordinates of this division.
     pub fn get_center(&mut self, galaxy: &Galaxy) -> SpaceCoordinates {
-        self.get_top_left_up(galaxy)
-            + (self.get_bottom_right_down(galaxy) - self.get_top_left_up(galaxy))
-                .abs(galaxy.get_galactic_start())
-                / SpaceCoordinates::new(2, 2, 2)
+        self.get_top_left_up(galaxy) + self.get_size(galaxy) / SpaceCoordinates::new(2, 2, 2)
     }
 
     /// Returns the bottom down right coordinates of this division.
     pub fn get_bottom_right_down(&mut self, galaxy: &Galaxy) -> SpaceCoordinates {
-        self.index.rel(galaxy.get_galactic_start()) + self.get_size(galaxy)
+        self.get_top_left_up(galaxy) + self.get_size(galaxy)
     }
 
     /// Returns the full width of this division in parsecs.
@@ -85,22 +86,29 @@ impl GalacticMapDivision {
     /// Returns the full size of this division in parsecs.
     pub fn get_size(&mut self, galaxy: &Galaxy) -> SpaceCoordinates {
         if self.size.x < 1 || self.size.y < 1 || self.size.z < 1 {
-            let mut size = SpaceCoordinates::new(1, 1, 1);
-            galaxy
-                .division_levels
-                .clone()
-                .iter()
-                .filter(|l| l.level <= self.level)
-                .for_each(|l| {
-                    size = size
-                        * SpaceCoordinates::new(
-                            l.x_subdivisions as i64,
-                            l.y_subdivisions as i64,
-                            l.z_subdivisions as i64,
-                        );
-                });
-            self.size = size;
+            self.size = self.calculate_size(galaxy);
         }
         self.size
     }
+
+    /// Returns the size of this division in parsecs, using the cached value if there is one.
+    fn calculate_size(&self, galaxy: &Galaxy) -> SpaceCoordinates {
+        if self.size.x >= 1 && self.size.y >= 1 && self.size.z >= 1 {
+            return self.size;
+        }
+        let mut size = SpaceCoordinates::new(1, 1, 1);
+        galaxy
+            .division_levels
+            .iter()
+            .filter(|l| l.level <= self.level)
+            .for_each(|l| {
+                size = size
+                    * SpaceCoordinates::new(
+                        l.x_subdivisions as i64,
+                        l.y_subdivisions as i64,
+                        l.z_subdivisions as i64,
+                    );
+            });
+        size
+    }
 }
@@ -5,7 +5,7 @@ impl GalacticMapDivisionLevel {
     /// Returns a list of [GalacticMapDivisionLevel], one for each level, using the given settings.
     pub fn generate_division_levels(settings: &GenerationSettings) -> Vec<Self> {
         let mut division_levels = Vec::new();
-        let sector_settings = settings.sector;
+        let sector_settings = &settings.sector;
         let flat_map = sector_settings.flat_map;
         division_levels.push(Self {
             level: 0,
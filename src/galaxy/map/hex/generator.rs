@@ -3,20 +3,77 @@ use crate::prelude::*;
 
 impl GalacticHex {
     /// Generates the [GalacticHex] at the given coordinates.
-    pub fn generate(coord: SpaceCoordinates, index: SpaceCoordinates, galaxy: &mut Galaxy) -> Self {
+    pub fn generate(
+        coord: SpaceCoordinates,
+        index: SpaceCoordinates,
+        galaxy: &mut Galaxy,
+    ) -> Result<Self, Rc<str>> {
         debug!(
             "generating new hex (seed: {}, coord: {})",
             galaxy.settings.seed, coord
         );
         let contents = Vec::new();
         let neighborhood = StellarNeighborhood::generate(coord, galaxy);
+        let region = galaxy
+            .get_divisions_for_coord(coord)
+            .expect("Should have returned divisions.")
+            .iter()
+            .find(|div| div.level == 0)
+            .expect("Should have found the hex's division.")
+            .region;
         let mut generated = Self {
             index,
+            region,
             neighborhood,
             contents,
         };
 
-        let number_of_systems_to_generate = get_number_of_systems_to_generate(galaxy, index, coord);
+        if let Some(pinned_systems) = galaxy.get_pinned_systems_in_hex(index) {
+            let sub_sector = galaxy
+                .get_division_at_level(coord, 1)
+                .expect("Should return a subsector.");
+            for (i, pinned_system) in pinned_systems.iter().enumerate() {
+                generated.contents.push(match pinned_system {
+                    PinnedSystem::System(system) => system.clone(),
+                    PinnedSystem::Template(template) => StarSystem::generate_from_template(
+                        i as u16,
+                        coord,
+                        template,
+                        &generated,
+                        &sub_sector,
+                        galaxy,
+                    )?,
+                });
+            }
+            debug!("generated from pinned systems: {}", generated);
+            return Ok(generated);
+        }
+
+        if let Some(catalog_systems) = galaxy.get_catalog_systems_in_hex(index) {
+            let sub_sector = galaxy
+                .get_division_at_level(coord, 1)
+                .expect("Should return a subsector.");
+            for (i, catalog_system) in catalog_systems.iter().enumerate() {
+                generated.contents.push(StarSystem::generate_from_catalog(
+                    i as u16,
+                    coord,
+                    catalog_system,
+                    &generated,
+                    &sub_sector,
+                    galaxy,
+                ));
+            }
+            debug!("generated from catalog: {}", generated);
+            return Ok(generated);
+        }
+
+        let number_of_systems_to_generate = get_number_of_systems_to_generate(
+            galaxy,
+            coord,
+            index,
+            region,
+            &generated.neighborhood.nebulae,
+        );
         for i in 0..number_of_systems_to_generate {
             generated.contents.push(StarSystem::generate(
                 i,
@@ -26,19 +83,22 @@ impl GalacticHex {
                     .get_division_at_level(coord, 1)
                     .expect("Should return a subsector."),
                 galaxy,
-            ));
+            )?);
         }
 
         debug!("generated: {}", generated);
-        generated
+        Ok(generated)
     }
 }
 
-/// Calculates how many systems should be generated using the expected stellar distribution of the hex.
+/// Calculates how many systems should be generated using the expected stellar distribution of the hex's region. Nebulae the hex lies
+/// in hide some of its stars.
 fn get_number_of_systems_to_generate(
     galaxy: &mut Galaxy,
-    index: SpaceCoordinates,
     coord: SpaceCoordinates,
+    index: SpaceCoordinates,
+    region: GalacticRegion,
+    nebulae: &[GalacticNebula],
 ) -> u16 {
     let mut rng = SeededDiceRoller::new(&galaxy.settings.seed, &format!("hex_{}_nbr_sys", index));
     let mut number_of_systems_to_generate = 0;
@@ -53,10 +113,6 @@ fn get_number_of_systems_to_generate(
         hex_size.0 * hex_size.1 * hex_size.2
     };
 
-    let region = galaxy
-        .get_division_at_level(coord, 1)
-        .expect("Should return a subsector.")
-        .region;
     match region {
         GalacticRegion::Void => {
             to_roll = PreparedRoll::new(1, 50, 0);
@@ -96,21 +152,21 @@ fn get_number_of_systems_to_generate(
         }
     };
 
+    let density = nebulae
+        .iter()
+        .filter(|nebula| nebula.contains(coord))
+        .fold(get_density_percentage(galaxy), |density, nebula| {
+            density * (100 - nebula.density as i64) / 100
+        });
     for _ in 0..turns {
-        let roll = rng.roll_prepared(&to_roll);
-        if roll <= success_on {
-            number_of_systems_to_generate += roll;
-        }
+        number_of_systems_to_generate += roll_for_systems(&mut rng, &to_roll, success_on, density);
     }
 
     // Add a number of brown dwarfs
     rng = SeededDiceRoller::new(&galaxy.settings.seed, &format!("hex_{}_nbr_brwn", index));
     let mut number_of_brown_dwarfs = 0;
     for _ in 0..turns {
-        let roll = rng.roll_prepared(&to_roll);
-        if roll <= success_on {
-            number_of_brown_dwarfs += roll;
-        }
+        number_of_brown_dwarfs += roll_for_systems(&mut rng, &to_roll, success_on, density);
     }
     number_of_systems_to_generate += number_of_brown_dwarfs / 5;
 
@@ -120,3 +176,60 @@ fn get_number_of_systems_to_generate(
 
     number_of_systems_to_generate as u16
 }
+
+/// Returns the stellar density of the given galaxy as a percentage of what is expected for a standard galaxy of the same type.
+fn get_density_percentage(galaxy: &Galaxy) -> i64 {
+    galaxy
+        .special_traits
+        .iter()
+        .fold(100, |density, special_trait| match special_trait {
+            GalaxySpecialTrait::Compact(percentage) | GalaxySpecialTrait::Expansive(percentage) => {
+                density * *percentage as i64 / 100
+            }
+            GalaxySpecialTrait::Starburst => density * 5 / 4,
+            _ => density,
+        })
+}
+
+/// Rolls once to know how many systems should be added, then scales that number by the given **density** percentage. The fractional
+/// part of the scaled number is kept as the odds of adding one more system, so that the count is proportional to the density on
+/// average whether it is above or below 100%.
+fn roll_for_systems(
+    rng: &mut SeededDiceRoller,
+    to_roll: &PreparedRoll,
+    success_on: i64,
+    density: i64,
+) -> i64 {
+    let roll = rng.roll_prepared(to_roll);
+    if roll > success_on {
+        return 0;
+    }
+    let scaled = roll * density.max(0);
+    let remainder = scaled % 100;
+    if remainder > 0 && rng.roll(1, 100, 0) <= remainder {
+        scaled / 100 + 1
+    } else {
+        scaled / 100
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_system_counts_in_proportion_to_density() {
+        let to_roll = PreparedRoll::new(1, 10, 0);
+        let count_systems = |density: i64| -> i64 {
+            let mut rng = SeededDiceRoller::new("default", "density");
+            (0..2000)
+                .map(|_| roll_for_systems(&mut rng, &to_roll, 10, density))
+                .sum()
+        };
+        let standard = count_systems(100) as f64;
+        for density in [10, 50, 125, 200, 1000] {
+            let ratio = count_systems(density) as f64 / standard;
+            assert!((ratio - density as f64 / 100.0).abs() < density as f64 / 1000.0);
+        }
+    }
+}
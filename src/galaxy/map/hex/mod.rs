@@ -8,6 +8,8 @@ pub mod types;
 pub struct GalacticHex {
     /// The index of this hex on the x, y and z axis.
     pub index: SpaceCoordinates,
+    /// Which region of space this hex belongs to.
+    pub region: GalacticRegion,
     /// The neighborhood this hex belongs to.
     pub neighborhood: StellarNeighborhood,
     /// The star systems one can find in this hex, if any.
@@ -18,11 +20,13 @@ impl GalacticHex {
     /// Creates a new instance of [GalacticHex].
     pub fn new(
         index: SpaceCoordinates,
+        region: GalacticRegion,
         neighborhood: StellarNeighborhood,
         contents: Vec<StarSystem>,
     ) -> Self {
         Self {
             index,
+            region,
             neighborhood,
             contents,
         }
@@ -33,8 +37,9 @@ impl Display for GalacticHex {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Hex {} in {} containing {} star systems",
+            "Hex {} of the {} in {} containing {} star systems",
             self.index,
+            self.region,
             self.neighborhood,
             self.contents.len(),
         )
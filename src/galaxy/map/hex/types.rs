@@ -83,3 +83,157 @@ impl Div for SpaceCoordinates {
         }
     }
 }
+
+/// An iterator over some [GalacticHex]es of a [Galaxy]. Each hex is only generated, or fetched from the already generated ones, when
+/// the iterator reaches it, and an error is yielded in its place if it could not be generated.
+pub struct GalacticHexIterator<'a> {
+    galaxy: &'a mut Galaxy,
+    coords: std::vec::IntoIter<SpaceCoordinates>,
+}
+
+impl<'a> GalacticHexIterator<'a> {
+    /// Creates a new [GalacticHexIterator] going through the hexes found at the given coordinates.
+    pub fn new(galaxy: &'a mut Galaxy, coords: Vec<SpaceCoordinates>) -> Self {
+        Self {
+            galaxy,
+            coords: coords.into_iter(),
+        }
+    }
+}
+
+impl Iterator for GalacticHexIterator<'_> {
+    type Item = Result<GalacticHex, Rc<str>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.coords.next().map(|coord| self.galaxy.get_hex(coord))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.coords.size_hint()
+    }
+}
+
+impl ExactSizeIterator for GalacticHexIterator<'_> {}
+
+/// The position of a [GalacticHex] on a genuine hexagonal grid, using "odd-q" offset coordinates: hexes have flat tops, columns go
+/// along the x axis and rows along the y axis, and odd columns are shifted half a hex down. The column and row of a hex are the x and
+/// y values of its index.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize, Deserialize,
+)]
+pub struct HexGridCoordinates {
+    /// The column of the hex, along the x axis.
+    pub column: i64,
+    /// The row of the hex, along the y axis.
+    pub row: i64,
+}
+
+/// The six directions one can go to from a hex, in cube coordinates, each one next to the previous.
+const HEX_GRID_DIRECTIONS: [(i64, i64, i64); 6] = [
+    (1, 0, -1),
+    (0, 1, -1),
+    (-1, 1, 0),
+    (-1, 0, 1),
+    (0, -1, 1),
+    (1, -1, 0),
+];
+
+impl HexGridCoordinates {
+    /// Creates a new [HexGridCoordinates] instance.
+    pub fn new(column: i64, row: i64) -> Self {
+        Self { column, row }
+    }
+
+    /// Returns the hex grid coordinates matching the given [GalacticHex] index.
+    pub fn from_index(index: SpaceCoordinates) -> Self {
+        Self::new(index.x, index.y)
+    }
+
+    /// Returns the [GalacticHex] index matching these hex grid coordinates, on the given **layer** of the map.
+    pub fn to_index(self, layer: i64) -> SpaceCoordinates {
+        SpaceCoordinates::new(self.column, self.row, layer)
+    }
+
+    /// Returns these coordinates converted to cube coordinates (q, r, s), whose sum is always 0.
+    pub fn to_cube(self) -> (i64, i64, i64) {
+        let q = self.column;
+        let r = self.row - (self.column - (self.column & 1)) / 2;
+        (q, r, -q - r)
+    }
+
+    /// Returns the hex grid coordinates matching the given cube coordinates.
+    pub fn from_cube(cube: (i64, i64, i64)) -> Self {
+        let (q, r, _) = cube;
+        Self::new(q, r + (q - (q & 1)) / 2)
+    }
+
+    /// Returns the number of steps needed to go from this hex to the **other** one.
+    pub fn distance_to(self, other: HexGridCoordinates) -> u32 {
+        let (q1, r1, s1) = self.to_cube();
+        let (q2, r2, s2) = other.to_cube();
+        (q1 - q2).abs().max((r1 - r2).abs()).max((s1 - s2).abs()) as u32
+    }
+
+    /// Returns the six hexes sharing a side with this one.
+    pub fn get_neighbors(self) -> Vec<HexGridCoordinates> {
+        let (q, r, s) = self.to_cube();
+        HEX_GRID_DIRECTIONS
+            .iter()
+            .map(|(dq, dr, ds)| Self::from_cube((q + dq, r + dr, s + ds)))
+            .collect()
+    }
+
+    /// Returns the hexes that are exactly **radius** steps away from this one, going around it. A radius of 0 returns this hex only.
+    pub fn get_ring(self, radius: u32) -> Vec<HexGridCoordinates> {
+        if radius == 0 {
+            return vec![self];
+        }
+        let radius = radius as i64;
+        let (q, r, s) = self.to_cube();
+        let (dq, dr, ds) = HEX_GRID_DIRECTIONS[4];
+        let mut cube = (q + dq * radius, r + dr * radius, s + ds * radius);
+        let mut ring = Vec::new();
+        for (dq, dr, ds) in HEX_GRID_DIRECTIONS.iter() {
+            for _ in 0..radius {
+                ring.push(Self::from_cube(cube));
+                cube = (cube.0 + dq, cube.1 + dr, cube.2 + ds);
+            }
+        }
+        ring
+    }
+
+    /// Returns every hex at most **radius** steps away from this one, starting with this hex and going outwards ring by ring.
+    pub fn get_range(self, radius: u32) -> Vec<HexGridCoordinates> {
+        (0..=radius).flat_map(|r| self.get_ring(r)).collect()
+    }
+}
+
+impl Display for HexGridCoordinates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(column: {}, row: {})", self.column, self.row)
+    }
+}
+
+/// Where to find a [StarSystem] in a [Galaxy]: the index of the [GalacticHex] it belongs to and its own index in that hex.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize, Deserialize,
+)]
+pub struct StarSystemAddress {
+    /// The index of the hex the system belongs to.
+    pub hex: SpaceCoordinates,
+    /// The index of the system in its hex.
+    pub system: u16,
+}
+
+impl StarSystemAddress {
+    /// Creates a new [StarSystemAddress] instance.
+    pub fn new(hex: SpaceCoordinates, system: u16) -> Self {
+        Self { hex, system }
+    }
+}
+
+impl Display for StarSystemAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "system {} of hex {}", self.system, self.hex)
+    }
+}
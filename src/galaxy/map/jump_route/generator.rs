@@ -0,0 +1,328 @@
+use crate::internal::*;
+use crate::prelude::*;
+use std::collections::HashMap;
+
+impl JumpRouteGraph {
+    /// Builds the [JumpRouteGraph] linking the [StarSystem]s of the given [GalacticMapDivision], generating its hexes if needed. Returns
+    /// an error if one of them could not be generated.
+    pub fn generate(
+        galaxy: &mut Galaxy,
+        division: &GalacticMapDivision,
+        settings: JumpRouteSettings,
+    ) -> Result<Self, Rc<str>> {
+        let hexes: Vec<GalacticHex> = galaxy
+            .get_hexes_in_division(division)
+            .collect::<Result<_, _>>()?;
+        Ok(Self::from_hexes(&hexes, galaxy, settings))
+    }
+
+    /// Builds the [JumpRouteGraph] linking the [StarSystem]s of the given [GalacticHex]es. Systems sharing the same hex are always
+    /// within jump range of each other.
+    pub fn from_hexes(hexes: &[GalacticHex], galaxy: &Galaxy, settings: JumpRouteSettings) -> Self {
+        let nodes: Vec<JumpRouteNode> = hexes
+            .iter()
+            .flat_map(|hex| {
+                hex.contents
+                    .iter()
+                    .enumerate()
+                    .map(|(index, system)| generate_node(hex.index, index as u16, system))
+            })
+            .collect();
+
+        // Only the systems of the hexes within jump range of each other are compared.
+        let mut nodes_by_hex: HashMap<SpaceCoordinates, Vec<usize>> = HashMap::new();
+        nodes.iter().enumerate().for_each(|(index, node)| {
+            nodes_by_hex
+                .entry(node.address.hex)
+                .or_default()
+                .push(index)
+        });
+        let reach = galaxy.get_hex_reach(settings.jump_distance);
+        let mut edges = Vec::new();
+        for (first, node) in nodes.iter().enumerate() {
+            for x in -reach.x..=reach.x {
+                for y in -reach.y..=reach.y {
+                    for z in -reach.z..=reach.z {
+                        let hex = node.address.hex + SpaceCoordinates::new(x, y, z);
+                        let Some(others) = nodes_by_hex.get(&hex) else {
+                            continue;
+                        };
+                        let distance = galaxy
+                            .get_distance_between_hexes(node.address.hex, hex)
+                            .ceil() as u32;
+                        if distance > settings.jump_distance {
+                            continue;
+                        }
+                        others
+                            .iter()
+                            .filter(|&&second| second > first)
+                            .for_each(|&second| {
+                                edges.push(JumpRouteEdge::new(first, second, distance))
+                            });
+                    }
+                }
+            }
+        }
+        edges.sort();
+
+        Self::new(settings, nodes, edges)
+    }
+}
+
+/// Describes the given [StarSystem] as a [JumpRouteNode], looking for places where ships could refuel.
+fn generate_node(hex: SpaceCoordinates, index: u16, system: &StarSystem) -> JumpRouteNode {
+    let has_gas_giant = system
+        .all_objects
+        .iter()
+        .any(|point| matches!(point.object, AstronomicalObject::GaseousBody(_)));
+    let has_water_ice = system.all_objects.iter().any(|point| match &point.object {
+        AstronomicalObject::IcyBody(_) | AstronomicalObject::IcyDisk(_) => true,
+        AstronomicalObject::TelluricBody(body) => match &body.details {
+            CelestialBodyDetails::Telluric(details) => {
+                matches!(
+                    details.world_type,
+                    CelestialBodyWorldType::Ice
+                        | CelestialBodyWorldType::DirtySnowball
+                        | CelestialBodyWorldType::Ocean
+                ) || details.hydrosphere > 0.0
+            }
+            _ => false,
+        },
+        _ => false,
+    });
+    JumpRouteNode::new(
+        StarSystemAddress::new(hex, index),
+        system.name.clone(),
+        has_gas_giant,
+        has_water_ice,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate_test_graph(seed: &str, settings: JumpRouteSettings) -> JumpRouteGraph {
+        let settings_gen = &GenerationSettings {
+            seed: seed.into(),
+            ..Default::default()
+        };
+        let neighborhood =
+            GalacticNeighborhood::generate(Universe::generate(&settings_gen), &settings_gen);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings_gen);
+        let subsector = galaxy
+            .get_division_at_level(SpaceCoordinates::new(0, 0, 0), 1)
+            .expect("Should return a div.");
+        JumpRouteGraph::generate(&mut galaxy, &subsector, settings)
+            .expect("Should generate the graph.")
+    }
+
+    #[test]
+    fn generate_jump_route_graphs_within_jump_range() {
+        let settings = JumpRouteSettings {
+            jump_distance: 2,
+            ..Default::default()
+        };
+        let graph = generate_test_graph("default", settings);
+        assert!(!graph.nodes.is_empty());
+        assert!(!graph.edges.is_empty());
+        for edge in graph.edges.iter() {
+            assert!(edge.distance <= settings.jump_distance);
+            assert!(edge.first < edge.second);
+        }
+        for node in graph.nodes.iter() {
+            for neighbor in graph.get_neighbors(node.address) {
+                assert!(graph.get_neighbors(neighbor).contains(&node.address));
+            }
+        }
+    }
+
+    #[test]
+    fn link_every_pair_of_systems_within_jump_range() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let subsector = galaxy
+            .get_division_at_level(SpaceCoordinates::new(0, 0, 0), 1)
+            .expect("Should return a div.");
+        for jump_distance in 1..4 {
+            let graph = JumpRouteGraph::generate(
+                &mut galaxy,
+                &subsector,
+                JumpRouteSettings {
+                    jump_distance,
+                    ..Default::default()
+                },
+            )
+            .expect("Should generate the graph.");
+            let mut expected_edges = Vec::new();
+            for first in 0..graph.nodes.len() {
+                for second in (first + 1)..graph.nodes.len() {
+                    let distance = galaxy
+                        .get_distance_between_hexes(
+                            graph.nodes[first].address.hex,
+                            graph.nodes[second].address.hex,
+                        )
+                        .ceil() as u32;
+                    if distance <= jump_distance {
+                        expected_edges.push(JumpRouteEdge::new(first, second, distance));
+                    }
+                }
+            }
+            assert_eq!(graph.edges, expected_edges);
+        }
+    }
+
+    #[test]
+    fn refuse_to_build_graphs_over_hexes_that_cannot_be_generated() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            star: StarSettings {
+                fixed_number_of_stars: Some(0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let subsector = galaxy
+            .get_division_at_level(SpaceCoordinates::new(0, 0, 0), 1)
+            .expect("Should return a div.");
+        assert!(
+            JumpRouteGraph::generate(&mut galaxy, &subsector, JumpRouteSettings::default())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn find_shortest_routes_between_reachable_systems() {
+        let settings = JumpRouteSettings {
+            jump_distance: 2,
+            ..Default::default()
+        };
+        let graph = generate_test_graph("default", settings);
+        let start = graph.nodes[0].address;
+        let reachable = graph.get_reachable_systems(start, None);
+        let close = graph.get_reachable_systems(start, Some(1));
+        assert_eq!(close.len(), graph.get_neighbors(start).len());
+        assert!(close.iter().all(|address| reachable.contains(address)));
+
+        for node in graph.nodes.iter().skip(1) {
+            let route = graph.find_shortest_route(start, node.address, None);
+            if reachable.contains(&node.address) {
+                let route = route.expect("Should find a route.");
+                assert_eq!(route.systems.first(), Some(&start));
+                assert_eq!(route.systems.last(), Some(&node.address));
+                let mut cost = 0;
+                for jump in route.systems.windows(2) {
+                    assert!(graph.get_neighbors(jump[0]).contains(&jump[1]));
+                    let destination = &graph.nodes[graph
+                        .get_node_index(jump[1])
+                        .expect("Should be in the graph.")];
+                    cost += settings.jump_cost
+                        + if destination.has_refuelling() {
+                            0
+                        } else {
+                            settings.no_refuelling_penalty
+                        };
+                }
+                assert_eq!(route.cost, cost);
+                assert!(route.get_number_of_jumps() >= 1);
+            } else {
+                assert!(route.is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn weight_routes_by_refuelling_availability() {
+        let address = |x: i64| StarSystemAddress::new(SpaceCoordinates::new(x, 0, 0), 0);
+        let node = |x: i64, fuel: bool| {
+            JumpRouteNode::new(address(x), format!("{}", x).into(), fuel, false)
+        };
+        let settings = JumpRouteSettings::default();
+        // A short path through a dry system and a longer one through systems offering fuel.
+        let graph = JumpRouteGraph::new(
+            settings,
+            vec![
+                node(0, true),
+                node(1, false),
+                node(2, true),
+                node(3, true),
+                node(4, true),
+            ],
+            vec![
+                JumpRouteEdge::new(0, 1, 1),
+                JumpRouteEdge::new(1, 2, 1),
+                JumpRouteEdge::new(0, 3, 1),
+                JumpRouteEdge::new(3, 4, 1),
+                JumpRouteEdge::new(2, 4, 1),
+            ],
+        );
+        let route = graph
+            .find_shortest_route(address(0), address(2), None)
+            .expect("Should find a route.");
+        assert_eq!(
+            route.systems,
+            vec![address(0), address(3), address(4), address(2)]
+        );
+        assert_eq!(route.cost, 3);
+        // The cheapest route made of at most two jumps goes through the dry system.
+        let route = graph
+            .find_shortest_route(address(0), address(2), Some(2))
+            .expect("Should find a route.");
+        assert_eq!(route.systems, vec![address(0), address(1), address(2)]);
+        assert_eq!(route.cost, 4);
+        assert!(graph
+            .find_shortest_route(address(0), address(2), Some(1))
+            .is_none());
+
+        let unweighted = JumpRouteGraph::new(
+            JumpRouteSettings {
+                no_refuelling_penalty: 0,
+                ..settings
+            },
+            graph.nodes.clone(),
+            graph.edges.clone(),
+        );
+        let route = unweighted
+            .find_shortest_route(address(0), address(2), None)
+            .expect("Should find a route.");
+        assert_eq!(route.systems, vec![address(0), address(1), address(2)]);
+        assert_eq!(route.cost, 2);
+    }
+
+    #[test]
+    fn find_routes_in_graphs_edited_after_their_creation() {
+        let address = |x: i64| StarSystemAddress::new(SpaceCoordinates::new(x, 0, 0), 0);
+        let graph = JumpRouteGraph {
+            nodes: (0..3)
+                .map(|x| JumpRouteNode::new(address(x), format!("{}", x).into(), true, false))
+                .collect(),
+            edges: vec![JumpRouteEdge::new(0, 1, 1)],
+            ..Default::default()
+        };
+        assert!(graph
+            .find_shortest_route(address(0), address(2), None)
+            .is_none());
+
+        let mut graph = JumpRouteGraph::new(graph.settings, graph.nodes, graph.edges);
+        graph.edges.push(JumpRouteEdge::new(1, 2, 1));
+        let route = graph
+            .find_shortest_route(address(0), address(2), None)
+            .expect("Should find a route.");
+        assert_eq!(route.get_number_of_jumps(), 2);
+
+        graph
+            .nodes
+            .push(JumpRouteNode::new(address(3), "3".into(), true, false));
+        graph.edges.push(JumpRouteEdge::new(0, 3, 1));
+        assert_eq!(
+            graph.get_reachable_systems(address(3), None),
+            vec![address(0), address(1), address(2)]
+        );
+    }
+}
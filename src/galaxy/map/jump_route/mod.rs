@@ -0,0 +1,225 @@
+use crate::internal::*;
+use crate::prelude::*;
+use std::borrow::Cow;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::{Hash, Hasher};
+pub mod generator;
+pub mod types;
+
+/// A graph connecting the [StarSystem]s of a part of a [Galaxy] that are within jump range of each other.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JumpRouteGraph {
+    /// The settings used to build this graph.
+    pub settings: JumpRouteSettings,
+    /// The star systems of the graph.
+    pub nodes: Vec<JumpRouteNode>,
+    /// The possible jumps between the star systems of the graph.
+    pub edges: Vec<JumpRouteEdge>,
+    /// For each star system of the graph, the systems one can jump to. Only a cache of what the nodes and edges already tell, worked
+    /// out again whenever it doesn't fit them anymore.
+    #[serde(skip)]
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl PartialEq for JumpRouteGraph {
+    fn eq(&self, other: &Self) -> bool {
+        self.settings == other.settings && self.nodes == other.nodes && self.edges == other.edges
+    }
+}
+
+impl Eq for JumpRouteGraph {}
+
+impl PartialOrd for JumpRouteGraph {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JumpRouteGraph {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.settings, &self.nodes, &self.edges).cmp(&(
+            &other.settings,
+            &other.nodes,
+            &other.edges,
+        ))
+    }
+}
+
+impl Hash for JumpRouteGraph {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.settings.hash(state);
+        self.nodes.hash(state);
+        self.edges.hash(state);
+    }
+}
+
+impl Display for JumpRouteGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Jump-{} route graph of {} systems and {} jumps",
+            self.settings.jump_distance,
+            self.nodes.len(),
+            self.edges.len()
+        )
+    }
+}
+
+impl JumpRouteGraph {
+    /// Creates a new [JumpRouteGraph], working out once and for all which systems can be jumped to from each one.
+    pub fn new(
+        settings: JumpRouteSettings,
+        nodes: Vec<JumpRouteNode>,
+        edges: Vec<JumpRouteEdge>,
+    ) -> Self {
+        let mut graph = Self {
+            settings,
+            nodes,
+            edges,
+            adjacency: Vec::new(),
+        };
+        graph.adjacency = graph.generate_adjacency();
+        graph
+    }
+
+    /// Returns the index of the node of the system found at the given address, if it is part of the graph.
+    pub fn get_node_index(&self, address: StarSystemAddress) -> Option<usize> {
+        self.nodes.iter().position(|node| node.address == address)
+    }
+
+    /// Returns the addresses of the systems one can jump to from the given one.
+    pub fn get_neighbors(&self, address: StarSystemAddress) -> Vec<StarSystemAddress> {
+        if let Some(node) = self.get_node_index(address) {
+            self.get_adjacency()[node]
+                .iter()
+                .map(|&neighbor| self.nodes[neighbor].address)
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    /// Returns the cheapest [JumpRoute] between the two given systems made of at most **max_jumps** jumps, or without limit if [None] is
+    /// given. Returns [None] if one cannot reach the other. Uses Dijkstra's algorithm, going through each system once per number of
+    /// jumps it can be reached in when the jumps are limited.
+    pub fn find_shortest_route(
+        &self,
+        from: StarSystemAddress,
+        to: StarSystemAddress,
+        max_jumps: Option<u32>,
+    ) -> Option<JumpRoute> {
+        let start = self.get_node_index(from)?;
+        let goal = self.get_node_index(to)?;
+        let adjacency = self.get_adjacency();
+        // Each state is a system along with the number of jumps taken to reach it, always 0 when they are not limited.
+        let layers = max_jumps.map_or(1, |max| max as usize + 1);
+        let state = |node: usize, jumps: usize| node * layers + jumps;
+
+        let mut costs = vec![u32::MAX; self.nodes.len() * layers];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len() * layers];
+        let mut open = BinaryHeap::new();
+        costs[state(start, 0)] = 0;
+        open.push(Reverse((0, start, 0)));
+
+        while let Some(Reverse((cost, node, jumps))) = open.pop() {
+            if node == goal {
+                let mut systems = vec![self.nodes[node].address];
+                let mut current = state(node, jumps);
+                while let Some(before) = previous[current] {
+                    systems.push(self.nodes[before / layers].address);
+                    current = before;
+                }
+                systems.reverse();
+                return Some(JumpRoute { systems, cost });
+            }
+            if cost > costs[state(node, jumps)] {
+                continue;
+            }
+            let next_jumps = if max_jumps.is_some() { jumps + 1 } else { 0 };
+            if next_jumps >= layers {
+                continue;
+            }
+            for &neighbor in adjacency[node].iter() {
+                let new_cost = cost + self.get_jump_cost(neighbor);
+                if new_cost < costs[state(neighbor, next_jumps)] {
+                    costs[state(neighbor, next_jumps)] = new_cost;
+                    previous[state(neighbor, next_jumps)] = Some(state(node, jumps));
+                    open.push(Reverse((new_cost, neighbor, next_jumps)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the addresses of every system that can be reached from the given one in at most **max_jumps** jumps, or without limit if
+    /// [None] is given. The starting system is not included.
+    pub fn get_reachable_systems(
+        &self,
+        from: StarSystemAddress,
+        max_jumps: Option<u32>,
+    ) -> Vec<StarSystemAddress> {
+        let Some(start) = self.get_node_index(from) else {
+            return vec![];
+        };
+        let adjacency = self.get_adjacency();
+        let mut jumps: Vec<Option<u32>> = vec![None; self.nodes.len()];
+        let mut queue = VecDeque::new();
+        jumps[start] = Some(0);
+        queue.push_back(start);
+
+        let mut result = Vec::new();
+        while let Some(node) = queue.pop_front() {
+            let node_jumps = jumps[node].unwrap_or_default();
+            if max_jumps.is_some_and(|max| node_jumps >= max) {
+                continue;
+            }
+            for &neighbor in adjacency[node].iter() {
+                if jumps[neighbor].is_none() {
+                    jumps[neighbor] = Some(node_jumps + 1);
+                    result.push(self.nodes[neighbor].address);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns true if a ship can go from one of the given systems to the other.
+    pub fn are_connected(&self, first: StarSystemAddress, second: StarSystemAddress) -> bool {
+        first == second && self.get_node_index(first).is_some()
+            || self.get_reachable_systems(first, None).contains(&second)
+    }
+
+    /// Returns the cost of jumping to the given system.
+    fn get_jump_cost(&self, destination: usize) -> u32 {
+        self.settings.jump_cost
+            + if self.nodes[destination].has_refuelling() {
+                0
+            } else {
+                self.settings.no_refuelling_penalty
+            }
+    }
+
+    /// Returns, for each node, the list of nodes one can jump to. The list worked out when the graph was created is used as long as it
+    /// fits its nodes and edges, which may have been changed or deserialized since.
+    fn get_adjacency(&self) -> Cow<'_, [Vec<usize>]> {
+        if self.adjacency.len() == self.nodes.len()
+            && self.adjacency.iter().map(Vec::len).sum::<usize>() == self.edges.len() * 2
+        {
+            Cow::Borrowed(&self.adjacency)
+        } else {
+            Cow::Owned(self.generate_adjacency())
+        }
+    }
+
+    /// Works out, for each node, the list of nodes one can jump to.
+    fn generate_adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        self.edges.iter().for_each(|edge| {
+            adjacency[edge.first].push(edge.second);
+            adjacency[edge.second].push(edge.first);
+        });
+        adjacency
+    }
+}
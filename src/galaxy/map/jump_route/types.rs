@@ -0,0 +1,127 @@
+use crate::internal::*;
+use crate::prelude::*;
+
+/// A list of settings used to configure how a [JumpRouteGraph] is built.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, SmartDefault, Serialize, Deserialize,
+)]
+pub struct JumpRouteSettings {
+    /// The maximum distance in parsecs a ship can travel in a single jump, like the "jump-N" rating of Traveller ships.
+    #[default = 1]
+    pub jump_distance: u32,
+    /// The cost of a single jump when looking for the shortest path between two systems.
+    #[default = 1]
+    pub jump_cost: u32,
+    /// The cost added to a jump when the destination system offers no way to refuel, neither gas giants nor water ice. Set it to 0 to
+    /// ignore refuelling availability.
+    #[default = 2]
+    pub no_refuelling_penalty: u32,
+}
+
+/// A star system in a [JumpRouteGraph].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize)]
+pub struct JumpRouteNode {
+    /// Where to find this system in the galaxy.
+    pub address: StarSystemAddress,
+    /// The name of this system.
+    pub name: Rc<str>,
+    /// Does this system contain a gas giant ships can skim fuel from?
+    pub has_gas_giant: bool,
+    /// Does this system contain water ice ships can turn into fuel?
+    pub has_water_ice: bool,
+}
+
+impl JumpRouteNode {
+    /// Creates a new [JumpRouteNode].
+    pub fn new(
+        address: StarSystemAddress,
+        name: Rc<str>,
+        has_gas_giant: bool,
+        has_water_ice: bool,
+    ) -> Self {
+        Self {
+            address,
+            name,
+            has_gas_giant,
+            has_water_ice,
+        }
+    }
+
+    /// Returns true if ships can refuel in this system.
+    pub fn has_refuelling(&self) -> bool {
+        self.has_gas_giant || self.has_water_ice
+    }
+}
+
+impl Display for JumpRouteNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}){}",
+            self.name,
+            self.address,
+            match (self.has_gas_giant, self.has_water_ice) {
+                (true, true) => ", refuelling at gas giants and water ice",
+                (true, false) => ", refuelling at gas giants",
+                (false, true) => ", refuelling at water ice",
+                (false, false) => "",
+            }
+        )
+    }
+}
+
+/// A jump between two systems of a [JumpRouteGraph]. Jumps can be made both ways, but their cost depends on the destination.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize, Deserialize,
+)]
+pub struct JumpRouteEdge {
+    /// The index of the first system in the graph's nodes.
+    pub first: usize,
+    /// The index of the second system in the graph's nodes.
+    pub second: usize,
+    /// The distance between both systems, in parsecs, rounded up.
+    pub distance: u32,
+}
+
+impl JumpRouteEdge {
+    /// Creates a new [JumpRouteEdge].
+    pub fn new(first: usize, second: usize, distance: u32) -> Self {
+        Self {
+            first,
+            second,
+            distance,
+        }
+    }
+}
+
+/// A path through a [JumpRouteGraph], from a system to another.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize)]
+pub struct JumpRoute {
+    /// The systems to go through, including the starting and destination ones.
+    pub systems: Vec<StarSystemAddress>,
+    /// The total cost of the route, according to the [JumpRouteSettings] used to build the graph.
+    pub cost: u32,
+}
+
+impl JumpRoute {
+    /// Returns the number of jumps needed to follow this route.
+    pub fn get_number_of_jumps(&self) -> usize {
+        self.systems.len().saturating_sub(1)
+    }
+}
+
+impl Display for JumpRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} jumps route of cost {}: {}",
+            self.get_number_of_jumps(),
+            self.cost,
+            self.systems
+                .iter()
+                .map(|s| format!("{}", s))
+                .collect::<Vec<String>>()
+                .join(" -> ")
+        )
+    }
+}
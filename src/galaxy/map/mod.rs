@@ -5,6 +5,12 @@ mod constants;
 pub mod division;
 pub mod division_level;
 pub mod hex;
+pub mod jump_route;
+pub mod nebula;
+pub mod sector_export;
+pub mod star_catalog;
+pub mod sub_region;
+pub mod trade_route;
 pub mod types;
 
 impl Galaxy {
@@ -15,26 +21,130 @@ impl Galaxy {
             return Err("Invalid coordinates.".into());
         }
 
-        let starting_point = self.get_galactic_start();
-        let abs_coord = coord.abs(starting_point);
-        let hex_size = self
-            .division_levels
-            .iter()
-            .find(|l| l.level == 0)
-            .expect("The division levels should be set")
-            .as_coord();
-        let index = abs_coord / hex_size;
+        let index = self.get_hex_index(coord);
         let possible_hex = self.hexes.iter().find(|hex| hex.index == index);
 
         if let Some(hex) = possible_hex {
             Ok(hex.clone())
         } else {
-            let new_hex = GalacticHex::generate(coord, index, self);
+            let new_hex = GalacticHex::generate(coord, index, self)?;
             self.hexes.push(new_hex.clone());
             Ok(new_hex)
         }
     }
 
+    /// Returns the [GalacticHex]es whose center is at most **range** parsecs away from the center of the hex the given coordinates belong
+    /// to. On flat maps, only the hexes of the same layer are returned. On hexagonal grids, the range is counted in hex steps.
+    pub fn get_hexes_in_range(
+        &mut self,
+        coord: SpaceCoordinates,
+        range: u32,
+    ) -> Result<GalacticHexIterator<'_>, Rc<str>> {
+        if !self.are_coord_valid(coord) {
+            return Err("Invalid coordinates.".into());
+        }
+
+        let hex_size = self.get_hex_size();
+        let center_index = self.get_hex_index(coord);
+        if self.uses_hex_grid() {
+            let coords = HexGridCoordinates::from_index(center_index)
+                .get_range(range / hex_size.x as u32)
+                .iter()
+                .map(|cell| self.get_hex_top_left_up(cell.to_index(center_index.z)))
+                .filter(|hex_coord| self.are_coord_valid(*hex_coord))
+                .collect();
+            return Ok(GalacticHexIterator::new(self, coords));
+        }
+        let reach = self.get_hex_reach(range);
+        let range = range as i64;
+
+        let mut coords = Vec::new();
+        for x in -reach.x..=reach.x {
+            for y in -reach.y..=reach.y {
+                for z in -reach.z..=reach.z {
+                    let offset = SpaceCoordinates::new(x, y, z) * hex_size;
+                    if offset.x.pow(2) + offset.y.pow(2) + offset.z.pow(2) > range.pow(2) {
+                        continue;
+                    }
+                    let hex_coord = self.get_hex_top_left_up(center_index) + offset;
+                    if self.are_coord_valid(hex_coord) {
+                        coords.push(hex_coord);
+                    }
+                }
+            }
+        }
+        Ok(GalacticHexIterator::new(self, coords))
+    }
+
+    /// Returns every [GalacticHex] inside the given [GalacticMapDivision].
+    pub fn get_hexes_in_division(
+        &mut self,
+        division: &GalacticMapDivision,
+    ) -> GalacticHexIterator<'_> {
+        let mut division = division.clone();
+        let hex_size = self.get_hex_size();
+        let start = division.get_top_left_up(self);
+        let number_of_hexes = division.get_size(self) / hex_size;
+
+        let mut coords = Vec::new();
+        for x in 0..number_of_hexes.x {
+            for y in 0..number_of_hexes.y {
+                for z in 0..number_of_hexes.z {
+                    let hex_coord = start + SpaceCoordinates::new(x, y, z) * hex_size;
+                    if self.are_coord_valid(hex_coord) {
+                        coords.push(hex_coord);
+                    }
+                }
+            }
+        }
+        GalacticHexIterator::new(self, coords)
+    }
+
+    /// Returns the [GalacticHex]es sharing a face with the hex the given coordinates belong to, or also those sharing an edge or a
+    /// corner with it if **include_diagonals** is true. That makes up to 6 or 26 hexes, or up to 4 and 8 on flat maps, as hexes above
+    /// and below the map layer are ignored. On hexagonal grids, the 6 hexes sharing a side are returned whatever **include_diagonals**
+    /// is.
+    pub fn get_adjacent_hexes(
+        &mut self,
+        coord: SpaceCoordinates,
+        include_diagonals: bool,
+    ) -> Result<GalacticHexIterator<'_>, Rc<str>> {
+        if !self.are_coord_valid(coord) {
+            return Err("Invalid coordinates.".into());
+        }
+
+        let hex_size = self.get_hex_size();
+        let index = self.get_hex_index(coord);
+        if self.uses_hex_grid() {
+            let coords = HexGridCoordinates::from_index(index)
+                .get_neighbors()
+                .iter()
+                .map(|cell| self.get_hex_top_left_up(cell.to_index(index.z)))
+                .filter(|hex_coord| self.are_coord_valid(*hex_coord))
+                .collect();
+            return Ok(GalacticHexIterator::new(self, coords));
+        }
+        let top_left_up = self.get_hex_top_left_up(index);
+        let z_reach: i64 = if self.settings.sector.flat_map { 0 } else { 1 };
+
+        let mut coords = Vec::new();
+        for x in -1..=1_i64 {
+            for y in -1..=1_i64 {
+                for z in -z_reach..=z_reach {
+                    let distance = x.abs() + y.abs() + z.abs();
+                    if distance == 0 || (!include_diagonals && distance > 1) {
+                        continue;
+                    }
+                    let hex_coord = top_left_up + SpaceCoordinates::new(x, y, z) * hex_size;
+                    if self.are_coord_valid(hex_coord) {
+                        coords.push(hex_coord);
+                    }
+                }
+            }
+        }
+        Ok(GalacticHexIterator::new(self, coords))
+    }
+
     /// Returns the [GalacticMapDivision] at the level and coordinates given in parameters. 0 being the hex level and 9 being the highest
     /// possible division level.
     pub fn get_division_at_level(
@@ -75,33 +185,51 @@ impl Galaxy {
         let mut index = abs_coord;
         for i in 0..=9 {
             index = calculate_next_index(self, i, index);
-            let possible_division = self
-                .divisions
-                .iter()
-                .filter(|div| div.level == i)
-                .find(|div| div.index == index);
-
-            if let Some(division) = possible_division {
-                result.push(division.clone())
-            } else {
-                let new_division = GalacticMapDivision::generate(
-                    index,
-                    i,
-                    &self
-                        .division_levels
-                        .iter()
-                        .find(|lvl| lvl.level == i + 1)
-                        .unwrap_or(&GalacticMapDivisionLevel::new(10, 255, 255, 255)),
-                    self,
-                );
-                self.divisions.push(new_division.clone());
-                result.push(new_division)
-            }
+            result.push(self.get_or_generate_division(index, i));
         }
 
         Ok(result)
     }
 
+    /// Returns the already generated [GalacticMapDivision] at the given index and level, generating it first if needed.
+    fn get_or_generate_division(
+        &mut self,
+        index: SpaceCoordinates,
+        level: u8,
+    ) -> GalacticMapDivision {
+        let possible_division = self
+            .divisions
+            .iter()
+            .filter(|div| div.level == level)
+            .find(|div| div.index == index);
+
+        if let Some(division) = possible_division {
+            division.clone()
+        } else {
+            let new_division = GalacticMapDivision::generate(
+                index,
+                level,
+                &self
+                    .division_levels
+                    .iter()
+                    .find(|lvl| lvl.level == level + 1)
+                    .unwrap_or(&GalacticMapDivisionLevel::new(10, 255, 255, 255)),
+                self,
+            );
+            self.divisions.push(new_division.clone());
+            new_division
+        }
+    }
+
+    /// Returns the [GalacticRegion] the given coordinates belong to, according to the sub-regions and shape of the galaxy.
+    pub fn get_region_at(&self, coord: SpaceCoordinates) -> GalacticRegion {
+        self.sub_regions
+            .iter()
+            .find(|sub_region| sub_region.contains(coord))
+            .map(|sub_region| sub_region.region)
+            .unwrap_or_else(|| self.shape.get_region(self.category, coord))
+    }
+
     /// Returns the starting point of a galactic 3D map.
     pub fn get_galactic_start(&self) -> SpaceCoordinates {
         return match self.category {
@@ -163,6 +291,57 @@ impl Galaxy {
         };
     }
 
+    /// Returns the nebulae that can be seen from the given coordinates along with their apparent size, sorted from the biggest to the
+    /// smallest. Only the nebulae of the division containing the coordinates and of its direct neighbors are taken into account.
+    pub fn get_nebulae_near(
+        &mut self,
+        coord: SpaceCoordinates,
+    ) -> Result<Vec<(GalacticNebula, NebulaeApparentSize)>, Rc<str>> {
+        if !self.are_coord_valid(coord) {
+            return Err("Invalid coordinates.".into());
+        }
+        let level = constants::NEBULA_DIVISION_LEVEL;
+        let start = self.get_galactic_start();
+        let size = self
+            .division_levels
+            .iter()
+            .filter(|l| l.level <= level)
+            .fold(SpaceCoordinates::new(1, 1, 1), |size, l| {
+                size * l.as_coord()
+            });
+        let mut index = coord.abs(start);
+        for i in 0..=level {
+            index = calculate_next_index(self, i, index);
+        }
+
+        // Only the nebula level division is needed, so there is no need to generate every level for the neighboring ones
+        let mut result = Vec::new();
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let neighbor_index = index + SpaceCoordinates::new(dx, dy, dz);
+                    if neighbor_index.x < 0
+                        || neighbor_index.y < 0
+                        || neighbor_index.z < 0
+                        || !self.are_coord_valid((neighbor_index * size).rel(start))
+                    {
+                        continue;
+                    }
+                    self.get_or_generate_division(neighbor_index, level)
+                        .nebulae
+                        .iter()
+                        .for_each(|nebula| {
+                            if let Some(apparent_size) = nebula.get_apparent_size_from(coord) {
+                                result.push((*nebula, apparent_size));
+                            }
+                        });
+                }
+            }
+        }
+        result.sort_by(|a, b| b.1.cmp(&a.1));
+        Ok(result)
+    }
+
     /// Returns the size of the [Galaxy] in parsecs on the (x, y, z) axis.
     pub fn get_galaxy_size(&self) -> SpaceCoordinates {
         return match self.category {
@@ -180,6 +359,195 @@ impl Galaxy {
         };
     }
 
+    /// Returns the position on the hexagonal grid of the [GalacticHex] the given coordinates belong to.
+    pub fn get_hex_grid_coordinates(
+        &self,
+        coord: SpaceCoordinates,
+    ) -> Result<HexGridCoordinates, Rc<str>> {
+        if !self.are_coord_valid(coord) {
+            return Err("Invalid coordinates.".into());
+        }
+        Ok(HexGridCoordinates::from_index(self.get_hex_index(coord)))
+    }
+
+    /// Returns the coordinates of the top left corner of the [GalacticHex] found at the given position on the hexagonal grid, in the
+    /// layer of the galactic plane.
+    pub fn get_coordinates_from_hex_grid(
+        &self,
+        cell: HexGridCoordinates,
+    ) -> Result<SpaceCoordinates, Rc<str>> {
+        let layer = self.get_hex_index(SpaceCoordinates::new(0, 0, 0)).z;
+        let coord = self.get_hex_top_left_up(cell.to_index(layer));
+        if !self.are_coord_valid(coord) {
+            return Err("Invalid coordinates.".into());
+        }
+        Ok(coord)
+    }
+
+    /// Returns the distance in parsecs between the centers of the [GalacticHex]es of the given indexes. On hexagonal grids, the
+    /// distance is counted in hex steps, each one being as long as a hex is wide.
+    pub fn get_distance_between_hexes(
+        &self,
+        first: SpaceCoordinates,
+        second: SpaceCoordinates,
+    ) -> f64 {
+        let hex_size = self.get_hex_size();
+        if self.uses_hex_grid() {
+            let steps = HexGridCoordinates::from_index(first)
+                .distance_to(HexGridCoordinates::from_index(second));
+            return (steps as i64 * hex_size.x) as f64;
+        }
+        let offset = (second - first) * hex_size;
+        ((offset.x.pow(2) + offset.y.pow(2) + offset.z.pow(2)) as f64).sqrt()
+    }
+
+    /// Pins the systems of the given [StarCatalog] into this galaxy. The hexes lying within the catalog's radius will only contain the
+    /// catalog's systems, with planets generated around their stars as usual. Already generated hexes affected by the catalog are
+    /// discarded so they can be generated again.
+    pub fn import_star_catalog(&mut self, catalog: StarCatalog) {
+        self.star_catalog = Some(catalog);
+        let affected: Vec<SpaceCoordinates> = self
+            .hexes
+            .iter()
+            .map(|hex| hex.index)
+            .filter(|index| self.get_catalog_systems_in_hex(*index).is_some())
+            .collect();
+        self.hexes.retain(|hex| !affected.contains(&hex.index));
+    }
+
+    /// Returns the coordinates of this galaxy's home, where our own solar system lies: the origin of its [StarCatalog] if any, or the
+    /// galactic center otherwise.
+    pub fn get_home_coordinates(&self) -> SpaceCoordinates {
+        self.star_catalog
+            .as_ref()
+            .map_or(SpaceCoordinates::default(), |catalog| catalog.origin)
+    }
+
+    /// Returns the coordinates of the given system of this galaxy's [StarCatalog]. On flat maps, systems are projected onto the layer
+    /// of the catalog's origin.
+    pub fn get_catalog_system_coordinates(&self, system: &CatalogSystem) -> SpaceCoordinates {
+        self.get_home_coordinates()
+            + SpaceCoordinates::new(
+                system.position.0.round() as i64,
+                system.position.1.round() as i64,
+                if self.settings.sector.flat_map {
+                    0
+                } else {
+                    system.position.2.round() as i64
+                },
+            )
+    }
+
+    /// Returns the systems of this galaxy's [StarCatalog] lying in the [GalacticHex] of the given index, or [None] if the hex is not
+    /// covered by the catalog.
+    pub(crate) fn get_catalog_systems_in_hex(
+        &self,
+        index: SpaceCoordinates,
+    ) -> Option<Vec<CatalogSystem>> {
+        let catalog = self.star_catalog.as_ref()?;
+        let systems: Vec<CatalogSystem> = catalog
+            .systems
+            .iter()
+            .filter(|system| {
+                self.get_hex_index(self.get_catalog_system_coordinates(system)) == index
+            })
+            .cloned()
+            .collect();
+        let hex_size = self.get_hex_size();
+        let offset = self.get_hex_top_left_up(index) + hex_size / SpaceCoordinates::new(2, 2, 2)
+            - catalog.origin;
+        let z_offset = if self.settings.sector.flat_map {
+            0
+        } else {
+            offset.z
+        };
+        let distance = ((offset.x.pow(2) + offset.y.pow(2) + z_offset.pow(2)) as f64).sqrt();
+        if systems.is_empty() && distance > catalog.radius {
+            None
+        } else {
+            Some(systems)
+        }
+    }
+
+    /// Pins the given system or system template at the given coordinates. The [GalacticHex] these coordinates belong to will only
+    /// contain the systems pinned into it, templates being completed by the generator. If that hex was already generated, it is
+    /// discarded so it can be generated again.
+    pub fn pin_system(
+        &mut self,
+        coord: SpaceCoordinates,
+        system: PinnedSystem,
+    ) -> Result<(), Rc<str>> {
+        if !self.are_coord_valid(coord) {
+            return Err("Invalid coordinates.".into());
+        }
+        let index = self.get_hex_index(coord);
+        self.pinned_systems.push((coord, system));
+        self.hexes.retain(|hex| hex.index != index);
+        Ok(())
+    }
+
+    /// Returns the systems pinned in the [GalacticHex] of the given index, in the order they were pinned, or [None] if there are none.
+    pub(crate) fn get_pinned_systems_in_hex(
+        &self,
+        index: SpaceCoordinates,
+    ) -> Option<Vec<PinnedSystem>> {
+        let systems: Vec<PinnedSystem> = self
+            .pinned_systems
+            .iter()
+            .filter(|(coord, _)| self.get_hex_index(*coord) == index)
+            .map(|(_, system)| system.clone())
+            .collect();
+        if systems.is_empty() {
+            None
+        } else {
+            Some(systems)
+        }
+    }
+
+    /// Returns true if the hexes of this galaxy are laid out as a genuine hexagonal grid.
+    pub fn uses_hex_grid(&self) -> bool {
+        self.settings.sector.flat_map && self.settings.sector.hex_grid
+    }
+
+    /// Returns how many hexes apart on each axis two hexes can be while their centers stay at most **range** parsecs away from each
+    /// other.
+    pub(crate) fn get_hex_reach(&self, range: u32) -> SpaceCoordinates {
+        let hex_size = self.get_hex_size();
+        let range = range as i64;
+        if self.uses_hex_grid() {
+            let steps = range / hex_size.x;
+            return SpaceCoordinates::new(steps, steps, 0);
+        }
+        SpaceCoordinates::new(
+            range / hex_size.x,
+            range / hex_size.y,
+            if self.settings.sector.flat_map {
+                0
+            } else {
+                range / hex_size.z
+            },
+        )
+    }
+
+    /// Returns the size of a [GalacticHex] in parsecs on the (x, y, z) axis.
+    fn get_hex_size(&self) -> SpaceCoordinates {
+        self.division_levels
+            .iter()
+            .find(|l| l.level == 0)
+            .expect("The division levels should be set")
+            .as_coord()
+    }
+
+    /// Returns the index of the [GalacticHex] the given coordinates belong to.
+    fn get_hex_index(&self, coord: SpaceCoordinates) -> SpaceCoordinates {
+        coord.abs(self.get_galactic_start()) / self.get_hex_size()
+    }
+
+    /// Returns the coordinates of the top left up corner of the [GalacticHex] with the given index.
+    fn get_hex_top_left_up(&self, index: SpaceCoordinates) -> SpaceCoordinates {
+        (index * self.get_hex_size()).rel(self.get_galactic_start())
+    }
+
     /// Checks whether the given coordinates are within the bounds of the galaxy.
     fn are_coord_valid(&self, coord: SpaceCoordinates) -> bool {
         let start = self.get_galactic_start();
@@ -230,9 +598,14 @@ mod tests {
             category: GalaxyCategory::Irregular(5, 4, 1),
             sub_category: constants::OUR_GALAXYS_SUB_CATEGORY,
             special_traits: vec![constants::NO_SPECIAL_TRAIT],
+            position: NeighborhoodCoordinates::default(),
+            shape: GalacticShape::default(),
+            sub_regions: vec![],
             division_levels: vec![],
             divisions: vec![],
             hexes: vec![],
+            star_catalog: None,
+            pinned_systems: Vec::new(),
         };
         let start = galaxy.get_galactic_start();
         let center = galaxy.get_galactic_center();
@@ -260,9 +633,14 @@ mod tests {
             category: GalaxyCategory::Irregular(5, 4, 1),
             sub_category: constants::OUR_GALAXYS_SUB_CATEGORY,
             special_traits: vec![constants::NO_SPECIAL_TRAIT],
+            position: NeighborhoodCoordinates::default(),
+            shape: GalacticShape::default(),
+            sub_regions: vec![],
             division_levels: vec![],
             divisions: vec![],
             hexes: vec![],
+            star_catalog: None,
+            pinned_systems: Vec::new(),
         };
         let start = galaxy.get_galactic_start();
         let center = galaxy.get_galactic_center();
@@ -324,9 +702,14 @@ mod tests {
             category: GalaxyCategory::Irregular(100, 5, 1),
             sub_category: constants::OUR_GALAXYS_SUB_CATEGORY,
             special_traits: vec![constants::NO_SPECIAL_TRAIT],
+            position: NeighborhoodCoordinates::default(),
+            shape: GalacticShape::default(),
+            sub_regions: vec![],
             division_levels: GalacticMapDivisionLevel::generate_division_levels(&settings),
             divisions: vec![],
             hexes: vec![],
+            star_catalog: None,
+            pinned_systems: Vec::new(),
         };
         let first_hex = galaxy
             .get_hex(SpaceCoordinates::new(-49, -2, 0))
@@ -412,4 +795,278 @@ mod tests {
             .expect("Should return a div.");
         assert_eq!(last_second_level_div.index, SpaceCoordinates::new(4, 0, 0));
     }
+
+    #[test]
+    fn returns_hexes_around_a_point() {
+        for flat_map in [true, false] {
+            let settings = &GenerationSettings {
+                seed: "default".into(),
+                sector: SectorSettings {
+                    flat_map,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+            let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+            let center = SpaceCoordinates::new(0, 0, 0);
+
+            let adjacent: Vec<GalacticHex> = galaxy
+                .get_adjacent_hexes(center, false)
+                .expect("Should return hexes.")
+                .collect::<Result<_, _>>()
+                .expect("Should generate hexes.");
+            assert_eq!(adjacent.len(), if flat_map { 4 } else { 6 });
+            let adjacent: Vec<GalacticHex> = galaxy
+                .get_adjacent_hexes(center, true)
+                .expect("Should return hexes.")
+                .collect::<Result<_, _>>()
+                .expect("Should generate hexes.");
+            assert_eq!(adjacent.len(), if flat_map { 8 } else { 26 });
+            assert!(adjacent
+                .iter()
+                .all(|hex| hex.index != galaxy.get_hex_index(center)));
+
+            let in_range: Vec<GalacticHex> = galaxy
+                .get_hexes_in_range(center, 2)
+                .expect("Should return hexes.")
+                .collect::<Result<_, _>>()
+                .expect("Should generate hexes.");
+            assert_eq!(in_range.len(), if flat_map { 13 } else { 33 });
+            assert!(galaxy
+                .get_hexes_in_range(SpaceCoordinates::new(i64::MAX, 0, 0), 2)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn pins_catalog_stars_into_the_galaxy() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let reference = galaxy.clone();
+        let origin = SpaceCoordinates::new(0, 0, 0);
+        let far_away = SpaceCoordinates::new(20, 0, 0);
+        galaxy.get_hex(origin).expect("Should return a hex.");
+        galaxy.get_hex(far_away).expect("Should return a hex.");
+
+        let catalog = StarCatalog::from_csv(
+            "id,proper,absmag,spect,x,y,z,comp_primary\n\
+            0,Sol,4.85,G2V,0,0,0,0\n\
+            71453,Rigil Kentaurus,4.38,G2V,-0.6,-0.4,-1.2,71453\n\
+            71454,,5.71,K1V,-0.6,-0.4,-1.2,71453\n\
+            87937,Barnard's Star,13.22,M4Ve,-0.06,-5.9,0.5,87937\n",
+            origin,
+            None,
+        )
+        .expect("Should read the catalog.");
+        galaxy.import_star_catalog(catalog);
+
+        let hex = galaxy.get_hex(origin).expect("Should return a hex.");
+        assert_eq!(hex.contents.len(), 1);
+        let sol = &hex.contents[0];
+        assert_eq!(&*sol.name, "Sol");
+        if let AstronomicalObject::Star(sun) = &sol.get_main_star().object {
+            assert_eq!(sun.spectral_type, StarSpectralType::G(2));
+            assert_eq!(sun.luminosity_class, StarLuminosityClass::V);
+            assert!((sun.luminosity - 0.98).abs() < 0.01);
+        } else {
+            panic!("The main star should be a star.");
+        }
+
+        let hex = galaxy
+            .get_hex(SpaceCoordinates::new(-1, 0, 0))
+            .expect("Should return a hex.");
+        assert_eq!(hex.contents.len(), 1);
+        assert_eq!(
+            hex.contents[0]
+                .all_objects
+                .iter()
+                .filter(|point| matches!(point.object, AstronomicalObject::Star(_)))
+                .count(),
+            2
+        );
+        let hex = galaxy
+            .get_hex(SpaceCoordinates::new(3, 3, 0))
+            .expect("Should return a hex.");
+        assert!(hex.contents.is_empty());
+        assert_eq!(
+            galaxy.get_hex(far_away).expect("Should return a hex."),
+            reference
+                .clone()
+                .get_hex(far_away)
+                .expect("Should return a hex.")
+        );
+    }
+
+    #[test]
+    fn pins_systems_and_templates_into_the_galaxy() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let reference = galaxy.clone();
+        let far_away = SpaceCoordinates::new(20, 0, 0);
+        let story_coord = SpaceCoordinates::new(0, 0, 0);
+        let template_coord = SpaceCoordinates::new(5, 5, 0);
+        galaxy.get_hex(story_coord).expect("Should return a hex.");
+
+        let story_system = StarSystem::sol();
+        galaxy
+            .pin_system(story_coord, PinnedSystem::System(story_system.clone()))
+            .expect("Should pin the system.");
+        let template = SystemTemplate {
+            name: Some("Haven".into()),
+            main_star_spectral_type: Some(StarSpectralType::K(0)),
+            bodies: vec![BodyTemplate {
+                world_type: Some(CelestialBodyWorldType::Ocean),
+                min_distance: Some(0.5),
+                max_distance: Some(2.0),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        galaxy
+            .pin_system(template_coord, PinnedSystem::Template(template.clone()))
+            .expect("Should pin the template.");
+        assert!(galaxy
+            .pin_system(
+                SpaceCoordinates::new(i64::MAX, 0, 0),
+                PinnedSystem::Template(template.clone())
+            )
+            .is_err());
+
+        let hex = galaxy.get_hex(story_coord).expect("Should return a hex.");
+        assert_eq!(hex.contents, vec![story_system]);
+
+        let hex = galaxy
+            .get_hex(template_coord)
+            .expect("Should return a hex.");
+        assert_eq!(hex.contents.len(), 1);
+        let haven = &hex.contents[0];
+        assert_eq!(&*haven.name, "Haven");
+        assert!(template.matches(haven));
+        if let AstronomicalObject::Star(star) = &haven.get_main_star().object {
+            assert!(matches!(star.spectral_type, StarSpectralType::K(_)));
+        } else {
+            panic!("The main star should be a star.");
+        }
+
+        let mut other_galaxy = reference.clone();
+        other_galaxy
+            .pin_system(template_coord, PinnedSystem::Template(template))
+            .expect("Should pin the template.");
+        assert_eq!(
+            other_galaxy
+                .get_hex(template_coord)
+                .expect("Should return a hex."),
+            hex
+        );
+        assert_eq!(
+            galaxy.get_hex(far_away).expect("Should return a hex."),
+            reference
+                .clone()
+                .get_hex(far_away)
+                .expect("Should return a hex.")
+        );
+    }
+
+    #[test]
+    fn returns_every_hex_of_a_division() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let subsector = galaxy
+            .get_division_at_level(SpaceCoordinates::new(0, 0, 0), 1)
+            .expect("Should return a div.");
+        let hexes: Vec<GalacticHex> = galaxy
+            .get_hexes_in_division(&subsector)
+            .collect::<Result<_, _>>()
+            .expect("Should generate hexes.");
+        assert_eq!(hexes.len(), 100);
+        for hex in hexes.iter() {
+            let hex_subsector = galaxy
+                .get_division_at_level(galaxy.get_hex_top_left_up(hex.index), 1)
+                .expect("Should return a div.");
+            assert_eq!(hex_subsector.index, subsector.index);
+        }
+    }
+
+    #[test]
+    fn computes_hex_grid_neighbors_distances_and_rings() {
+        for (column, row) in [(0, 0), (3, 4), (4, 3), (-5, 2)] {
+            let cell = HexGridCoordinates::new(column, row);
+            assert_eq!(HexGridCoordinates::from_cube(cell.to_cube()), cell);
+            let neighbors = cell.get_neighbors();
+            assert_eq!(neighbors.len(), 6);
+            assert!(neighbors.iter().all(|n| cell.distance_to(*n) == 1));
+            for radius in 1..5 {
+                let ring = cell.get_ring(radius);
+                assert_eq!(ring.len(), 6 * radius as usize);
+                assert!(ring.iter().all(|c| cell.distance_to(*c) == radius));
+            }
+            assert_eq!(cell.get_range(2).len(), 19);
+        }
+
+        // In odd-q layouts, odd columns are shifted down
+        let even = HexGridCoordinates::new(2, 2).get_neighbors();
+        assert!(even.contains(&HexGridCoordinates::new(1, 1)));
+        assert!(even.contains(&HexGridCoordinates::new(3, 2)));
+        let odd = HexGridCoordinates::new(3, 2).get_neighbors();
+        assert!(odd.contains(&HexGridCoordinates::new(2, 3)));
+        assert!(odd.contains(&HexGridCoordinates::new(4, 3)));
+        assert_eq!(
+            HexGridCoordinates::new(0, 0).distance_to(HexGridCoordinates::new(3, 2)),
+            4
+        );
+    }
+
+    #[test]
+    fn returns_hexes_around_a_point_on_hex_grids() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            sector: SectorSettings {
+                hex_grid: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let center = SpaceCoordinates::new(0, 0, 0);
+        let cell = galaxy
+            .get_hex_grid_coordinates(center)
+            .expect("Should return a cell.");
+        assert_eq!(
+            galaxy
+                .get_coordinates_from_hex_grid(cell)
+                .expect("Should return coordinates."),
+            galaxy.get_hex_top_left_up(galaxy.get_hex_index(center))
+        );
+
+        let adjacent: Vec<GalacticHex> = galaxy
+            .get_adjacent_hexes(center, true)
+            .expect("Should return hexes.")
+            .collect::<Result<_, _>>()
+            .expect("Should generate hexes.");
+        assert_eq!(adjacent.len(), 6);
+        assert!(adjacent
+            .iter()
+            .all(|hex| HexGridCoordinates::from_index(hex.index).distance_to(cell) == 1));
+        let in_range: Vec<GalacticHex> = galaxy
+            .get_hexes_in_range(center, 2)
+            .expect("Should return hexes.")
+            .collect::<Result<_, _>>()
+            .expect("Should generate hexes.");
+        assert_eq!(in_range.len(), 19);
+    }
 }
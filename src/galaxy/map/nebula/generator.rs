@@ -0,0 +1,132 @@
+use crate::internal::*;
+use crate::prelude::*;
+
+impl GalacticNebula {
+    /// Generates the nebulae found in the given [GalacticMapDivision], using its region and the special traits of the [Galaxy].
+    /// Nebulae are centered inside the division and never span more than half of it, so that looking at a division and its direct
+    /// neighbors is enough to find every nebula close to a point.
+    pub fn generate(division: &mut GalacticMapDivision, galaxy: &Galaxy) -> Vec<Self> {
+        let mut rng = SeededDiceRoller::new(
+            &galaxy.settings.seed,
+            &format!("div_{}_{}_neb", division.level, division.index),
+        );
+        let is_star_forming = matches!(
+            division.region,
+            GalacticRegion::Arm
+                | GalacticRegion::Bar
+                | GalacticRegion::OpenCluster
+                | GalacticRegion::Association
+        );
+
+        let mut modifier = 0;
+        galaxy.special_traits.iter().for_each(|t| match t {
+            GalaxySpecialTrait::GasRich
+            | GalaxySpecialTrait::Starburst
+            | GalaxySpecialTrait::Dusty => modifier += 1,
+            GalaxySpecialTrait::GasPoor | GalaxySpecialTrait::Dormant => modifier -= 1,
+            GalaxySpecialTrait::Dead => modifier -= 2,
+            _ => (),
+        });
+        let number_of_nebulae = match division.region {
+            GalacticRegion::Arm
+            | GalacticRegion::Bar
+            | GalacticRegion::OpenCluster
+            | GalacticRegion::Association => rng.roll(1, 6, -3 + modifier),
+            GalacticRegion::Multiple
+            | GalacticRegion::Disk
+            | GalacticRegion::Nucleus
+            | GalacticRegion::Bulge => rng.roll(1, 6, -4 + modifier),
+            GalacticRegion::Ellipse
+            | GalacticRegion::Core
+            | GalacticRegion::Halo
+            | GalacticRegion::GlobularCluster => rng.roll(1, 10, -9 + modifier),
+            GalacticRegion::Aura
+            | GalacticRegion::Void
+            | GalacticRegion::Stream
+            | GalacticRegion::Exile => rng.roll(1, 20, -19 + modifier),
+        }
+        .max(0);
+
+        let start = division.get_top_left_up(galaxy);
+        let size = division.get_size(galaxy);
+        let mut nebulae = Vec::new();
+        for _ in 0..number_of_nebulae {
+            let category = rng
+                .get_result(&CopyableRollToProcess::new(
+                    vec![
+                        CopyableWeightedResult::new(
+                            NebulaCategory::Emission,
+                            if is_star_forming { 4 } else { 1 },
+                        ),
+                        CopyableWeightedResult::new(
+                            NebulaCategory::Reflection,
+                            if is_star_forming { 3 } else { 1 },
+                        ),
+                        CopyableWeightedResult::new(
+                            NebulaCategory::Dark,
+                            if is_star_forming { 4 } else { 2 },
+                        ),
+                        CopyableWeightedResult::new(
+                            NebulaCategory::Planetary,
+                            if is_star_forming { 1 } else { 4 },
+                        ),
+                        CopyableWeightedResult::new(NebulaCategory::SupernovaRemnant, 2),
+                    ],
+                    RollMethod::SimpleRoll,
+                ))
+                .expect("Should have picked a nebula category.");
+            let (radius, density) = match category {
+                NebulaCategory::Emission => (rng.roll(2, 10, 0), rng.roll(1, 21, 9)),
+                NebulaCategory::Reflection => (rng.roll(1, 5, 0), rng.roll(1, 21, 9)),
+                NebulaCategory::Dark => (rng.roll(1, 15, 0), rng.roll(1, 41, 49)),
+                NebulaCategory::Planetary => (1, rng.roll(1, 6, -1)),
+                NebulaCategory::SupernovaRemnant => (rng.roll(1, 10, 0), rng.roll(1, 11, -1)),
+            };
+
+            // Nebulae are rarely perfect spheres, so each axis is stretched or squashed a bit
+            let mut stretch = |axis_size: i64| -> u32 {
+                let stretched = radius * rng.roll(1, 101, 49) / 100;
+                stretched.clamp(1, (axis_size / 2).max(1)) as u32
+            };
+            let radius = (stretch(size.x), stretch(size.y), stretch(size.z));
+            let center = start
+                + SpaceCoordinates::new(
+                    rng.gen_range(0..size.x.max(1)),
+                    rng.gen_range(0..size.y.max(1)),
+                    rng.gen_range(0..size.z.max(1)),
+                );
+            nebulae.push(Self::new(category, center, radius, density as u8));
+        }
+        nebulae
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_nebulae_inside_their_division() {
+        for i in 0..100 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+            let mut galaxy = Galaxy::generate(neighborhood, (i as u16) % 5, &settings);
+            let mut division = galaxy
+                .get_division_at_level(SpaceCoordinates::new(0, 0, 0), 2)
+                .expect("Should have returned a division.");
+            let start = division.get_top_left_up(&galaxy);
+            let end = division.get_bottom_right_down(&galaxy);
+            for nebula in division.nebulae.iter() {
+                assert!(nebula.center.x >= start.x && nebula.center.x < end.x);
+                assert!(nebula.center.y >= start.y && nebula.center.y < end.y);
+                assert!(nebula.center.z >= start.z && nebula.center.z < end.z);
+                assert!(nebula.contains(nebula.center));
+                assert!(nebula.density <= 100);
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::internal::*;
+use crate::prelude::*;
+pub mod generator;
+
+/// A cloud of gas and dust spanning a number of [GalacticHex]es. Nebulae hide the stars behind and inside them, and are often the
+/// birthplace of new ones.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize, Deserialize,
+)]
+pub struct GalacticNebula {
+    /// What kind of nebula this is.
+    pub category: NebulaCategory,
+    /// The center of this nebula. Relative to the galactic center.
+    pub center: SpaceCoordinates,
+    /// The radius of this nebula in parsecs on the (x, y, z) axis, as nebulae are shaped like more or less stretched ellipsoids.
+    pub radius: (u32, u32, u32),
+    /// How much of the light of the stars inside or behind this nebula is blocked, in percents.
+    pub density: u8,
+}
+
+impl Display for GalacticNebula {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of radius ({}, {}, {})pc centered on {}, blocking {}% of light",
+            self.category, self.radius.0, self.radius.1, self.radius.2, self.center, self.density
+        )
+    }
+}
+
+impl GalacticNebula {
+    /// Creates a new [GalacticNebula].
+    pub fn new(
+        category: NebulaCategory,
+        center: SpaceCoordinates,
+        radius: (u32, u32, u32),
+        density: u8,
+    ) -> Self {
+        Self {
+            category,
+            center,
+            radius,
+            density,
+        }
+    }
+
+    /// Returns true if the given coordinates are inside this nebula.
+    pub fn contains(&self, coord: SpaceCoordinates) -> bool {
+        self.get_scaled_distance(coord) <= 1.0
+    }
+
+    /// Returns how big this nebula appears in the sky when seen from the given coordinates, or [None] if it is too far away to be
+    /// noticeable.
+    pub fn get_apparent_size_from(&self, coord: SpaceCoordinates) -> Option<NebulaeApparentSize> {
+        let scaled_distance = self.get_scaled_distance(coord);
+        if scaled_distance <= 1.0 {
+            Some(NebulaeApparentSize::Dominant)
+        } else if scaled_distance <= 2.0 {
+            Some(NebulaeApparentSize::Large)
+        } else if scaled_distance <= 6.0 {
+            Some(NebulaeApparentSize::Small)
+        } else if scaled_distance <= 20.0 {
+            Some(NebulaeApparentSize::Tiny)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the distance between the given coordinates and the center of this nebula, expressed in nebula radii. A value of 1 or
+    /// less means the coordinates are inside the nebula.
+    fn get_scaled_distance(&self, coord: SpaceCoordinates) -> f64 {
+        let dx = (coord.x - self.center.x) as f64 / self.radius.0.max(1) as f64;
+        let dy = (coord.y - self.center.y) as f64 / self.radius.1.max(1) as f64;
+        let dz = (coord.z - self.center.z) as f64 / self.radius.2.max(1) as f64;
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
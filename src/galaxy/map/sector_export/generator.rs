@@ -0,0 +1,278 @@
+use crate::internal::*;
+use crate::prelude::*;
+
+/// The header of T5 tab-delimited sector files.
+const T5_HEADER: &str = "Hex\tName\tUWP\tBases\tRemarks\tZone\tPBG\tAllegiance\tStars";
+
+impl T5SectorExport {
+    /// Exports the [StarSystem]s of the given subsector or sector (a [GalacticMapDivision] of level 1 or 2) in the T5 tab-delimited
+    /// format, generating its hexes if needed. Only flat maps can be exported, and only the first system of each hex is written, as
+    /// the format allows a single world per hex.
+    pub fn generate(galaxy: &mut Galaxy, division: &GalacticMapDivision) -> Result<Self, Rc<str>> {
+        if division.level != 1 && division.level != 2 {
+            return Err("Only subsectors and sectors (levels 1 and 2) can be exported.".into());
+        }
+        if !galaxy.settings.sector.flat_map {
+            return Err("Only flat maps can be exported.".into());
+        }
+
+        let first_hex = galaxy.get_hex_index(division.get_top_left_up(galaxy));
+        let hexes: Vec<GalacticHex> = galaxy
+            .get_hexes_in_division(division)
+            .collect::<Result<_, _>>()?;
+        let mut lines = vec![String::from(T5_HEADER)];
+        for hex in hexes.iter() {
+            if let Some(system) = hex.contents.first() {
+                let column = hex.index.x - first_hex.x + 1;
+                let row = hex.index.y - first_hex.y + 1;
+                lines.push(
+                    [
+                        format!("{:02}{:02}", column, row),
+                        format!("{}", system.name),
+                        get_uwp(system),
+                        String::new(),
+                        String::new(),
+                        String::new(),
+                        get_pbg(system),
+                        String::from("Na"),
+                        get_stars(system),
+                    ]
+                    .join("\t"),
+                );
+            }
+        }
+        let data = lines.join("\n") + "\n";
+        let metadata = generate_metadata(galaxy, division);
+
+        Ok(Self::new(data.into(), metadata.into()))
+    }
+}
+
+/// Returns the UWP of the main world of the given system, or of its largest body if it has no telluric body. Digits that cannot be
+/// derived yet are written as "?".
+fn get_uwp(system: &StarSystem) -> String {
+    let main_world = system.get_main_world().or_else(|| {
+        system
+            .all_objects
+            .iter()
+            .filter_map(|point| match &point.object {
+                AstronomicalObject::IcyBody(body) | AstronomicalObject::GaseousBody(body) => {
+                    Some(body)
+                }
+                _ => None,
+            })
+            .max_by(|a, b| {
+                a.radius
+                    .partial_cmp(&b.radius)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    });
+    main_world.map_or(String::from("???????-?"), |body| {
+        format!("{}", body.get_uwp())
+    })
+}
+
+/// Returns the PBG code of the given system: its population multiplier, its number of planetoid belts and its number of gas giants.
+/// TODO: Use the actual population multiplier once systems are populated.
+fn get_pbg(system: &StarSystem) -> String {
+    let belts = system
+        .all_objects
+        .iter()
+        .filter(|point| {
+            matches!(
+                point.object,
+                AstronomicalObject::TelluricDisk(_) | AstronomicalObject::IcyDisk(_)
+            ) && point.own_orbit.as_ref().is_some_and(|orbit| {
+                system
+                    .get_point(orbit.primary_body_id)
+                    .is_some_and(|primary| {
+                        matches!(
+                            primary.object,
+                            AstronomicalObject::Star(_) | AstronomicalObject::Void
+                        )
+                    })
+            })
+        })
+        .count();
+    let gas_giants = system
+        .all_objects
+        .iter()
+        .filter(|point| matches!(point.object, AstronomicalObject::GaseousBody(_)))
+        .count();
+    format!("0{}{}", belts.min(9), gas_giants.min(9))
+}
+
+/// Returns the Traveller stellar codes of the stars of the given system, starting with its main star.
+fn get_stars(system: &StarSystem) -> String {
+    let mut stars: Vec<&Star> = system
+        .all_objects
+        .iter()
+        .filter_map(|point| match &point.object {
+            AstronomicalObject::Star(star) => Some(star),
+            _ => None,
+        })
+        .collect();
+    stars.sort_by_key(|star| star.orbital_point_id != system.main_star_id);
+    stars
+        .iter()
+        .map(|star| get_star_code(star))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Returns the Traveller stellar code of the given star, like "G2 V", "D" for white dwarfs or "BD" for brown dwarfs.
+fn get_star_code(star: &Star) -> String {
+    let luminosity = match star.luminosity_class {
+        StarLuminosityClass::O | StarLuminosityClass::Ia => "Ia",
+        StarLuminosityClass::Ib => "Ib",
+        StarLuminosityClass::II => "II",
+        StarLuminosityClass::III => "III",
+        StarLuminosityClass::IV => "IV",
+        StarLuminosityClass::VI => "VI",
+        _ => "V",
+    };
+    match star.spectral_type {
+        StarSpectralType::WR(d) | StarSpectralType::O(d) => format!("O{} {}", d, luminosity),
+        StarSpectralType::B(d) => format!("B{} {}", d, luminosity),
+        StarSpectralType::A(d) => format!("A{} {}", d, luminosity),
+        StarSpectralType::F(d) => format!("F{} {}", d, luminosity),
+        StarSpectralType::G(d) => format!("G{} {}", d, luminosity),
+        StarSpectralType::K(d) => format!("K{} {}", d, luminosity),
+        StarSpectralType::M(d) => format!("M{} {}", d, luminosity),
+        StarSpectralType::L(_) | StarSpectralType::T(_) | StarSpectralType::Y(_) => "BD".into(),
+        StarSpectralType::DA
+        | StarSpectralType::DB
+        | StarSpectralType::DC
+        | StarSpectralType::DO
+        | StarSpectralType::DZ
+        | StarSpectralType::DQ
+        | StarSpectralType::DX => "D".into(),
+        StarSpectralType::XNS => "NS".into(),
+        StarSpectralType::XBH => "BH".into(),
+    }
+}
+
+/// Returns the metadata XML of the given division, naming it and its subsectors. A subsector is exported as a sector made of a single
+/// subsector.
+fn generate_metadata(galaxy: &mut Galaxy, division: &GalacticMapDivision) -> String {
+    let mut subsectors = Vec::new();
+    if division.level == 1 {
+        subsectors.push(('A', division.name.clone()));
+    } else {
+        let mut sector = division.clone();
+        let top_left = sector.get_top_left_up(galaxy);
+        let level = galaxy
+            .division_levels
+            .iter()
+            .find(|l| l.level == 2)
+            .expect("The division levels should be set")
+            .as_coord();
+        let subsector_size = sector.get_size(galaxy) / level;
+        let mut letter = b'A';
+        for y in 0..level.y {
+            for x in 0..level.x {
+                if letter > b'Z' {
+                    break;
+                }
+                let coord = top_left + subsector_size * SpaceCoordinates::new(x, y, 0);
+                if let Ok(subsector) = galaxy.get_division_at_level(coord, 1) {
+                    subsectors.push((letter as char, subsector.name));
+                }
+                letter += 1;
+            }
+        }
+    }
+
+    let mut lines = vec![
+        String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>"),
+        String::from("<Sector>"),
+        format!("  <Name>{}</Name>", escape_xml(&division.name)),
+        String::from("  <Subsectors>"),
+    ];
+    for (index, name) in subsectors {
+        lines.push(format!(
+            "    <Subsector Index=\"{}\">{}</Subsector>",
+            index,
+            escape_xml(&name)
+        ));
+    }
+    lines.push(String::from("  </Subsectors>"));
+    lines.push(String::from("</Sector>"));
+    lines.join("\n") + "\n"
+}
+
+/// Escapes the characters of the given text that have a special meaning in XML.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_divisions_in_t5_format() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let subsector = galaxy
+            .get_division_at_level(SpaceCoordinates::new(0, 0, 0), 1)
+            .expect("Should return a div.");
+        let export = T5SectorExport::generate(&mut galaxy, &subsector).expect("Should export.");
+        let lines: Vec<&str> = export.data.lines().collect();
+        assert_eq!(lines[0], T5_HEADER);
+        assert!(lines.len() > 1);
+        for line in lines.iter().skip(1) {
+            let fields: Vec<&str> = line.split('\t').collect();
+            assert_eq!(fields.len(), 9);
+            let column: u32 = fields[0][0..2].parse().expect("Should be a number.");
+            let row: u32 = fields[0][2..4].parse().expect("Should be a number.");
+            assert!((1..=10).contains(&column) && (1..=10).contains(&row));
+            assert_eq!(fields[2].len(), 9);
+            assert_eq!(fields[6].len(), 3);
+            assert!(!fields[8].is_empty());
+        }
+        assert!(export
+            .metadata
+            .contains(&format!("<Name>{}</Name>", subsector.name)));
+        assert_eq!(export.metadata.matches("<Subsector ").count(), 1);
+
+        let sector = galaxy
+            .get_division_at_level(SpaceCoordinates::new(0, 0, 0), 2)
+            .expect("Should return a div.");
+        let export = T5SectorExport::generate(&mut galaxy, &sector).expect("Should export.");
+        assert_eq!(export.metadata.matches("<Subsector ").count(), 16);
+        assert!(export.metadata.contains(&format!(
+            "<Subsector Index=\"{}\">{}</Subsector>",
+            (b'A' + (subsector.y * 4 + subsector.x)) as char,
+            subsector.name
+        )));
+
+        let other = galaxy
+            .get_division_at_level(SpaceCoordinates::new(0, 0, 0), 3)
+            .expect("Should return a div.");
+        assert!(T5SectorExport::generate(&mut galaxy, &other).is_err());
+    }
+
+    #[test]
+    fn convert_stars_to_traveller_codes() {
+        let mut star = Star::default();
+        assert_eq!(get_star_code(&star), "G2 V");
+        star.spectral_type = StarSpectralType::K(5);
+        star.luminosity_class = StarLuminosityClass::III;
+        assert_eq!(get_star_code(&star), "K5 III");
+        star.spectral_type = StarSpectralType::DA;
+        star.luminosity_class = StarLuminosityClass::VII;
+        assert_eq!(get_star_code(&star), "D");
+        star.spectral_type = StarSpectralType::T(4);
+        star.luminosity_class = StarLuminosityClass::Y;
+        assert_eq!(get_star_code(&star), "BD");
+    }
+}
@@ -0,0 +1,26 @@
+use crate::internal::*;
+use crate::prelude::*;
+pub mod generator;
+
+/// A [GalacticMapDivision] exported in the T5 tab-delimited sector format used by TravellerMap and similar tools, along with the
+/// matching metadata.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize)]
+pub struct T5SectorExport {
+    /// The content of the tab-delimited sector file, one line per system after the header.
+    pub data: Rc<str>,
+    /// The content of the metadata XML file, naming the sector and its subsectors.
+    pub metadata: Rc<str>,
+}
+
+impl T5SectorExport {
+    /// Creates a new [T5SectorExport].
+    pub fn new(data: Rc<str>, metadata: Rc<str>) -> Self {
+        Self { data, metadata }
+    }
+}
+
+impl Display for T5SectorExport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.data)
+    }
+}
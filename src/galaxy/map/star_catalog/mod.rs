@@ -0,0 +1,48 @@
+use crate::internal::*;
+use crate::prelude::*;
+pub mod parser;
+pub mod types;
+
+/// A catalog of real stars, like the HYG database, to pin into a [Galaxy] in place of randomly generated systems. Planets are then
+/// generated around the catalog's stars as usual.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct StarCatalog {
+    /// Where the origin of the catalog's positions, usually our Sun, lies in the galaxy. Relative to the galactic center.
+    pub origin: SpaceCoordinates,
+    /// The distance in parsecs from the origin up to which the catalog is considered complete. No other system is generated within
+    /// that distance.
+    pub radius: f64,
+    /// The systems listed in the catalog.
+    pub systems: Vec<CatalogSystem>,
+}
+
+impl StarCatalog {
+    /// Creates a new [StarCatalog] whose radius is the distance of its farthest system.
+    pub fn new(origin: SpaceCoordinates, systems: Vec<CatalogSystem>) -> Self {
+        let radius = systems
+            .iter()
+            .map(|system| system.get_distance())
+            .fold(0.0, f64::max);
+        Self {
+            origin,
+            radius,
+            systems,
+        }
+    }
+}
+
+impl Display for StarCatalog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Catalog of {} systems and {} stars within {:.2}pc of {}",
+            self.systems.len(),
+            self.systems
+                .iter()
+                .map(|system| system.stars.len())
+                .sum::<usize>(),
+            self.radius,
+            self.origin
+        )
+    }
+}
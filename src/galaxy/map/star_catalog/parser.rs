@@ -0,0 +1,337 @@
+use crate::internal::*;
+use crate::prelude::*;
+use std::collections::HashMap;
+
+impl StarCatalog {
+    /// Reads a star catalog from the content of a HYG-style CSV file, whose first line names the columns. Positions are read from the
+    /// `x`, `y` and `z` columns, in parsecs from **origin**. The other columns used are, when present:
+    /// - `proper` or `name`, then `gl`, `hip` or `hd` for unnamed stars.
+    /// - `spect` or `spectral_type`, like "G2V", "M3.5Ve", "sdK7" or "DA2".
+    /// - `absmag` or `absolute_magnitude`, or else `mag` and `dist` to compute it.
+    /// - `mass`, in solar masses.
+    /// - `id` and `comp_primary`, to group companion stars into the same system.
+    ///
+    /// Systems farther than **max_distance** parsecs from the origin are skipped, and the catalog is then considered complete up to
+    /// that distance.
+    pub fn from_csv(
+        content: &str,
+        origin: SpaceCoordinates,
+        max_distance: Option<f64>,
+    ) -> Result<Self, Rc<str>> {
+        let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+        let header: Vec<String> = split_csv_line(lines.next().ok_or("The catalog is empty.")?)
+            .iter()
+            .map(|column| column.to_lowercase())
+            .collect();
+        let column = |names: &[&str]| -> Option<usize> {
+            names
+                .iter()
+                .find_map(|name| header.iter().position(|column| column == name))
+        };
+        let x = column(&["x"]).ok_or("Missing column: x.")?;
+        let y = column(&["y"]).ok_or("Missing column: y.")?;
+        let z = column(&["z"]).ok_or("Missing column: z.")?;
+        let absolute_magnitude = column(&["absmag", "absolute_magnitude"]);
+        let magnitude = column(&["mag"]);
+        let distance = column(&["dist"]);
+        if absolute_magnitude.is_none() && (magnitude.is_none() || distance.is_none()) {
+            return Err("Missing column: absmag, or mag and dist.".into());
+        }
+        let names = [
+            (column(&["proper", "name"]), ""),
+            (column(&["gl"]), ""),
+            (column(&["hip"]), "HIP "),
+            (column(&["hd"]), "HD "),
+        ];
+        let spectral_class = column(&["spect", "spectral_type"]);
+        let mass = column(&["mass"]);
+        let id = column(&["id"]);
+        let primary = column(&["comp_primary"]);
+
+        // Stars are grouped by the id of their system's primary, keeping the catalog's order.
+        let mut groups: Vec<(String, Vec<(bool, (f64, f64, f64), CatalogStar)>)> = Vec::new();
+        let mut group_indexes: HashMap<String, usize> = HashMap::new();
+        for (line_index, line) in lines.enumerate() {
+            let fields = split_csv_line(line);
+            let field = |index: Option<usize>| -> &str {
+                index
+                    .and_then(|i| fields.get(i))
+                    .map_or("", |value| value.trim())
+            };
+            let number = |index: Option<usize>| -> Result<f64, Rc<str>> {
+                field(index).parse::<f64>().map_err(|_| {
+                    format!("Invalid number on line {} of the catalog.", line_index + 2).into()
+                })
+            };
+
+            let position = (number(Some(x))?, number(Some(y))?, number(Some(z))?);
+            let absolute_magnitude = if absolute_magnitude.is_some() {
+                number(absolute_magnitude)?
+            } else {
+                number(magnitude)? - 5.0 * (number(distance)?.max(0.000001).log10() - 1.0)
+            };
+            let name: Rc<str> = names
+                .iter()
+                .find(|(index, _)| !field(*index).is_empty())
+                .map_or(String::new(), |(index, prefix)| {
+                    format!("{}{}", prefix, field(*index))
+                })
+                .into();
+            let (spectral_type, luminosity_class) = parse_spectral_class(field(spectral_class));
+            let mass = number(mass).ok().filter(|mass| *mass > 0.0);
+
+            let own_id = if id.is_some() {
+                String::from(field(id))
+            } else {
+                format!("line_{}", line_index)
+            };
+            let primary_id = match field(primary) {
+                "" => own_id.clone(),
+                primary_id => String::from(primary_id),
+            };
+            let star = CatalogStar::new(
+                name,
+                spectral_type,
+                luminosity_class,
+                absolute_magnitude as f32,
+                mass,
+            );
+            let entry = (primary_id == own_id, position, star);
+            if let Some(&group) = group_indexes.get(&primary_id) {
+                groups[group].1.push(entry);
+            } else {
+                group_indexes.insert(primary_id.clone(), groups.len());
+                groups.push((primary_id, vec![entry]));
+            }
+        }
+
+        let systems: Vec<CatalogSystem> = groups
+            .into_iter()
+            .map(|(_, mut stars)| {
+                let primary = stars.iter().position(|(is_primary, _, _)| *is_primary);
+                let (_, position, primary_star) = &stars[primary.unwrap_or(0)];
+                let position = *position;
+                let name = primary_star.name.clone();
+                stars.sort_by(|(_, _, a), (_, _, b)| {
+                    a.absolute_magnitude
+                        .partial_cmp(&b.absolute_magnitude)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                CatalogSystem::new(
+                    name,
+                    position,
+                    stars.into_iter().map(|(_, _, star)| star).collect(),
+                )
+            })
+            .filter(|system| max_distance.is_none_or(|max| system.get_distance() <= max))
+            .collect();
+
+        let mut catalog = Self::new(origin, systems);
+        if let Some(max_distance) = max_distance {
+            catalog.radius = max_distance;
+        }
+        Ok(catalog)
+    }
+}
+
+/// Splits a line of a CSV file into its fields, handling quoted fields.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Reads a spectral classification like "G2V", "K0III-IV", "sdM4" or "DA2" and returns the spectral type and luminosity class it
+/// describes, if they could be understood.
+fn parse_spectral_class(text: &str) -> (Option<StarSpectralType>, Option<StarLuminosityClass>) {
+    let mut text = text.trim();
+    let mut luminosity_class = None;
+    for prefix in ["esd", "usd", "sd"] {
+        if let Some(rest) = text.strip_prefix(prefix) {
+            text = rest;
+            luminosity_class = Some(StarLuminosityClass::VI);
+            break;
+        }
+    }
+
+    let mut chars = text.chars();
+    let Some(letter) = chars.next() else {
+        return (None, luminosity_class);
+    };
+    let rest = chars.as_str();
+    if letter == 'D' {
+        let spectral_type = match rest.chars().next() {
+            Some('B') => StarSpectralType::DB,
+            Some('C') => StarSpectralType::DC,
+            Some('O') => StarSpectralType::DO,
+            Some('Z') => StarSpectralType::DZ,
+            Some('Q') => StarSpectralType::DQ,
+            Some('X') => StarSpectralType::DX,
+            _ => StarSpectralType::DA,
+        };
+        return (Some(spectral_type), Some(StarLuminosityClass::VII));
+    }
+
+    // Skip the Wolf-Rayet sub-category (WN, WC, WO) if any, then read the subtype, ignoring decimals.
+    let rest = if letter == 'W' {
+        rest.trim_start_matches(['N', 'C', 'O', 'R'])
+    } else {
+        rest
+    };
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let subtype = digits
+        .chars()
+        .next()
+        .and_then(|c| c.to_digit(10))
+        .map_or(5, |d| d as u8);
+    let rest = rest[digits.len()..].trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let spectral_type = match letter {
+        'W' => StarSpectralType::WR(subtype),
+        'O' => StarSpectralType::O(subtype),
+        'B' => StarSpectralType::B(subtype),
+        'A' => StarSpectralType::A(subtype),
+        'F' => StarSpectralType::F(subtype),
+        'G' => StarSpectralType::G(subtype),
+        'K' => StarSpectralType::K(subtype),
+        'M' => StarSpectralType::M(subtype),
+        'L' => StarSpectralType::L(subtype),
+        'T' => StarSpectralType::T(subtype),
+        'Y' => StarSpectralType::Y(subtype),
+        _ => return (None, luminosity_class),
+    };
+    if matches!(
+        spectral_type,
+        StarSpectralType::L(_) | StarSpectralType::T(_) | StarSpectralType::Y(_)
+    ) {
+        return (Some(spectral_type), Some(StarLuminosityClass::Y));
+    }
+
+    let rest = rest.trim_start();
+    let classes = [
+        ("VII", StarLuminosityClass::VII),
+        ("VI", StarLuminosityClass::VI),
+        ("V", StarLuminosityClass::V),
+        ("IV", StarLuminosityClass::IV),
+        ("III", StarLuminosityClass::III),
+        ("II", StarLuminosityClass::II),
+        ("Iab", StarLuminosityClass::Ib),
+        ("Ia0", StarLuminosityClass::O),
+        ("Ia", StarLuminosityClass::Ia),
+        ("Ib", StarLuminosityClass::Ib),
+        ("I", StarLuminosityClass::Ib),
+        ("0", StarLuminosityClass::O),
+    ];
+    if luminosity_class.is_none() {
+        luminosity_class = classes
+            .iter()
+            .find(|(prefix, _)| rest.starts_with(prefix))
+            .map(|(_, class)| *class);
+    }
+    (Some(spectral_type), luminosity_class)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_spectral_classes_from_catalogs() {
+        assert_eq!(
+            parse_spectral_class("G2V"),
+            (Some(StarSpectralType::G(2)), Some(StarLuminosityClass::V))
+        );
+        assert_eq!(
+            parse_spectral_class("M3.5Ve"),
+            (Some(StarSpectralType::M(3)), Some(StarLuminosityClass::V))
+        );
+        assert_eq!(
+            parse_spectral_class("K0III-IV"),
+            (Some(StarSpectralType::K(0)), Some(StarLuminosityClass::III))
+        );
+        assert_eq!(
+            parse_spectral_class("sdM4"),
+            (Some(StarSpectralType::M(4)), Some(StarLuminosityClass::VI))
+        );
+        assert_eq!(
+            parse_spectral_class("DA2"),
+            (Some(StarSpectralType::DA), Some(StarLuminosityClass::VII))
+        );
+        assert_eq!(
+            parse_spectral_class("A1Iab"),
+            (Some(StarSpectralType::A(1)), Some(StarLuminosityClass::Ib))
+        );
+        assert_eq!(
+            parse_spectral_class("T8"),
+            (Some(StarSpectralType::T(8)), Some(StarLuminosityClass::Y))
+        );
+        assert_eq!(
+            parse_spectral_class("F"),
+            (Some(StarSpectralType::F(5)), None)
+        );
+        assert_eq!(parse_spectral_class(""), (None, None));
+    }
+
+    #[test]
+    fn read_hyg_style_catalogs() {
+        let content = "id,proper,gl,dist,mag,absmag,spect,x,y,z,comp_primary\n\
+            0,Sol,,0,-26.7,4.85,G2V,0,0,0,0\n\
+            70666,Proxima Centauri,Gl 551,1.3,11.1,15.45,M5Ve,-0.47,-0.36,-1.15,70666\n\
+            71453,Rigil Kentaurus,Gl 559A,1.35,-0.01,4.38,G2V,-0.5,-0.42,-1.18,71453\n\
+            71454,,Gl 559B,1.35,1.35,5.71,K1V,-0.5,-0.42,-1.18,71453\n\
+            \"99999\",\"Far, away\",,30,9,6.6,K3V,30,0,0,99999\n";
+        let catalog = StarCatalog::from_csv(content, SpaceCoordinates::new(10, 20, 0), Some(20.0))
+            .expect("Should read the catalog.");
+        assert_eq!(catalog.systems.len(), 3);
+        assert_eq!(catalog.radius, 20.0);
+        assert_eq!(&*catalog.systems[0].name, "Sol");
+        let alpha_centauri = &catalog.systems[2];
+        assert_eq!(&*alpha_centauri.name, "Rigil Kentaurus");
+        assert_eq!(alpha_centauri.stars.len(), 2);
+        assert_eq!(&*alpha_centauri.stars[1].name, "Gl 559B");
+        assert!((catalog.systems[0].stars[0].get_luminosity() - 0.98).abs() < 0.01);
+
+        let catalog = StarCatalog::from_csv(content, SpaceCoordinates::new(0, 0, 0), None)
+            .expect("Should read the catalog.");
+        assert_eq!(catalog.systems.len(), 4);
+        assert_eq!(&*catalog.systems[3].name, "Far, away");
+        assert_eq!(catalog.radius, 30.0);
+        assert!(StarCatalog::from_csv("id,x,y\n0,0,0", SpaceCoordinates::default(), None).is_err());
+    }
+
+    #[test]
+    fn correct_catalog_luminosities_for_light_outside_the_visible_spectrum() {
+        let content = "id,proper,gl,dist,mag,absmag,spect,x,y,z,comp_primary\n\
+            87665,Barnard's Star,Gl 699,1.83,9.54,13.21,M4Ve,-0.02,-1.82,0.15,87665\n\
+            70666,Proxima Centauri,Gl 551,1.3,11.1,15.45,M5.5Ve,-0.47,-0.36,-1.15,70666\n\
+            49528,Regulus,Gl 9313,24.31,1.36,-0.57,B8IVn,-19.06,10.35,10.65,49528\n";
+        let catalog = StarCatalog::from_csv(content, SpaceCoordinates::new(0, 0, 0), None)
+            .expect("Should read the catalog.");
+        let luminosity = |name: &str| {
+            catalog
+                .systems
+                .iter()
+                .find(|system| &*system.name == name)
+                .expect("Should find the system.")
+                .stars[0]
+                .get_luminosity()
+        };
+        // Measured at 0.0035, 0.0016 and 290 solar luminosities.
+        assert!((luminosity("Barnard's Star") - 0.0035).abs() < 0.0005);
+        assert!(luminosity("Proxima Centauri") > 0.0006 && luminosity("Proxima Centauri") < 0.003);
+        assert!(luminosity("Regulus") > 200.0 && luminosity("Regulus") < 350.0);
+    }
+}
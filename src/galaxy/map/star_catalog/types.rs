@@ -0,0 +1,114 @@
+use crate::internal::*;
+use crate::prelude::*;
+use crate::system::star::generator::get_bolometric_correction;
+
+/// The absolute bolometric magnitude of the Sun, used to turn magnitudes into luminosities.
+const SUN_BOLOMETRIC_MAGNITUDE: f32 = 4.74;
+
+/// A star listed in a [StarCatalog].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct CatalogStar {
+    /// The name of this star, empty if the catalog gives none.
+    pub name: Rc<str>,
+    /// The spectral type of this star, if it could be read from the catalog.
+    pub spectral_type: Option<StarSpectralType>,
+    /// The luminosity class of this star, if it could be read from the catalog.
+    pub luminosity_class: Option<StarLuminosityClass>,
+    /// The absolute visual magnitude of this star.
+    pub absolute_magnitude: f32,
+    /// The mass of this star in solar masses, if the catalog gives it.
+    pub mass: Option<f64>,
+}
+
+impl CatalogStar {
+    /// Creates a new [CatalogStar].
+    pub fn new(
+        name: Rc<str>,
+        spectral_type: Option<StarSpectralType>,
+        luminosity_class: Option<StarLuminosityClass>,
+        absolute_magnitude: f32,
+        mass: Option<f64>,
+    ) -> Self {
+        Self {
+            name,
+            spectral_type,
+            luminosity_class,
+            absolute_magnitude,
+            mass,
+        }
+    }
+
+    /// Returns the luminosity of this star in solar luminosities, estimated from its absolute magnitude corrected for the light its
+    /// spectral type emits outside of the visible spectrum. Stars of unknown type are corrected as if they were Sun-like.
+    pub fn get_luminosity(&self) -> f32 {
+        let bolometric_magnitude = self.absolute_magnitude
+            + get_bolometric_correction(self.spectral_type.unwrap_or(StarSpectralType::G(2)));
+        10.0_f32.powf((SUN_BOLOMETRIC_MAGNITUDE - bolometric_magnitude) / 2.5)
+    }
+}
+
+impl Display for CatalogStar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}, absolute magnitude {}{}",
+            if self.name.is_empty() {
+                "Unnamed star"
+            } else {
+                &self.name
+            },
+            self.spectral_type
+                .map_or(String::new(), |spectral_type| format!(" {}", spectral_type)),
+            self.luminosity_class
+                .map_or(String::new(), |class| format!(" {}", class)),
+            self.absolute_magnitude,
+            self.mass
+                .map_or(String::new(), |mass| format!(", {} solar masses", mass))
+        )
+    }
+}
+
+/// A group of [CatalogStar]s orbiting each other, that will become a single [StarSystem].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct CatalogSystem {
+    /// The name of this system.
+    pub name: Rc<str>,
+    /// The position of this system in parsecs on the (x, y, z) axis, relative to the origin of its catalog.
+    pub position: (f64, f64, f64),
+    /// The stars of this system, the brightest one first.
+    pub stars: Vec<CatalogStar>,
+}
+
+impl CatalogSystem {
+    /// Creates a new [CatalogSystem].
+    pub fn new(name: Rc<str>, position: (f64, f64, f64), stars: Vec<CatalogStar>) -> Self {
+        Self {
+            name,
+            position,
+            stars,
+        }
+    }
+
+    /// Returns the distance in parsecs between this system and the origin of its catalog.
+    pub fn get_distance(&self) -> f64 {
+        (self.position.0.powi(2) + self.position.1.powi(2) + self.position.2.powi(2)).sqrt()
+    }
+}
+
+impl Display for CatalogSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at ({:.2}, {:.2}, {:.2})pc: {}",
+            self.name,
+            self.position.0,
+            self.position.1,
+            self.position.2,
+            self.stars
+                .iter()
+                .map(|s| format!("{}", s))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
@@ -0,0 +1,356 @@
+use crate::internal::*;
+use crate::prelude::*;
+
+/// How many times we try to find a suitable place for a sub-region before giving up on it.
+const MAX_PLACEMENT_TRIES: u16 = 100;
+
+impl GalacticSubRegion {
+    /// Generates the star clusters, associations, stellar streams and groups of exiled stars of the given [Galaxy]. Globular
+    /// clusters are placed in its halo, open clusters and associations where stars are being formed, streams only appear around
+    /// galaxies with a tail or interacting with others, and exiles are lost in the void around the galaxy.
+    pub fn generate(galaxy: &Galaxy) -> Vec<Self> {
+        let mut rng = SeededDiceRoller::new(
+            &galaxy.settings.seed,
+            &format!("gal_{}_sub_reg", galaxy.index),
+        );
+        let mut result = Vec::new();
+        let (globular_regions, young_regions) = match galaxy.category {
+            GalaxyCategory::Spiral(_, _) => (vec![GalacticRegion::Halo], vec![GalacticRegion::Arm]),
+            GalaxyCategory::Lenticular(_, _) => {
+                (vec![GalacticRegion::Halo], vec![GalacticRegion::Disk])
+            }
+            GalaxyCategory::Elliptical(_) | GalaxyCategory::DominantElliptical(_) => {
+                (vec![GalacticRegion::Halo], vec![GalacticRegion::Ellipse])
+            }
+            GalaxyCategory::Intergalactic(_, _, _)
+            | GalaxyCategory::Irregular(_, _, _)
+            | GalaxyCategory::Intracluster(_, _, _) => (
+                vec![GalacticRegion::Multiple, GalacticRegion::Aura],
+                vec![GalacticRegion::Multiple],
+            ),
+        };
+
+        for _ in 0..get_number_of_globular_clusters(galaxy, &mut rng) {
+            if let Some(center) = find_point_in(galaxy, &globular_regions, &mut rng) {
+                let radius = rng.roll(2, 20, 5) as u32;
+                result.push(Self::new(
+                    GalacticRegion::GlobularCluster,
+                    center,
+                    center,
+                    radius,
+                ));
+            }
+        }
+
+        let number_of_open_clusters = get_number_of_open_clusters(galaxy, &mut rng);
+        for _ in 0..number_of_open_clusters {
+            if let Some(center) = find_star_forming_point(galaxy, &young_regions, &mut rng) {
+                let radius = rng.roll(2, 4, 1) as u32;
+                result.push(Self::new(
+                    GalacticRegion::OpenCluster,
+                    center,
+                    center,
+                    radius,
+                ));
+            }
+        }
+
+        for _ in 0..number_of_open_clusters / 4 {
+            if let Some(center) = find_star_forming_point(galaxy, &young_regions, &mut rng) {
+                let radius = rng.roll(3, 20, 10) as u32;
+                result.push(Self::new(
+                    GalacticRegion::Association,
+                    center,
+                    center,
+                    radius,
+                ));
+            }
+        }
+
+        for _ in 0..get_number_of_streams(galaxy, &mut rng) {
+            if let Some(stream) = generate_stream(galaxy, &mut rng) {
+                result.push(stream);
+            }
+        }
+
+        let number_of_exiles = rng.roll(1, 6, -1)
+            + if galaxy
+                .special_traits
+                .contains(&GalaxySpecialTrait::Interacting)
+            {
+                rng.roll(1, 6, 0)
+            } else {
+                0
+            };
+        for _ in 0..number_of_exiles {
+            if let Some(center) = find_point_in(
+                galaxy,
+                &[GalacticRegion::Void, GalacticRegion::Aura],
+                &mut rng,
+            ) {
+                let radius = rng.roll(1, 10, 2) as u32;
+                result.push(Self::new(GalacticRegion::Exile, center, center, radius));
+            }
+        }
+
+        result
+    }
+}
+
+/// Returns how many globular clusters orbit the given [Galaxy].
+fn get_number_of_globular_clusters(galaxy: &Galaxy, rng: &mut SeededDiceRoller) -> u32 {
+    let number = match galaxy.sub_category {
+        GalaxySubCategory::DwarfAmorphous
+        | GalaxySubCategory::DwarfSpiral
+        | GalaxySubCategory::DwarfLenticular => rng.roll(1, 6, -1),
+        GalaxySubCategory::DwarfElliptical | GalaxySubCategory::Amorphous => rng.roll(1, 10, 0),
+        GalaxySubCategory::GiantElliptical => {
+            if let GalaxyCategory::DominantElliptical(_) = galaxy.category {
+                rng.roll(4, 6, 0) * 25
+            } else {
+                rng.roll(3, 6, 0) * 20
+            }
+        }
+        GalaxySubCategory::CommonElliptical | GalaxySubCategory::GiantLenticular => {
+            rng.roll(3, 6, 0) * 10
+        }
+        _ => rng.roll(2, 6, 0) * 10,
+    } as u32;
+
+    if galaxy
+        .special_traits
+        .contains(&GalaxySpecialTrait::ExtendedHalo)
+    {
+        number * 2
+    } else {
+        number
+    }
+}
+
+/// Returns how many open clusters can be found in the given [Galaxy], depending on its star formation rate.
+fn get_number_of_open_clusters(galaxy: &Galaxy, rng: &mut SeededDiceRoller) -> u32 {
+    let number = match galaxy.category {
+        GalaxyCategory::Spiral(_, _) => {
+            (0..galaxy.shape.arms).fold(0, |sum, _| sum + rng.roll(2, 6, 0) * 5)
+        }
+        GalaxyCategory::Lenticular(_, _) | GalaxyCategory::Elliptical(_) => rng.roll(1, 6, -3),
+        GalaxyCategory::DominantElliptical(_) => 0,
+        GalaxyCategory::Irregular(_, _, _) => rng.roll(2, 6, 0) * 2,
+        GalaxyCategory::Intergalactic(_, _, _) | GalaxyCategory::Intracluster(_, _, _) => {
+            rng.roll(1, 6, -3)
+        }
+    }
+    .max(0) as u32;
+
+    galaxy
+        .special_traits
+        .iter()
+        .fold(number, |number, special_trait| match special_trait {
+            GalaxySpecialTrait::Dead | GalaxySpecialTrait::Dormant => 0,
+            GalaxySpecialTrait::GasPoor => number / 2,
+            GalaxySpecialTrait::GasRich | GalaxySpecialTrait::Interacting => number * 3 / 2,
+            GalaxySpecialTrait::Starburst => number * 3,
+            _ => number,
+        })
+}
+
+/// Returns how many stellar streams were torn from the given [Galaxy] by its interactions with other ones.
+fn get_number_of_streams(galaxy: &Galaxy, rng: &mut SeededDiceRoller) -> u32 {
+    galaxy
+        .special_traits
+        .iter()
+        .map(|special_trait| match special_trait {
+            GalaxySpecialTrait::Tail => 1,
+            GalaxySpecialTrait::Interacting => rng.roll(1, 3, 0) as u32,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Generates a stellar stream starting in the outskirts of the given [Galaxy] and heading away from its center.
+fn generate_stream(galaxy: &Galaxy, rng: &mut SeededDiceRoller) -> Option<GalacticSubRegion> {
+    let start = find_point_in(galaxy, &[GalacticRegion::Halo, GalacticRegion::Aura], rng)?;
+    let galaxy_radius = galaxy.shape.halo_radius as f64;
+    let length = galaxy_radius * rng.roll(1, 4, 1) as f64 / 10.0;
+    let angle = (start.y as f64).atan2(start.x as f64) + (rng.roll(1, 91, -46) as f64).to_radians();
+    let (map_start, map_end) = (galaxy.get_galactic_start(), galaxy.get_galactic_end());
+    let end = SpaceCoordinates::new(
+        (start.x + (length * angle.cos()) as i64).clamp(map_start.x, map_end.x),
+        (start.y + (length * angle.sin()) as i64).clamp(map_start.y, map_end.y),
+        start.z,
+    );
+    let radius = ((galaxy_radius * rng.roll(1, 3, 0) as f64 / 100.0) as u32).max(5);
+
+    Some(GalacticSubRegion::new(
+        GalacticRegion::Stream,
+        start,
+        end,
+        radius,
+    ))
+}
+
+/// Finds a random point of the given [Galaxy] where stars are being formed, that lies in one of the given regions of its
+/// [GalacticShape]. Points are picked along the spiral arms if the galaxy has any.
+fn find_star_forming_point(
+    galaxy: &Galaxy,
+    regions: &[GalacticRegion],
+    rng: &mut SeededDiceRoller,
+) -> Option<SpaceCoordinates> {
+    let shape = &galaxy.shape;
+    let arms_start = shape.get_arms_start();
+    if shape.arms == 0 || shape.disk_radius as f64 <= arms_start {
+        return find_point_in(galaxy, regions, rng);
+    }
+
+    let spread = (shape.arm_width as f64 / 2.0).max(1.0);
+    let height = shape.thin_disk_height as i64;
+    for _ in 0..MAX_PLACEMENT_TRIES {
+        let distance = rng.gen_range(arms_start..shape.disk_radius as f64);
+        let (x, y) = shape.get_point_on_arm(rng.gen_range(0..shape.arms), distance);
+        let point = SpaceCoordinates::new(
+            (x + rng.gen_range(-spread..spread)) as i64,
+            (y + rng.gen_range(-spread..spread)) as i64,
+            rng.gen_range(-height..=height),
+        );
+        if regions.contains(&galaxy.shape.get_region(galaxy.category, point)) {
+            return Some(point);
+        }
+    }
+    None
+}
+
+/// Finds a random point of the map of the given [Galaxy] that lies in one of the given regions of its [GalacticShape].
+fn find_point_in(
+    galaxy: &Galaxy,
+    regions: &[GalacticRegion],
+    rng: &mut SeededDiceRoller,
+) -> Option<SpaceCoordinates> {
+    let (start, end) = (galaxy.get_galactic_start(), galaxy.get_galactic_end());
+    for _ in 0..MAX_PLACEMENT_TRIES {
+        let point = SpaceCoordinates::new(
+            rng.gen_range(start.x..=end.x),
+            rng.gen_range(start.y..=end.y),
+            rng.gen_range(start.z..=end.z),
+        );
+        if regions.contains(&galaxy.shape.get_region(galaxy.category, point)) {
+            return Some(point);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_clusters_in_the_expected_regions() {
+        for i in 0..50 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                galaxy: GalaxySettings {
+                    fixed_neighborhood: Some(GalacticNeighborhoodDensity::Group(1, 0)),
+                    fixed_category: Some(GalaxyCategory::Spiral(10000, 100)),
+                    fixed_sub_category: Some(GalaxySubCategory::ClassicSpiral),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(settings), settings);
+            let galaxy = Galaxy::generate(neighborhood, 0, settings);
+            let shape_region_at = |coord| galaxy.shape.get_region(galaxy.category, coord);
+
+            assert!(galaxy
+                .sub_regions
+                .iter()
+                .any(|s| s.region == GalacticRegion::GlobularCluster));
+            for sub_region in galaxy.sub_regions.iter() {
+                match sub_region.region {
+                    GalacticRegion::GlobularCluster => {
+                        assert_eq!(shape_region_at(sub_region.start), GalacticRegion::Halo)
+                    }
+                    GalacticRegion::OpenCluster | GalacticRegion::Association => {
+                        assert_eq!(shape_region_at(sub_region.start), GalacticRegion::Arm)
+                    }
+                    GalacticRegion::Exile => {
+                        assert_eq!(shape_region_at(sub_region.start), GalacticRegion::Void)
+                    }
+                    _ => (),
+                }
+                assert!(sub_region.contains(sub_region.start));
+            }
+        }
+    }
+
+    #[test]
+    fn generate_streams_only_for_tail_or_interacting_galaxies() {
+        for i in 0..20 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                ..Default::default()
+            };
+            let mut galaxy = Galaxy::new(
+                settings.clone(),
+                GalacticNeighborhood::default(),
+                0,
+                "Test".into(),
+                10.0,
+                false,
+                true,
+                GalaxyCategory::Spiral(5000, 100),
+                GalaxySubCategory::ClassicSpiral,
+                vec![GalaxySpecialTrait::NoPeculiarity],
+            );
+            assert!(!galaxy
+                .sub_regions
+                .iter()
+                .any(|s| s.region == GalacticRegion::Stream));
+
+            galaxy.special_traits = vec![GalaxySpecialTrait::Tail];
+            let sub_regions = GalacticSubRegion::generate(&galaxy);
+            let streams: Vec<&GalacticSubRegion> = sub_regions
+                .iter()
+                .filter(|s| s.region == GalacticRegion::Stream)
+                .collect();
+            assert_eq!(streams.len(), 1);
+            assert_ne!(streams[0].start, streams[0].end);
+        }
+    }
+
+    #[test]
+    fn hexes_and_divisions_report_sub_regions() {
+        for i in 0..20 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                galaxy: GalaxySettings {
+                    fixed_neighborhood: Some(GalacticNeighborhoodDensity::Group(1, 0)),
+                    fixed_category: Some(GalaxyCategory::Spiral(10000, 100)),
+                    fixed_sub_category: Some(GalaxySubCategory::ClassicSpiral),
+                    fixed_special_traits: Some(vec![GalaxySpecialTrait::ExtendedHalo]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(settings), settings);
+            let mut galaxy = Galaxy::generate(neighborhood, 0, settings);
+            let cluster = *galaxy
+                .sub_regions
+                .iter()
+                .find(|s| s.region == GalacticRegion::GlobularCluster)
+                .expect("Should have generated a globular cluster.");
+
+            assert_eq!(
+                galaxy.get_region_at(cluster.start),
+                GalacticRegion::GlobularCluster
+            );
+            let hex = galaxy.get_hex(cluster.start).unwrap();
+            assert_eq!(hex.region, GalacticRegion::GlobularCluster);
+            let divisions = galaxy.get_divisions_for_coord(cluster.start).unwrap();
+            assert!(divisions
+                .iter()
+                .any(|d| d.region == GalacticRegion::GlobularCluster));
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::internal::*;
+use crate::prelude::*;
+pub mod generator;
+
+/// A stellar structure standing out from the rest of its surroundings in a [Galaxy], like a star cluster, an association or a
+/// stellar stream. Sub-regions take precedence over the regions defined by the [GalacticShape] of the galaxy.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct GalacticSubRegion {
+    /// Which kind of structure this is. Should be either [GalacticRegion::GlobularCluster], [GalacticRegion::OpenCluster],
+    /// [GalacticRegion::Association], [GalacticRegion::Stream] or [GalacticRegion::Exile].
+    pub region: GalacticRegion,
+    /// The center of this sub-region, or the point where it starts for elongated ones. Relative to the galactic center.
+    pub start: SpaceCoordinates,
+    /// The point where this sub-region ends. Equal to **start** for spheroidal ones.
+    pub end: SpaceCoordinates,
+    /// The radius of this sub-region in parsecs, or half its width for elongated ones.
+    pub radius: u32,
+}
+
+impl Display for GalacticSubRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start == self.end {
+            write!(
+                f,
+                "{} of radius {}pc centered on {}",
+                self.region, self.radius, self.start
+            )
+        } else {
+            write!(
+                f,
+                "{} of width {}pc from {} to {}",
+                self.region,
+                self.radius * 2,
+                self.start,
+                self.end
+            )
+        }
+    }
+}
+
+impl GalacticSubRegion {
+    /// Creates a new [GalacticSubRegion].
+    pub fn new(
+        region: GalacticRegion,
+        start: SpaceCoordinates,
+        end: SpaceCoordinates,
+        radius: u32,
+    ) -> Self {
+        Self {
+            region,
+            start,
+            end,
+            radius,
+        }
+    }
+
+    /// Returns true if the box going from **top_left_up** to **bottom_right_down** might overlap with this sub-region.
+    pub fn overlaps(
+        &self,
+        top_left_up: SpaceCoordinates,
+        bottom_right_down: SpaceCoordinates,
+    ) -> bool {
+        let margin = self.radius as i64;
+        bottom_right_down.x >= self.start.x.min(self.end.x) - margin
+            && top_left_up.x <= self.start.x.max(self.end.x) + margin
+            && bottom_right_down.y >= self.start.y.min(self.end.y) - margin
+            && top_left_up.y <= self.start.y.max(self.end.y) + margin
+            && bottom_right_down.z >= self.start.z.min(self.end.z) - margin
+            && top_left_up.z <= self.start.z.max(self.end.z) + margin
+    }
+
+    /// Returns true if the given coordinates are inside this sub-region.
+    pub fn contains(&self, coord: SpaceCoordinates) -> bool {
+        // Cheap bounding box check first, as most points of the map are far from any sub-region
+        if !self.overlaps(coord, coord) {
+            return false;
+        }
+
+        let radius = self.radius as f64;
+        let (px, py, pz) = (
+            (coord.x - self.start.x) as f64,
+            (coord.y - self.start.y) as f64,
+            (coord.z - self.start.z) as f64,
+        );
+        let (dx, dy, dz) = (
+            (self.end.x - self.start.x) as f64,
+            (self.end.y - self.start.y) as f64,
+            (self.end.z - self.start.z) as f64,
+        );
+
+        // Finds the point of the segment between start and end that is the closest from the given coordinates
+        let length = dx * dx + dy * dy + dz * dz;
+        let along = if length > 0.0 {
+            ((px * dx + py * dy + pz * dz) / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (ox, oy, oz) = (px - dx * along, py - dy * along, pz - dz * along);
+        ox * ox + oy * oy + oz * oz <= radius * radius
+    }
+}
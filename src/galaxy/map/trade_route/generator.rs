@@ -0,0 +1,191 @@
+use crate::internal::*;
+use crate::prelude::*;
+
+impl TradeNetwork {
+    /// Generates the [TradeNetwork] linking the [StarSystem]s of the given [GalacticMapDivision], generating its hexes if needed.
+    /// Returns an error if one of them could not be generated.
+    pub fn generate(
+        galaxy: &mut Galaxy,
+        division: &GalacticMapDivision,
+        settings: TradeRouteSettings,
+    ) -> Result<Self, Rc<str>> {
+        let hexes: Vec<GalacticHex> = galaxy
+            .get_hexes_in_division(division)
+            .collect::<Result<_, _>>()?;
+        Ok(Self::from_hexes(&hexes, galaxy, settings))
+    }
+
+    /// Generates the [TradeNetwork] linking the [StarSystem]s of the given [GalacticHex]es.
+    pub fn from_hexes(
+        hexes: &[GalacticHex],
+        galaxy: &Galaxy,
+        settings: TradeRouteSettings,
+    ) -> Self {
+        let graph = JumpRouteGraph::from_hexes(hexes, galaxy, settings.jump_route_settings);
+        let world_trade_numbers: Vec<(StarSystemAddress, u8)> = graph
+            .nodes
+            .iter()
+            .map(|node| {
+                let system = hexes
+                    .iter()
+                    .find(|hex| hex.index == node.address.hex)
+                    .and_then(|hex| hex.contents.get(node.address.system as usize));
+                (
+                    node.address,
+                    system.map_or(0, |system| get_world_trade_number(system, node)),
+                )
+            })
+            .collect();
+
+        let mut routes = Vec::new();
+        for (index, (from, from_trade_number)) in world_trade_numbers.iter().enumerate() {
+            for to in graph.get_reachable_systems(*from, Some(settings.max_jumps)) {
+                let Some(to_index) = graph.get_node_index(to) else {
+                    continue;
+                };
+                let to_trade_number = world_trade_numbers[to_index].1;
+                // Each pair is only considered once, and the distance can only lower the trade number.
+                if to_index <= index
+                    || from_trade_number + to_trade_number < settings.minor_route_threshold
+                {
+                    continue;
+                }
+                if let Some(route) = graph.find_shortest_route(*from, to, Some(settings.max_jumps))
+                {
+                    let trade_number = (from_trade_number + to_trade_number)
+                        .saturating_sub(get_distance_penalty(route.get_number_of_jumps()));
+                    let category = if trade_number >= settings.major_route_threshold {
+                        TradeRouteCategory::Major
+                    } else if trade_number >= settings.minor_route_threshold {
+                        TradeRouteCategory::Minor
+                    } else {
+                        continue;
+                    };
+                    routes.push(TradeRoute::new(
+                        category,
+                        *from,
+                        to,
+                        trade_number,
+                        route.systems,
+                    ));
+                }
+            }
+        }
+        routes.sort_by(|a, b| {
+            b.trade_number
+                .cmp(&a.trade_number)
+                .then(a.from.cmp(&b.from))
+                .then(a.to.cmp(&b.to))
+        });
+
+        Self {
+            settings,
+            world_trade_numbers,
+            routes,
+        }
+    }
+}
+
+/// Returns the world trade number of the given [StarSystem], measuring how much it would take part in trade. The most habitable world
+/// of the system sets the base value, then resources like fuel, water and ores add to it.
+/// TODO: Take population into account once systems are populated.
+fn get_world_trade_number(system: &StarSystem, node: &JumpRouteNode) -> u8 {
+    let mut best_world = 0;
+    let mut has_ores = false;
+    for point in system.all_objects.iter() {
+        match &point.object {
+            AstronomicalObject::TelluricBody(body) => {
+                if let CelestialBodyDetails::Telluric(details) = &body.details {
+                    best_world = best_world.max(match details.world_type {
+                        CelestialBodyWorldType::Terrestrial => 4,
+                        CelestialBodyWorldType::Ocean => 3,
+                        CelestialBodyWorldType::Greenhouse
+                        | CelestialBodyWorldType::Ammonia
+                        | CelestialBodyWorldType::GeoActive => 2,
+                        CelestialBodyWorldType::Ice
+                        | CelestialBodyWorldType::DirtySnowball
+                        | CelestialBodyWorldType::Hadean
+                        | CelestialBodyWorldType::Rock => 1,
+                        CelestialBodyWorldType::ProtoWorld
+                        | CelestialBodyWorldType::Chthonian
+                        | CelestialBodyWorldType::VolatilesGiant => 0,
+                    });
+                    has_ores |= details.body_type == TelluricBodyComposition::Metallic;
+                }
+            }
+            AstronomicalObject::TelluricDisk(_) => has_ores = true,
+            _ => (),
+        }
+    }
+    best_world + node.has_gas_giant as u8 + node.has_water_ice as u8 + has_ores as u8
+}
+
+/// Returns how much the trade number between two systems is lowered by the number of jumps between them.
+fn get_distance_penalty(jumps: usize) -> u8 {
+    match jumps {
+        0..=1 => 0,
+        2 => 1,
+        3..=5 => 2,
+        6..=9 => 3,
+        10..=19 => 4,
+        _ => 5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_trade_routes_between_important_systems() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let subsector = galaxy
+            .get_division_at_level(SpaceCoordinates::new(0, 0, 0), 1)
+            .expect("Should return a div.");
+        let trade_settings = TradeRouteSettings {
+            jump_route_settings: JumpRouteSettings {
+                jump_distance: 2,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let network = TradeNetwork::generate(&mut galaxy, &subsector, trade_settings)
+            .expect("Should generate the network.");
+        assert!(!network.world_trade_numbers.is_empty());
+        assert!(!network.routes.is_empty());
+        for route in network.routes.iter() {
+            assert_eq!(route.path.first(), Some(&route.from));
+            assert_eq!(route.path.last(), Some(&route.to));
+            assert!(route.trade_number >= trade_settings.minor_route_threshold);
+            assert!(route.path.len() <= trade_settings.max_jumps as usize + 1);
+            assert_eq!(
+                route.category == TradeRouteCategory::Major,
+                route.trade_number >= trade_settings.major_route_threshold
+            );
+            let from = network
+                .get_world_trade_number(route.from)
+                .expect("Should be in the network.");
+            let to = network
+                .get_world_trade_number(route.to)
+                .expect("Should be in the network.");
+            assert!(route.trade_number <= from + to);
+            assert!(network.get_routes_through(route.from).contains(&route));
+        }
+        let jumps = network.get_jumps();
+        for (first, second, _) in jumps.iter() {
+            assert!(first < second);
+            assert_eq!(
+                jumps
+                    .iter()
+                    .filter(|(a, b, _)| a == first && b == second)
+                    .count(),
+                1
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::internal::*;
+use crate::prelude::*;
+pub mod generator;
+pub mod types;
+
+/// The trade routes linking the [StarSystem]s of a part of a [Galaxy], similar to Traveller's trade route generation.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize)]
+pub struct TradeNetwork {
+    /// The settings used to generate this network.
+    pub settings: TradeRouteSettings,
+    /// The world trade number of each system of the network, measuring its economic importance.
+    pub world_trade_numbers: Vec<(StarSystemAddress, u8)>,
+    /// The trade routes of this network, from the most to the least important.
+    pub routes: Vec<TradeRoute>,
+}
+
+impl Display for TradeNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Trade network of {} systems, with {} major and {} minor routes",
+            self.world_trade_numbers.len(),
+            self.get_routes_of_category(TradeRouteCategory::Major).len(),
+            self.get_routes_of_category(TradeRouteCategory::Minor).len()
+        )
+    }
+}
+
+impl TradeNetwork {
+    /// Returns the world trade number of the given system, if it is part of the network.
+    pub fn get_world_trade_number(&self, address: StarSystemAddress) -> Option<u8> {
+        self.world_trade_numbers
+            .iter()
+            .find(|(system, _)| *system == address)
+            .map(|(_, trade_number)| *trade_number)
+    }
+
+    /// Returns the trade routes of the given category.
+    pub fn get_routes_of_category(&self, category: TradeRouteCategory) -> Vec<&TradeRoute> {
+        self.routes
+            .iter()
+            .filter(|route| route.category == category)
+            .collect()
+    }
+
+    /// Returns the trade routes starting at, ending at or going through the given system.
+    pub fn get_routes_through(&self, address: StarSystemAddress) -> Vec<&TradeRoute> {
+        self.routes
+            .iter()
+            .filter(|route| route.goes_through(address))
+            .collect()
+    }
+
+    /// Returns every jump taken by at least one trade route, along with the category of the most important route using it. Meant to
+    /// draw the network on sector maps, each jump being listed once.
+    pub fn get_jumps(&self) -> Vec<(StarSystemAddress, StarSystemAddress, TradeRouteCategory)> {
+        let mut jumps: Vec<(StarSystemAddress, StarSystemAddress, TradeRouteCategory)> = Vec::new();
+        for route in self.routes.iter() {
+            for jump in route.path.windows(2) {
+                let (first, second) = if jump[0] <= jump[1] {
+                    (jump[0], jump[1])
+                } else {
+                    (jump[1], jump[0])
+                };
+                if let Some(existing) = jumps
+                    .iter_mut()
+                    .find(|(a, b, _)| *a == first && *b == second)
+                {
+                    existing.2 = existing.2.max(route.category);
+                } else {
+                    jumps.push((first, second, route.category));
+                }
+            }
+        }
+        jumps
+    }
+}
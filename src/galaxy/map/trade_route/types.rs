@@ -0,0 +1,94 @@
+use crate::internal::*;
+use crate::prelude::*;
+
+/// A list of settings used to configure how a [TradeNetwork] is generated.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, SmartDefault, Serialize, Deserialize,
+)]
+pub struct TradeRouteSettings {
+    /// The settings of the [JumpRouteGraph] trade routes follow.
+    pub jump_route_settings: JumpRouteSettings,
+    /// The maximum number of jumps between two systems for a trade route to be established between them.
+    #[default = 4]
+    pub max_jumps: u32,
+    /// The minimal trade number two systems must reach to be linked by a minor trade route.
+    #[default = 7]
+    pub minor_route_threshold: u8,
+    /// The minimal trade number two systems must reach to be linked by a major trade route.
+    #[default = 10]
+    pub major_route_threshold: u8,
+}
+
+/// The importance of a [TradeRoute].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, SmartDefault, Serialize, Deserialize,
+)]
+pub enum TradeRouteCategory {
+    /// A route seeing regular but modest traffic.
+    #[default]
+    Minor,
+    /// A busy route linking important systems.
+    Major,
+}
+
+impl Display for TradeRouteCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TradeRouteCategory::Minor => write!(f, "Minor"),
+            TradeRouteCategory::Major => write!(f, "Major"),
+        }
+    }
+}
+
+/// A trade route between two [StarSystem]s, following the cheapest path through a [JumpRouteGraph].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize)]
+pub struct TradeRoute {
+    /// The importance of this route.
+    pub category: TradeRouteCategory,
+    /// The system at one end of this route.
+    pub from: StarSystemAddress,
+    /// The system at the other end of this route.
+    pub to: StarSystemAddress,
+    /// The trade number of this route, the sum of both systems' own trade numbers minus a penalty for the distance between them.
+    pub trade_number: u8,
+    /// The systems the route goes through, including both ends.
+    pub path: Vec<StarSystemAddress>,
+}
+
+impl TradeRoute {
+    /// Creates a new [TradeRoute].
+    pub fn new(
+        category: TradeRouteCategory,
+        from: StarSystemAddress,
+        to: StarSystemAddress,
+        trade_number: u8,
+        path: Vec<StarSystemAddress>,
+    ) -> Self {
+        Self {
+            category,
+            from,
+            to,
+            trade_number,
+            path,
+        }
+    }
+
+    /// Returns true if this route starts at, ends at or goes through the given system.
+    pub fn goes_through(&self, address: StarSystemAddress) -> bool {
+        self.path.contains(&address)
+    }
+}
+
+impl Display for TradeRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} trade route of trade number {} between {} and {}, {} jumps long",
+            self.category,
+            self.trade_number,
+            self.from,
+            self.to,
+            self.path.len().saturating_sub(1)
+        )
+    }
+}
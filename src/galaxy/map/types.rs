@@ -3,7 +3,7 @@ use crate::prelude::*;
 
 /// A list of settings used to configure the [GalacticMapDivisionLevel], [GalacticMapDivision]s and [GalacticHex]es generation.
 #[derive(
-    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, SmartDefault, Serialize, Deserialize,
+    Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, SmartDefault, Serialize, Deserialize,
 )]
 pub struct SectorSettings {
     /// How many parsecs the building block of a galactic map spans, on the (x, y, z) axis. Must be between 1 and 255 inclusive.
@@ -49,6 +49,10 @@ pub struct SectorSettings {
     /// z axis to a value different than 1, it will enable star systems to be generated "above" and "under" the map plane.
     #[default = true]
     pub flat_map: bool,
+    /// If set to true along with **flat_map**, hexes are laid out as a genuine hexagonal grid using "odd-q" offset coordinates, like
+    /// Traveller maps do: odd columns are shifted half a hex down and every hex has 6 neighbors. See [HexGridCoordinates].
+    #[default = false]
+    pub hex_grid: bool,
     /// If set to true, only one roll will occur to determine how much star systems there are per hex. If set to false, a roll will be made
     /// for each cubic parsec inside the hex.
     #[default = true]
@@ -56,6 +60,75 @@ pub struct SectorSettings {
     /// If set to true, the maximum number of systems per hex is one.
     #[default = true]
     pub max_one_system_per_hex: bool,
+    /// A function used to name [GalacticMapDivision]s instead of the default naming scheme. Whenever it returns [None], the default
+    /// name is used instead. Cannot be serialized.
+    #[serde(skip)]
+    pub division_name_source: Option<DivisionNameSource>,
+}
+
+/// Wraps a function returning the name of a [GalacticMapDivision] using its level, its cell in its parent's grid as (x, y, z), its
+/// index and the seed of the galaxy it belongs to. The function can be a closure capturing its own naming data. Two sources are only
+/// equal if they share the same function.
+#[derive(Clone)]
+pub struct DivisionNameSource(
+    pub Rc<dyn Fn(u8, (u8, u8, u8), SpaceCoordinates, &str) -> Option<Rc<str>>>,
+);
+
+impl DivisionNameSource {
+    /// Creates a new [DivisionNameSource].
+    pub fn new(
+        name_source: impl Fn(u8, (u8, u8, u8), SpaceCoordinates, &str) -> Option<Rc<str>> + 'static,
+    ) -> Self {
+        Self(Rc::new(name_source))
+    }
+
+    /// Returns the name the wrapped function gives to the division of the given parameters, if any.
+    pub fn get_name(
+        &self,
+        level: u8,
+        cell: (u8, u8, u8),
+        index: SpaceCoordinates,
+        seed: &str,
+    ) -> Option<Rc<str>> {
+        (self.0)(level, cell, index, seed)
+    }
+
+    /// Returns the address of the wrapped function, used to compare sources.
+    fn get_address(&self) -> usize {
+        Rc::as_ptr(&self.0) as *const () as usize
+    }
+}
+
+impl PartialEq for DivisionNameSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_address() == other.get_address()
+    }
+}
+
+impl Eq for DivisionNameSource {}
+
+impl PartialOrd for DivisionNameSource {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DivisionNameSource {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.get_address().cmp(&other.get_address())
+    }
+}
+
+impl std::hash::Hash for DivisionNameSource {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.get_address().hash(state);
+    }
+}
+
+impl std::fmt::Debug for DivisionNameSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DivisionNameSource({:#x})", self.get_address())
+    }
 }
 
 /// Represents the different kind of places a star could be in the galaxy.
@@ -105,3 +178,56 @@ pub enum GalacticRegion {
     /// Some stars lost into the void, pushed out of their normal course by gravity during their past.
     Exile,
 }
+
+impl Display for GalacticRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GalacticRegion::Multiple => write!(f, "Multiple Regions"),
+            GalacticRegion::Core => write!(f, "Core"),
+            GalacticRegion::Nucleus => write!(f, "Nucleus"),
+            GalacticRegion::Bulge => write!(f, "Bulge"),
+            GalacticRegion::Bar => write!(f, "Bar"),
+            GalacticRegion::Arm => write!(f, "Arm"),
+            GalacticRegion::Disk => write!(f, "Disk"),
+            GalacticRegion::Ellipse => write!(f, "Ellipse"),
+            GalacticRegion::Halo => write!(f, "Halo"),
+            GalacticRegion::Aura => write!(f, "Aura"),
+            GalacticRegion::Void => write!(f, "Void"),
+            GalacticRegion::GlobularCluster => write!(f, "Globular Cluster"),
+            GalacticRegion::OpenCluster => write!(f, "Open Cluster"),
+            GalacticRegion::Association => write!(f, "Association"),
+            GalacticRegion::Stream => write!(f, "Stellar Stream"),
+            GalacticRegion::Exile => write!(f, "Exiles"),
+        }
+    }
+}
+
+/// The different kinds of nebulae one can find in a [Galaxy].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, SmartDefault, Serialize, Deserialize,
+)]
+pub enum NebulaCategory {
+    /// A cloud of ionized gas glowing under the light of the young and hot stars born inside it.
+    #[default]
+    Emission,
+    /// A cloud of dust reflecting the light of nearby stars, usually bluish.
+    Reflection,
+    /// A cloud of dust and cold gas dense enough to block the light of the stars behind it.
+    Dark,
+    /// The expanding shell of gas ejected by a dying giant star before it becomes a white dwarf.
+    Planetary,
+    /// The remains of a star that exploded as a supernova, still expanding and full of radiation.
+    SupernovaRemnant,
+}
+
+impl Display for NebulaCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NebulaCategory::Emission => write!(f, "Emission Nebula"),
+            NebulaCategory::Reflection => write!(f, "Reflection Nebula"),
+            NebulaCategory::Dark => write!(f, "Dark Nebula"),
+            NebulaCategory::Planetary => write!(f, "Planetary Nebula"),
+            NebulaCategory::SupernovaRemnant => write!(f, "Supernova Remnant"),
+        }
+    }
+}
@@ -6,6 +6,7 @@ use constants::*;
 pub mod generator;
 pub mod map;
 pub mod neighborhood;
+pub mod shape;
 pub mod types;
 
 /// Data allowing us to model a galaxy.
@@ -31,17 +32,27 @@ pub struct Galaxy {
     pub sub_category: GalaxySubCategory,
     /// What are the pecularities of this galaxy.
     pub special_traits: Vec<GalaxySpecialTrait>,
+    /// Where this galaxy lies in its neighborhood.
+    pub position: NeighborhoodCoordinates,
+    /// The geometry of this galaxy's structures, used to find what region a given point belongs to.
+    pub shape: GalacticShape,
+    /// The star clusters, associations, stellar streams and groups of exiles that can be found in this galaxy.
+    pub sub_regions: Vec<GalacticSubRegion>,
     /// The specific division levels used to map this galaxy's content.
     pub division_levels: Vec<GalacticMapDivisionLevel>,
     /// This galaxy's already generated divisions.
     pub divisions: Vec<GalacticMapDivision>,
     /// This galaxy's already generated hexagons.
     pub hexes: Vec<GalacticHex>,
+    /// The catalog of real stars pinned into this galaxy, if any. See [Galaxy::import_star_catalog].
+    pub star_catalog: Option<StarCatalog>,
+    /// The systems and system templates pinned at specific coordinates of this galaxy. See [Galaxy::pin_system].
+    pub pinned_systems: Vec<(SpaceCoordinates, PinnedSystem)>,
 }
 
 impl Default for Galaxy {
     fn default() -> Self {
-        Self {
+        let mut galaxy = Self {
             settings: GenerationSettings {
                 ..Default::default()
             },
@@ -56,10 +67,25 @@ impl Default for Galaxy {
             category: OUR_GALAXYS_CATEGORY,
             sub_category: OUR_GALAXYS_SUB_CATEGORY,
             special_traits: vec![NO_SPECIAL_TRAIT],
+            position: NeighborhoodCoordinates::default(),
+            shape: GalacticShape::generate(
+                OUR_GALAXYS_CATEGORY,
+                OUR_GALAXYS_SUB_CATEGORY,
+                &[NO_SPECIAL_TRAIT],
+                0,
+                &GenerationSettings {
+                    ..Default::default()
+                },
+            ),
+            sub_regions: vec![],
             division_levels: vec![],
             divisions: vec![],
             hexes: vec![],
-        }
+            star_catalog: None,
+            pinned_systems: Vec::new(),
+        };
+        galaxy.sub_regions = GalacticSubRegion::generate(&galaxy);
+        galaxy
     }
 }
 
@@ -67,13 +93,14 @@ impl Display for Galaxy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{:04} - \"{}\" - {}{}, of sub-type {}, aged {} billion years, with the following special traits: {}",
+            "{:04} - \"{}\" - {}{}, of sub-type {}, aged {} billion years, located at {}, with the following special traits: {}",
             self.index,
             self.name,
             if self.is_dominant { "" } else if self.is_major { "major " } else { "minor " },
             self.category,
             self.sub_category,
             self.age,
+            self.position,
             self.special_traits
                 .iter()
                 .map(|t| format!("{}", t))
@@ -97,7 +124,9 @@ impl Galaxy {
         sub_category: GalaxySubCategory,
         special_traits: Vec<GalaxySpecialTrait>,
     ) -> Self {
-        Self {
+        let shape =
+            GalacticShape::generate(category, sub_category, &special_traits, index, &settings);
+        let mut galaxy = Self {
             settings,
             neighborhood,
             index,
@@ -108,9 +137,41 @@ impl Galaxy {
             category,
             sub_category,
             special_traits,
+            position: NeighborhoodCoordinates::default(),
+            shape,
+            sub_regions: vec![],
             division_levels: vec![],
             divisions: vec![],
             hexes: vec![],
-        }
+            star_catalog: None,
+            pinned_systems: Vec::new(),
+        };
+        galaxy.sub_regions = GalacticSubRegion::generate(&galaxy);
+        galaxy
+    }
+
+    /// Returns the distance in kiloparsecs between this galaxy and the **other** one.
+    pub fn distance_to(&self, other: &Galaxy) -> f32 {
+        self.position.distance_to(&other.position)
+    }
+
+    /// Returns up to **count** galaxies from the given list, sorted from the nearest to the farthest from this one. This galaxy is
+    /// excluded from the results if it is part of the list.
+    pub fn get_nearest_galaxies<'a>(
+        &self,
+        galaxies: &'a [Galaxy],
+        count: usize,
+    ) -> Vec<&'a Galaxy> {
+        let mut nearest: Vec<&Galaxy> = galaxies
+            .iter()
+            .filter(|galaxy| galaxy.index != self.index)
+            .collect();
+        nearest.sort_by(|a, b| {
+            self.distance_to(a)
+                .partial_cmp(&self.distance_to(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        nearest.truncate(count);
+        nearest
     }
 }
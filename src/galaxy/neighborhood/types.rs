@@ -91,3 +91,33 @@ impl Display for GalacticNeighborhoodDensity {
         }
     }
 }
+
+/// The position of a [Galaxy]'s center inside its [GalacticNeighborhood], in kiloparsecs.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct NeighborhoodCoordinates {
+    /// The x coordinate of the galaxy in kiloparsecs.
+    pub x: f32,
+    /// The y coordinate of the galaxy in kiloparsecs.
+    pub y: f32,
+    /// The z coordinate of the galaxy in kiloparsecs.
+    pub z: f32,
+}
+
+impl NeighborhoodCoordinates {
+    /// Creates a new [NeighborhoodCoordinates] instance.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the distance in kiloparsecs between these coordinates and the **other** ones.
+    pub fn distance_to(&self, other: &Self) -> f32 {
+        let (dx, dy, dz) = (self.x - other.x, self.y - other.y, self.z - other.z);
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
+
+impl Display for NeighborhoodCoordinates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})kpc", self.x, self.y, self.z)
+    }
+}
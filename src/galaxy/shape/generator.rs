@@ -0,0 +1,289 @@
+use crate::internal::*;
+use crate::prelude::*;
+
+impl GalacticShape {
+    /// Generates the [GalacticShape] of a [Galaxy] of the given category and sub-category, while following the given
+    /// [GenerationSettings].
+    pub fn generate(
+        category: GalaxyCategory,
+        sub_category: GalaxySubCategory,
+        special_traits: &[GalaxySpecialTrait],
+        index: u16,
+        settings: &GenerationSettings,
+    ) -> Self {
+        let mut rng = SeededDiceRoller::new(&settings.seed, &format!("gal_{}_shp", index));
+        let has_extended_halo = special_traits.contains(&GalaxySpecialTrait::ExtendedHalo);
+
+        match category {
+            GalaxyCategory::Spiral(radius, thickness) => {
+                let (arms, arm_pitch) = generate_arms(sub_category, &mut rng, settings);
+                let (bulge_ratio, nucleus_ratio) = match sub_category {
+                    GalaxySubCategory::FlatSpiral => (rng.roll(1, 4, 2) as f64 / 100.0, 0.005),
+                    GalaxySubCategory::BarredSpiral => (rng.roll(1, 6, 9) as f64 / 100.0, 0.01),
+                    GalaxySubCategory::ClassicSpiral => (rng.roll(1, 11, 14) as f64 / 100.0, 0.01),
+                    _ => (rng.roll(1, 4, 3) as f64 / 100.0, 0.01),
+                };
+                let bar_length = if sub_category == GalaxySubCategory::BarredSpiral {
+                    (radius as f64 * rng.roll(1, 21, 24) as f64 / 100.0) as u32
+                } else {
+                    0
+                };
+
+                Self {
+                    nucleus_radius: (radius as f64 * nucleus_ratio) as u32,
+                    bulge_radius: (radius as f64 * bulge_ratio) as u32,
+                    bar_length,
+                    bar_width: bar_length * rng.roll(1, 11, 19) as u32 / 100,
+                    bar_angle: rng.roll(1, 180, -1) as f32,
+                    arms,
+                    arm_pitch,
+                    arm_width: (radius as f64 * rng.roll(1, 4, 3) as f64 / 100.0) as u32,
+                    arm_offset: rng.roll(1, 360, -1) as f32,
+                    disk_radius: radius,
+                    thin_disk_height: thickness / 4,
+                    thick_disk_height: thickness / 2 + thickness % 2,
+                    halo_radius: get_halo_radius(radius, has_extended_halo),
+                }
+            }
+            GalaxyCategory::Lenticular(radius, thickness) => {
+                let bulge_ratio = match sub_category {
+                    GalaxySubCategory::DwarfLenticular => 0.0,
+                    GalaxySubCategory::GiantLenticular => rng.roll(1, 11, 29) as f64 / 100.0,
+                    _ => rng.roll(1, 11, 19) as f64 / 100.0,
+                };
+
+                Self {
+                    nucleus_radius: (radius as f64 * 0.01) as u32,
+                    bulge_radius: (radius as f64 * bulge_ratio) as u32,
+                    disk_radius: radius,
+                    thin_disk_height: thickness / 4,
+                    thick_disk_height: thickness / 2 + thickness % 2,
+                    halo_radius: get_halo_radius(radius, has_extended_halo),
+                    ..Default::default()
+                }
+            }
+            GalaxyCategory::Elliptical(radius) | GalaxyCategory::DominantElliptical(radius) => {
+                let core_ratio = rng.roll(1, 6, 4) as f64 / 100.0;
+                let ellipse_ratio = if has_extended_halo {
+                    rng.roll(1, 11, 49) as f64 / 100.0
+                } else {
+                    rng.roll(1, 11, 74) as f64 / 100.0
+                };
+
+                Self {
+                    nucleus_radius: (radius as f64 * core_ratio) as u32,
+                    disk_radius: (radius as f64 * ellipse_ratio) as u32,
+                    halo_radius: radius,
+                    ..Default::default()
+                }
+            }
+            GalaxyCategory::Intergalactic(l, w, h)
+            | GalaxyCategory::Irregular(l, w, h)
+            | GalaxyCategory::Intracluster(l, w, h) => Self {
+                disk_radius: l.max(w).max(h) / 2,
+                halo_radius: l.max(w).max(h) / 2,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Generates the number of arms and their pitch angle for a spiral galaxy of the given sub-category.
+fn generate_arms(
+    sub_category: GalaxySubCategory,
+    rng: &mut SeededDiceRoller,
+    settings: &GenerationSettings,
+) -> (u8, f32) {
+    let arms = if let Some(fixed_arms) = settings.galaxy.fixed_number_of_arms {
+        fixed_arms
+    } else {
+        rng.get_result(&CopyableRollToProcess {
+            possible_results: vec![
+                CopyableWeightedResult {
+                    result: 1,
+                    weight: if sub_category == GalaxySubCategory::DwarfSpiral {
+                        3
+                    } else {
+                        0
+                    },
+                },
+                CopyableWeightedResult {
+                    result: 2,
+                    weight: if sub_category == GalaxySubCategory::BarredSpiral {
+                        12
+                    } else {
+                        6
+                    },
+                },
+                CopyableWeightedResult {
+                    result: 3,
+                    weight: 2,
+                },
+                CopyableWeightedResult {
+                    result: 4,
+                    weight: if sub_category == GalaxySubCategory::DwarfSpiral {
+                        1
+                    } else {
+                        4
+                    },
+                },
+                CopyableWeightedResult {
+                    result: 5,
+                    weight: if sub_category == GalaxySubCategory::FlatSpiral {
+                        1
+                    } else {
+                        0
+                    },
+                },
+            ],
+            roll_method: RollMethod::SimpleRoll,
+        })
+        .expect("Should return a number of arms.")
+    };
+
+    let arm_pitch = if let Some(fixed_pitch) = settings.galaxy.fixed_arm_pitch {
+        fixed_pitch.clamp(1.0, 89.0)
+    } else {
+        (match sub_category {
+            GalaxySubCategory::ClassicSpiral => rng.roll(1, 11, 7),
+            GalaxySubCategory::BarredSpiral => rng.roll(1, 9, 9),
+            GalaxySubCategory::FlatSpiral => rng.roll(1, 16, 17),
+            _ => rng.roll(1, 16, 24),
+        }) as f32
+    };
+
+    (arms, arm_pitch)
+}
+
+/// Returns the radius of the halo of a disk galaxy of the given radius.
+fn get_halo_radius(radius: u32, has_extended_halo: bool) -> u32 {
+    if has_extended_halo {
+        radius * 2
+    } else {
+        radius + radius * 3 / 10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn generate_spiral_galaxies_with_all_expected_regions() {
+        for i in 0..50 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                galaxy: GalaxySettings {
+                    fixed_neighborhood: Some(GalacticNeighborhoodDensity::Group(1, 0)),
+                    fixed_category: Some(GalaxyCategory::Spiral(10000, 100)),
+                    fixed_sub_category: Some(GalaxySubCategory::BarredSpiral),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(settings), settings);
+            let galaxy = Galaxy::generate(neighborhood, 0, settings);
+            let mut regions = HashSet::new();
+            for x in (-10000..=10000).step_by(100) {
+                for y in (-10000..=10000).step_by(100) {
+                    regions.insert(
+                        galaxy
+                            .shape
+                            .get_region(galaxy.category, SpaceCoordinates::new(x, y, 0)),
+                    );
+                }
+            }
+
+            assert!(regions.contains(&GalacticRegion::Nucleus));
+            assert!(regions.contains(&GalacticRegion::Bulge));
+            assert!(regions.contains(&GalacticRegion::Bar));
+            assert!(regions.contains(&GalacticRegion::Arm));
+            assert!(regions.contains(&GalacticRegion::Disk));
+            assert!(regions.contains(&GalacticRegion::Halo));
+            assert_eq!(
+                galaxy.get_region_at(SpaceCoordinates::new(0, 0, 0)),
+                GalacticRegion::Nucleus
+            );
+        }
+    }
+
+    #[test]
+    fn generate_spiral_galaxies_with_configured_arms() {
+        let settings = &GenerationSettings {
+            galaxy: GalaxySettings {
+                fixed_number_of_arms: Some(4),
+                fixed_arm_pitch: Some(15.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let shape = GalacticShape::generate(
+            GalaxyCategory::Spiral(10000, 100),
+            GalaxySubCategory::ClassicSpiral,
+            &[],
+            0,
+            settings,
+        );
+        assert_eq!(shape.arms, 4);
+        assert_eq!(shape.arm_pitch, 15.0);
+        assert_eq!(shape.bar_length, 0);
+    }
+
+    #[test]
+    fn generate_elliptical_and_lenticular_regions() {
+        let settings = &GenerationSettings {
+            ..Default::default()
+        };
+        let category = GalaxyCategory::Elliptical(10000);
+        let shape = GalacticShape::generate(
+            category,
+            GalaxySubCategory::CommonElliptical,
+            &[],
+            0,
+            settings,
+        );
+        assert_eq!(
+            shape.get_region(category, SpaceCoordinates::new(0, 0, 0)),
+            GalacticRegion::Core
+        );
+        assert_eq!(
+            shape.get_region(category, SpaceCoordinates::new(5000, 0, 0)),
+            GalacticRegion::Ellipse
+        );
+        assert_eq!(
+            shape.get_region(category, SpaceCoordinates::new(9900, 0, 0)),
+            GalacticRegion::Halo
+        );
+        assert_eq!(
+            shape.get_region(category, SpaceCoordinates::new(9000, 9000, 0)),
+            GalacticRegion::Void
+        );
+
+        let category = GalaxyCategory::Lenticular(10000, 600);
+        let shape = GalacticShape::generate(
+            category,
+            GalaxySubCategory::CommonLenticular,
+            &[],
+            0,
+            settings,
+        );
+        assert_eq!(
+            shape.get_region(category, SpaceCoordinates::new(0, 0, 0)),
+            GalacticRegion::Nucleus
+        );
+        assert_eq!(
+            shape.get_region(category, SpaceCoordinates::new(1000, 0, 0)),
+            GalacticRegion::Bulge
+        );
+        assert_eq!(
+            shape.get_region(category, SpaceCoordinates::new(7000, 0, 0)),
+            GalacticRegion::Disk
+        );
+        assert_eq!(
+            shape.get_region(category, SpaceCoordinates::new(10000, 5000, 0)),
+            GalacticRegion::Halo
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::internal::*;
+use crate::prelude::*;
+use std::f64::consts::PI;
+pub mod generator;
+
+/// The geometric description of the main structures of a [Galaxy]. Used to find in which [GalacticRegion] a given point of the galactic
+/// map lies. All distances are in parsecs and relative to the galactic center.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct GalacticShape {
+    /// The radius of the nucleus of a spiral or lenticular galaxy, or of the core of an elliptical one.
+    pub nucleus_radius: u32,
+    /// The radius of the central bulge. Zero if the galaxy has none.
+    pub bulge_radius: u32,
+    /// Half the length of the central bar. Zero if the galaxy has none.
+    pub bar_length: u32,
+    /// Half the width of the central bar.
+    pub bar_width: u32,
+    /// The angle between the central bar and the x axis, in degrees.
+    pub bar_angle: f32,
+    /// The number of spiral arms. Zero if the galaxy has none.
+    pub arms: u8,
+    /// The pitch angle of the spiral arms in degrees. The lower, the more tightly wound the arms are.
+    pub arm_pitch: f32,
+    /// The full width of a spiral arm.
+    pub arm_width: u32,
+    /// The angle at which the first arm starts, in degrees.
+    pub arm_offset: f32,
+    /// The radius of the disk of a spiral or lenticular galaxy, or of the main ellipse of an elliptical one.
+    pub disk_radius: u32,
+    /// Half the thickness of the thin disk, in which arms can be found.
+    pub thin_disk_height: u32,
+    /// Half the thickness of the thick disk, made of older stars.
+    pub thick_disk_height: u32,
+    /// The radius of the spherical halo surrounding the galaxy.
+    pub halo_radius: u32,
+}
+
+impl Display for GalacticShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} arms of pitch {}°, bar of {}pc, bulge of {}pc, disk of {}pc and halo of {}pc",
+            self.arms,
+            self.arm_pitch,
+            self.bar_length * 2,
+            self.bulge_radius,
+            self.disk_radius,
+            self.halo_radius
+        )
+    }
+}
+
+impl GalacticShape {
+    /// Returns the [GalacticRegion] found at the given coordinates, for a galaxy of the given category.
+    pub fn get_region(&self, category: GalaxyCategory, coord: SpaceCoordinates) -> GalacticRegion {
+        match category {
+            GalaxyCategory::Spiral(_, _) => self.get_disk_galaxy_region(coord, true),
+            GalaxyCategory::Lenticular(_, _) => self.get_disk_galaxy_region(coord, false),
+            GalaxyCategory::Elliptical(_) | GalaxyCategory::DominantElliptical(_) => {
+                self.get_elliptical_galaxy_region(coord)
+            }
+            GalaxyCategory::Intergalactic(l, w, h)
+            | GalaxyCategory::Irregular(l, w, h)
+            | GalaxyCategory::Intracluster(l, w, h) => {
+                get_irregular_galaxy_region(coord, l as f64, w as f64, h as f64)
+            }
+        }
+    }
+
+    /// Finds the region of a point in a spiral or lenticular galaxy.
+    fn get_disk_galaxy_region(&self, coord: SpaceCoordinates, has_arms: bool) -> GalacticRegion {
+        let (x, y, z) = (coord.x as f64, coord.y as f64, coord.z as f64);
+        let planar_distance = (x * x + y * y).sqrt();
+        let distance = (planar_distance * planar_distance + z * z).sqrt();
+
+        if distance <= self.nucleus_radius as f64 {
+            GalacticRegion::Nucleus
+        } else if distance <= self.bulge_radius as f64 {
+            GalacticRegion::Bulge
+        } else if self.is_in_bar(x, y, z) {
+            GalacticRegion::Bar
+        } else if planar_distance <= self.disk_radius as f64
+            && z.abs() <= self.thick_disk_height as f64
+        {
+            if has_arms && z.abs() <= self.thin_disk_height as f64 && self.is_in_arm(x, y) {
+                GalacticRegion::Arm
+            } else {
+                GalacticRegion::Disk
+            }
+        } else if distance <= self.halo_radius as f64 {
+            GalacticRegion::Halo
+        } else {
+            GalacticRegion::Void
+        }
+    }
+
+    /// Finds the region of a point in an elliptical galaxy.
+    fn get_elliptical_galaxy_region(&self, coord: SpaceCoordinates) -> GalacticRegion {
+        let (x, y, z) = (coord.x as f64, coord.y as f64, coord.z as f64);
+        let distance = (x * x + y * y + z * z).sqrt();
+
+        if distance <= self.nucleus_radius as f64 {
+            GalacticRegion::Core
+        } else if distance <= self.disk_radius as f64 {
+            GalacticRegion::Ellipse
+        } else if distance <= self.halo_radius as f64 {
+            GalacticRegion::Halo
+        } else {
+            GalacticRegion::Void
+        }
+    }
+
+    /// Returns true if the given point is inside the central bar, if any.
+    fn is_in_bar(&self, x: f64, y: f64, z: f64) -> bool {
+        if self.bar_length == 0 {
+            return false;
+        }
+        let angle = (self.bar_angle as f64).to_radians();
+        let along = x * angle.cos() + y * angle.sin();
+        let across = -x * angle.sin() + y * angle.cos();
+        along.abs() <= self.bar_length as f64
+            && across.abs() <= self.bar_width as f64
+            && z.abs() <= self.bar_width as f64
+    }
+
+    /// Returns true if the given point of the galactic plane is within one of the logarithmic spiral arms.
+    fn is_in_arm(&self, x: f64, y: f64) -> bool {
+        if self.arms == 0 {
+            return false;
+        }
+        let planar_distance = (x * x + y * y).sqrt();
+        if planar_distance < self.get_arms_start() {
+            return false;
+        }
+
+        let pitch = (self.arm_pitch as f64).to_radians();
+        let arm_angle = self.get_arm_angle(planar_distance);
+        let point_angle = y.atan2(x);
+        let arm_spacing = 2.0 * PI / self.arms as f64;
+        let mut difference = (point_angle - arm_angle).rem_euclid(arm_spacing);
+        if difference > arm_spacing / 2.0 {
+            difference = arm_spacing - difference;
+        }
+
+        // The distance to the arm is measured perpendicularly to it
+        let distance_to_arm = planar_distance * difference * pitch.sin();
+        distance_to_arm <= self.arm_width as f64 / 2.0
+    }
+
+    /// Returns the (x, y) coordinates of the point at the given distance from the galactic center on the center line of the arm of
+    /// the given index.
+    pub fn get_point_on_arm(&self, arm: u8, distance: f64) -> (f64, f64) {
+        let angle = self.get_arm_angle(distance) + 2.0 * PI * arm as f64 / self.arms.max(1) as f64;
+        (distance * angle.cos(), distance * angle.sin())
+    }
+
+    /// Returns the distance from the galactic center at which the spiral arms start.
+    pub fn get_arms_start(&self) -> f64 {
+        (self.bar_length.max(self.bulge_radius).max(1)) as f64
+    }
+
+    /// Returns the angle in radians at which the first arm passes at the given distance from the galactic center.
+    fn get_arm_angle(&self, distance: f64) -> f64 {
+        // An arm follows r = r0 * e^(tan(pitch) * (θ - offset)), so at a given distance, it passes at a known angle
+        let pitch = (self.arm_pitch as f64).to_radians();
+        (distance / self.get_arms_start()).ln() / pitch.tan()
+            + (self.arm_offset as f64).to_radians()
+    }
+}
+
+/// Finds the region of a point in a galaxy without any discernable structure. Everything inside its ellipsoid is a mix of regions,
+/// everything outside is its aura.
+fn get_irregular_galaxy_region(
+    coord: SpaceCoordinates,
+    length: f64,
+    width: f64,
+    height: f64,
+) -> GalacticRegion {
+    let scaled = (coord.x as f64 / (length / 2.0).max(1.0)).powi(2)
+        + (coord.y as f64 / (width / 2.0).max(1.0)).powi(2)
+        + (coord.z as f64 / (height / 2.0).max(1.0)).powi(2);
+    if scaled <= 1.0 {
+        GalacticRegion::Multiple
+    } else {
+        GalacticRegion::Aura
+    }
+}
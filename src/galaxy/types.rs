@@ -16,13 +16,18 @@ pub struct GalaxySettings {
     pub forbidden_special_traits: Option<Vec<GalaxySpecialTrait>>,
     /// The specific age to use for galaxy generation, if any.
     pub fixed_age: Option<f32>,
+    /// The specific number of arms spiral galaxies should have, if any.
+    pub fixed_number_of_arms: Option<u8>,
+    /// The specific pitch angle in degrees the arms of spiral galaxies should have, if any. The lower the value, the more tightly
+    /// wound the arms are.
+    pub fixed_arm_pitch: Option<f32>,
     /// Skip the galaxy generation and just uses a copy of ours.
     pub use_ours: bool,
 }
 
 impl Display for GalaxySettings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ fixed_era: {}, era_before: {}, era_after: {}, fixed_age: {}, age_before: {}, age_after: {}, use_ours: {} }}",
+        write!(f, "{{ fixed_era: {}, era_before: {}, era_after: {}, fixed_age: {}, age_before: {}, age_after: {}, fixed_number_of_arms: {}, fixed_arm_pitch: {}, use_ours: {} }}",
         if self.fixed_neighborhood.is_some() { format!("{}", self.fixed_neighborhood.unwrap()) } else {
             "None".to_string()
         },
@@ -39,6 +44,8 @@ impl Display for GalaxySettings {
         .collect::<Vec<String>>()
         .join(", ")) } else { "None".to_string() },
         if self.fixed_age.is_some() { format!("{}", self.fixed_age.unwrap()) } else { "None".to_string() },
+        if self.fixed_number_of_arms.is_some() { format!("{}", self.fixed_number_of_arms.unwrap()) } else { "None".to_string() },
+        if self.fixed_arm_pitch.is_some() { format!("{}", self.fixed_arm_pitch.unwrap()) } else { "None".to_string() },
         self.use_ours)
     }
 }
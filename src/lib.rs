@@ -20,9 +20,19 @@ pub mod prelude {
     pub use crate::galaxy::map::division_level::GalacticMapDivisionLevel;
     pub use crate::galaxy::map::hex::types::*;
     pub use crate::galaxy::map::hex::GalacticHex;
+    pub use crate::galaxy::map::jump_route::types::*;
+    pub use crate::galaxy::map::jump_route::JumpRouteGraph;
+    pub use crate::galaxy::map::nebula::GalacticNebula;
+    pub use crate::galaxy::map::sector_export::T5SectorExport;
+    pub use crate::galaxy::map::star_catalog::types::*;
+    pub use crate::galaxy::map::star_catalog::StarCatalog;
+    pub use crate::galaxy::map::sub_region::GalacticSubRegion;
+    pub use crate::galaxy::map::trade_route::types::*;
+    pub use crate::galaxy::map::trade_route::TradeNetwork;
     pub use crate::galaxy::map::types::*;
     pub use crate::galaxy::neighborhood::types::*;
     pub use crate::galaxy::neighborhood::GalacticNeighborhood;
+    pub use crate::galaxy::shape::GalacticShape;
     pub use crate::galaxy::types::*;
     pub use crate::galaxy::Galaxy;
     pub use crate::generator::types::*;
@@ -37,6 +47,7 @@ pub mod prelude {
     pub use crate::system::celestial_body::traits::types::*;
     pub use crate::system::celestial_body::traits::*;
     pub use crate::system::celestial_body::types::*;
+    pub use crate::system::celestial_body::uwp::UniversalWorldProfile;
     pub use crate::system::celestial_body::world::types::*;
     pub use crate::system::celestial_body::world::WorldGenerator;
     pub use crate::system::celestial_body::CelestialBody;
@@ -54,6 +65,8 @@ pub mod prelude {
     pub use crate::system::orbital_point::OrbitalPoint;
     pub use crate::system::star::types::*;
     pub use crate::system::star::Star;
+    pub use crate::system::template::types::*;
+    pub use crate::system::template::SystemTemplate;
     pub use crate::system::types::*;
     pub use crate::system::StarSystem;
     pub use crate::universe::types::*;
@@ -129,7 +142,8 @@ mod tests {
                 .get_division_at_level(coord, 1)
                 .expect("Should have returned a sub-sector.");
             let hex = galaxy.get_hex(coord).expect("Should have returned an hex.");
-            let system = StarSystem::generate(i as u16, coord, &hex, &sub_sector, &mut galaxy);
+            let system = StarSystem::generate(i as u16, coord, &hex, &sub_sector, &mut galaxy)
+                .expect("Should have generated a system.");
             let main_star = system
                 .clone()
                 .all_objects
@@ -163,7 +177,8 @@ mod tests {
                 .get_division_at_level(coord, 1)
                 .expect("Should have returned a sub-sector.");
             let hex = galaxy.get_hex(coord).expect("Should have returned an hex.");
-            let system = StarSystem::generate(i as u16, coord, &hex, &sub_sector, &mut galaxy);
+            let system = StarSystem::generate(i as u16, coord, &hex, &sub_sector, &mut galaxy)
+                .expect("Should have generated a system.");
             let main_star = system
                 .clone()
                 .all_objects
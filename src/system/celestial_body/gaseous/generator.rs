@@ -24,6 +24,7 @@ impl GaseousBodyDetails {
         star_type: &StarSpectralType,
         star_class: &StarLuminosityClass,
         star_luminosity: f32,
+        star_metallicity: f32,
         star_traits: &Vec<StarPeculiarity>,
         primary_star_mass: f64,
         gas_giant_arrangement: GasGiantArrangement,
@@ -138,6 +139,7 @@ impl GaseousBodyDetails {
                 star_id,
                 ConversionUtils::solar_mass_to_earth_mass(star_mass),
                 gas_giant_arrangement,
+                system_traits,
                 body_id,
                 &Some(orbit.clone()),
                 orbit_distance,
@@ -159,6 +161,7 @@ impl GaseousBodyDetails {
                 star_age,
                 star_mass,
                 star_luminosity,
+                star_metallicity,
                 star_type,
                 star_class,
                 star_traits,
@@ -47,6 +47,7 @@ impl IcyBodyDetails {
         star_type: &StarSpectralType,
         star_class: &StarLuminosityClass,
         star_luminosity: f32,
+        star_metallicity: f32,
         star_traits: &Vec<StarPeculiarity>,
         primary_star_mass: f64,
         gas_giant_arrangement: GasGiantArrangement,
@@ -147,6 +148,7 @@ impl IcyBodyDetails {
                         star_id,
                         ConversionUtils::solar_mass_to_earth_mass(star_mass),
                         gas_giant_arrangement,
+                        system_traits,
                         body_id,
                         &own_orbit,
                         orbit_distance,
@@ -173,6 +175,7 @@ impl IcyBodyDetails {
                     star_age,
                     star_mass,
                     star_luminosity,
+                    star_metallicity,
                     star_type,
                     star_class,
                     star_traits,
@@ -257,6 +260,7 @@ impl IcyBodyDetails {
                 star_id,
                 ConversionUtils::solar_mass_to_earth_mass(star_mass),
                 gas_giant_arrangement,
+                system_traits,
                 body_id,
                 &own_orbit,
                 orbit_distance,
@@ -281,6 +285,7 @@ impl IcyBodyDetails {
                 star_age,
                 star_mass,
                 star_luminosity,
+                star_metallicity,
                 star_type,
                 star_class,
                 star_traits,
@@ -7,6 +7,7 @@ pub mod moon;
 pub mod telluric;
 pub mod traits;
 pub mod types;
+pub mod uwp;
 pub mod world;
 
 #[derive(Clone, PartialEq, PartialOrd, Debug, SmartDefault, Serialize, Deserialize)]
@@ -73,4 +74,9 @@ impl CelestialBody {
     pub fn is_stub(self) -> bool {
         self.stub
     }
+
+    /// Returns the Traveller [UniversalWorldProfile] of this body. Only its physical digits are known for now.
+    pub fn get_uwp(&self) -> UniversalWorldProfile {
+        UniversalWorldProfile::from_body(self)
+    }
 }
@@ -21,6 +21,7 @@ impl MoonGenerator {
         star_age: f32,
         star_mass: f64,
         star_luminosity: f32,
+        star_metallicity: f32,
         star_type: &StarSpectralType,
         star_class: &StarLuminosityClass,
         star_traits: &Vec<StarPeculiarity>,
@@ -69,6 +70,7 @@ impl MoonGenerator {
             star_age,
             star_mass,
             star_luminosity,
+            star_metallicity,
             star_type,
             star_class,
             star_traits,
@@ -105,6 +107,7 @@ impl MoonGenerator {
         star_age: f32,
         star_mass: f64,
         star_luminosity: f32,
+        star_metallicity: f32,
         star_type: &StarSpectralType,
         star_class: &StarLuminosityClass,
         star_traits: &Vec<StarPeculiarity>,
@@ -176,6 +179,7 @@ impl MoonGenerator {
             star_age,
             star_mass,
             star_luminosity,
+            star_metallicity,
             star_type,
             star_class,
             star_traits,
@@ -212,6 +216,7 @@ impl MoonGenerator {
         star_age: f32,
         star_mass: f64,
         star_luminosity: f32,
+        star_metallicity: f32,
         star_type: &StarSpectralType,
         star_class: &StarLuminosityClass,
         star_traits: &Vec<StarPeculiarity>,
@@ -323,10 +328,11 @@ impl MoonGenerator {
                     ..settings.clone()
                 };
 
+                // Moons form from the same material as their planet, so the star's metallicity weighs on their makeup too
                 let moon_type = if blackbody_temperature >= 170 {
-                    generate_inner_body_type(&mut rng, settings.clone())
+                    generate_inner_body_type(&mut rng, settings.clone(), star_metallicity)
                 } else {
-                    generate_outer_body_type(&mut rng, settings.clone())
+                    generate_outer_body_type(&mut rng, settings.clone(), star_metallicity)
                 };
 
                 if moon_type == CelestialBodyComposition::Metallic {
@@ -345,6 +351,7 @@ impl MoonGenerator {
                 star_age,
                 star_mass,
                 star_luminosity,
+                star_metallicity,
                 star_type,
                 star_class,
                 star_traits,
@@ -510,6 +517,7 @@ impl MoonGenerator {
                                 star_id,
                                 ConversionUtils::solar_mass_to_earth_mass(star_mass),
                                 gas_giant_arrangement,
+                                system_traits,
                                 moon_id,
                                 &moon_stub.own_orbit,
                                 moon_orbit_distance,
@@ -59,6 +59,7 @@ impl TelluricBodyDetails {
         star_type: &StarSpectralType,
         star_class: &StarLuminosityClass,
         star_luminosity: f32,
+        star_metallicity: f32,
         star_traits: &Vec<StarPeculiarity>,
         primary_star_mass: f64,
         gas_giant_arrangement: GasGiantArrangement,
@@ -149,6 +150,7 @@ impl TelluricBodyDetails {
                     star_id,
                     ConversionUtils::solar_mass_to_earth_mass(star_mass),
                     gas_giant_arrangement,
+                    system_traits,
                     body_id,
                     &own_orbit,
                     orbit_distance,
@@ -176,6 +178,7 @@ impl TelluricBodyDetails {
                 star_age,
                 star_mass,
                 star_luminosity,
+                star_metallicity,
                 star_type,
                 star_class,
                 star_traits,
@@ -395,6 +398,7 @@ impl TelluricBodyDetails {
         star_type: &StarSpectralType,
         star_class: &StarLuminosityClass,
         star_luminosity: f32,
+        star_metallicity: f32,
         star_traits: &Vec<StarPeculiarity>,
         primary_star_mass: f64,
         gas_giant_arrangement: GasGiantArrangement,
@@ -483,6 +487,7 @@ impl TelluricBodyDetails {
                     star_id,
                     ConversionUtils::solar_mass_to_earth_mass(star_mass),
                     gas_giant_arrangement,
+                    system_traits,
                     body_id,
                     &own_orbit,
                     orbit_distance,
@@ -510,6 +515,7 @@ impl TelluricBodyDetails {
                 star_age,
                 star_mass,
                 star_luminosity,
+                star_metallicity,
                 star_type,
                 star_class,
                 star_traits,
@@ -0,0 +1,210 @@
+use crate::internal::*;
+use crate::prelude::*;
+
+impl UniversalWorldProfile {
+    /// Derives the physical digits of the [UniversalWorldProfile] of the given [CelestialBody]. See [Self::get_size_code],
+    /// [Self::get_atmosphere_code] and [Self::get_hydrographics_code] for the mapping tables. Gaseous and icy giants, lacking a
+    /// surface, are given the exotic atmosphere code (A) and no hydrographics.
+    pub fn from_body(body: &CelestialBody) -> Self {
+        let size = Self::get_size_code(body.radius);
+        match &body.details {
+            CelestialBodyDetails::Telluric(details) => Self::new(
+                size,
+                Self::get_atmosphere_code(
+                    details.atmospheric_pressure,
+                    &details.atmospheric_composition,
+                ),
+                Self::get_hydrographics_code(details.hydrosphere),
+            ),
+            CelestialBodyDetails::Gaseous(_) | CelestialBodyDetails::Icy(_) => {
+                Self::new(size, 10, 0)
+            }
+            CelestialBodyDetails::Cloud(_) => Self::new(0, 0, 0),
+        }
+    }
+
+    /// Returns the size code of a world of the given radius in Earth radii, from its diameter:
+    ///
+    /// | Code | Diameter                     |
+    /// |------|------------------------------|
+    /// | 0    | Less than 800 km (asteroids) |
+    /// | 1    | 800 to 2,399 km              |
+    /// | 2    | 2,400 to 3,999 km            |
+    /// | n    | Around n × 1,600 km          |
+    /// | F    | 23,200 km and more           |
+    pub fn get_size_code(radius: f64) -> u8 {
+        let diameter = ConversionUtils::earth_radii_to_kilometers(radius) * 2.0;
+        if diameter < 800.0 {
+            0
+        } else {
+            ((diameter / 1600.0).round() as u8).clamp(1, 15)
+        }
+    }
+
+    /// Returns the atmosphere code of a world with the given atmospheric pressure in atm and composition. An atmosphere is breathable
+    /// when its oxygen partial pressure is at least 0.1 atm, and tainted when it holds more than 1% of a toxic gas (like carbon monoxide,
+    /// ammonia or sulfur compounds) or more than 5% of carbon dioxide:
+    ///
+    /// | Code | Atmosphere           | Condition                                   |
+    /// |------|----------------------|---------------------------------------------|
+    /// | 0    | None                 | Less than 0.001 atm                         |
+    /// | 1    | Trace                | Less than 0.1 atm                           |
+    /// | 2, 3 | Very thin (tainted)  | Breathable, less than 0.43 atm              |
+    /// | 4, 5 | Thin (tainted)       | Breathable, less than 0.7 atm               |
+    /// | 6, 7 | Standard (tainted)   | Breathable, less than 1.5 atm               |
+    /// | 8, 9 | Dense (tainted)      | Breathable, less than 2.5 atm               |
+    /// | A    | Exotic               | Not breathable                              |
+    /// | B    | Corrosive            | More than 1% of acids or chlorine compounds |
+    /// | C    | Insidious            | Corrosive, 10 atm or more                   |
+    /// | D    | Dense, high          | Breathable, 2.5 atm or more                 |
+    pub fn get_atmosphere_code(pressure: f32, composition: &[(f32, ChemicalComponent)]) -> u8 {
+        if pressure < 0.001 {
+            return 0;
+        } else if pressure < 0.1 {
+            return 1;
+        }
+        let share_of = |components: &[ChemicalComponent]| -> f32 {
+            composition
+                .iter()
+                .filter(|(_, component)| components.contains(component))
+                .map(|(percentage, _)| *percentage)
+                .sum()
+        };
+
+        let corrosive = share_of(&[
+            ChemicalComponent::SulfuricAcid,
+            ChemicalComponent::NitricAcid,
+            ChemicalComponent::Chlorine,
+            ChemicalComponent::TitaniumTetrachloride,
+            ChemicalComponent::ChromiumChloride,
+            ChemicalComponent::HydrogenCyanide,
+        ]) > 1.0;
+        if corrosive {
+            return if pressure >= 10.0 { 12 } else { 11 };
+        }
+
+        let oxygen_pressure = share_of(&[ChemicalComponent::Oxygen]) / 100.0 * pressure;
+        if oxygen_pressure < 0.1 {
+            return 10;
+        }
+
+        let tainted = share_of(&[
+            ChemicalComponent::CarbonMonoxide,
+            ChemicalComponent::Ammonia,
+            ChemicalComponent::HydrogenSulfide,
+            ChemicalComponent::SulfurDioxide,
+            ChemicalComponent::NitricOxide,
+            ChemicalComponent::NitrogenDioxide,
+            ChemicalComponent::Methane,
+            ChemicalComponent::Formaldehyde,
+            ChemicalComponent::Benzene,
+        ]) > 1.0
+            || share_of(&[ChemicalComponent::CarbonDioxide]) > 5.0;
+        let (clean, dirty) = if pressure < 0.43 {
+            (3, 2)
+        } else if pressure < 0.7 {
+            (5, 4)
+        } else if pressure < 1.5 {
+            (6, 7)
+        } else if pressure < 2.5 {
+            (8, 9)
+        } else {
+            (13, 13)
+        };
+        if tainted {
+            dirty
+        } else {
+            clean
+        }
+    }
+
+    /// Returns the hydrographics code of a world whose surface is covered by liquid at the given percentage:
+    ///
+    /// | Code | Surface covered by liquid |
+    /// |------|---------------------------|
+    /// | 0    | Less than 6%              |
+    /// | 1    | 6% to 15%                 |
+    /// | n    | (n × 10 - 4)% to (n × 10 + 5)% |
+    /// | A    | 96% and more              |
+    pub fn get_hydrographics_code(hydrosphere: f32) -> u8 {
+        (((hydrosphere + 4.0) / 10.0).floor() as u8).min(10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derive_earth_like_profiles() {
+        let earth_air = vec![
+            (78.0, ChemicalComponent::Nitrogen),
+            (21.0, ChemicalComponent::Oxygen),
+            (0.9, ChemicalComponent::Argon),
+        ];
+        assert_eq!(UniversalWorldProfile::get_size_code(1.0), 8);
+        assert_eq!(UniversalWorldProfile::get_size_code(0.01), 0);
+        assert_eq!(UniversalWorldProfile::get_size_code(11.0), 15);
+        assert_eq!(
+            UniversalWorldProfile::get_atmosphere_code(1.0, &earth_air),
+            6
+        );
+        assert_eq!(
+            UniversalWorldProfile::get_atmosphere_code(0.6, &earth_air),
+            5
+        );
+        assert_eq!(
+            UniversalWorldProfile::get_atmosphere_code(0.0, &earth_air),
+            0
+        );
+        assert_eq!(
+            UniversalWorldProfile::get_atmosphere_code(0.05, &earth_air),
+            1
+        );
+        assert_eq!(
+            UniversalWorldProfile::get_atmosphere_code(3.0, &earth_air),
+            13
+        );
+        assert_eq!(
+            UniversalWorldProfile::get_atmosphere_code(
+                1.0,
+                &[
+                    (70.0, ChemicalComponent::Nitrogen),
+                    (20.0, ChemicalComponent::Oxygen),
+                    (10.0, ChemicalComponent::CarbonDioxide),
+                ]
+            ),
+            7
+        );
+        assert_eq!(
+            UniversalWorldProfile::get_atmosphere_code(
+                90.0,
+                &[
+                    (96.5, ChemicalComponent::CarbonDioxide),
+                    (3.5, ChemicalComponent::SulfuricAcid),
+                ]
+            ),
+            12
+        );
+        assert_eq!(
+            UniversalWorldProfile::get_atmosphere_code(
+                0.8,
+                &[(100.0, ChemicalComponent::Nitrogen)]
+            ),
+            10
+        );
+        assert_eq!(UniversalWorldProfile::get_hydrographics_code(0.0), 0);
+        assert_eq!(UniversalWorldProfile::get_hydrographics_code(5.0), 0);
+        assert_eq!(UniversalWorldProfile::get_hydrographics_code(71.0), 7);
+        assert_eq!(UniversalWorldProfile::get_hydrographics_code(100.0), 10);
+
+        let mut profile = UniversalWorldProfile::new(8, 6, 7);
+        assert_eq!(format!("{}", profile), "?867???-?");
+        profile.starport = Some('A');
+        profile.population = Some(9);
+        profile.government = Some(7);
+        profile.law_level = Some(4);
+        profile.tech_level = Some(12);
+        assert_eq!(format!("{}", profile), "A867974-C");
+    }
+}
@@ -0,0 +1,69 @@
+use crate::internal::*;
+use crate::prelude::*;
+pub mod generator;
+
+/// The Universal World Profile of a world, as used by the Traveller role-playing game: a compact code summarizing its main
+/// characteristics, like "A867974-C". Only the physical digits can be derived for now, the others are left unknown until worlds are
+/// populated, and are then displayed as "?".
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize, Deserialize,
+)]
+pub struct UniversalWorldProfile {
+    /// The quality of the world's best starport, from 'A' (excellent) to 'E' (frontier), or 'X' if there is none.
+    pub starport: Option<char>,
+    /// The world's size code, roughly its diameter in thousands of miles.
+    pub size: u8,
+    /// The world's atmosphere code, describing its density and breathability.
+    pub atmosphere: u8,
+    /// The world's hydrographics code, the tenth of its surface covered by liquid.
+    pub hydrographics: u8,
+    /// The world's population code, the power of ten of its number of inhabitants.
+    pub population: Option<u8>,
+    /// The world's government code.
+    pub government: Option<u8>,
+    /// The world's law level code.
+    pub law_level: Option<u8>,
+    /// The world's technology level code.
+    pub tech_level: Option<u8>,
+}
+
+impl UniversalWorldProfile {
+    /// Creates a new [UniversalWorldProfile] with only its physical digits known.
+    pub fn new(size: u8, atmosphere: u8, hydrographics: u8) -> Self {
+        Self {
+            starport: None,
+            size,
+            atmosphere,
+            hydrographics,
+            population: None,
+            government: None,
+            law_level: None,
+            tech_level: None,
+        }
+    }
+}
+
+impl Display for UniversalWorldProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digit = |value: Option<u8>| value.map_or('?', to_ehex);
+        write!(
+            f,
+            "{}{}{}{}{}{}{}-{}",
+            self.starport.unwrap_or('?'),
+            to_ehex(self.size),
+            to_ehex(self.atmosphere),
+            to_ehex(self.hydrographics),
+            digit(self.population),
+            digit(self.government),
+            digit(self.law_level),
+            digit(self.tech_level)
+        )
+    }
+}
+
+/// Returns the extended hexadecimal digit used by Traveller for the given value: 0 to 9, then A to Z skipping I and O. Values above 33
+/// are written as Z.
+fn to_ehex(value: u8) -> char {
+    const DIGITS: &[u8] = b"0123456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+    DIGITS[(value as usize).min(DIGITS.len() - 1)] as char
+}
@@ -363,6 +363,24 @@ impl WorldGenerator {
                     }
                 }
             }
+            // Carbon-rich systems form carbon planets, made of carbides and graphite instead of silicates, where oxygen is scarce
+            if system_traits.contains(&SystemPeculiarity::CarbonRich) {
+                let roll = rng.roll(1, 6, 0);
+                special_traits.push(CelestialBodySpecialTrait::UnusualElementPresence((
+                    ChemicalComponent::Carbon,
+                    if roll <= 3 {
+                        ElementPresenceOccurrence::High
+                    } else if roll <= 5 {
+                        ElementPresenceOccurrence::VeryHigh
+                    } else {
+                        ElementPresenceOccurrence::Omnipresence
+                    },
+                )));
+                special_traits.push(CelestialBodySpecialTrait::UnusualElementPresence((
+                    ChemicalComponent::Oxygen,
+                    ElementPresenceOccurrence::Low,
+                )));
+            }
         }
 
         // TODO: Atmospheric composition
@@ -3735,30 +3753,43 @@ fn generate_atmosphere(
     };
     atmospheric_mass_modifier += if is_moon { -2 } else { 0 };
     for peculiarity in star_traits {
-        if let StarPeculiarity::UnusualMetallicity(metallicity_difference) = peculiarity {
-            if discriminant(metallicity_difference)
-                == discriminant(&StarMetallicityDifference::MuchHigher)
-            {
-                2
-            } else if discriminant(metallicity_difference)
-                == discriminant(&StarMetallicityDifference::Higher)
+        atmospheric_mass_modifier +=
+            if let StarPeculiarity::UnusualMetallicity(metallicity_difference) = peculiarity {
+                if discriminant(metallicity_difference)
+                    == discriminant(&StarMetallicityDifference::MuchHigher)
+                {
+                    2
+                } else if discriminant(metallicity_difference)
+                    == discriminant(&StarMetallicityDifference::Higher)
+                {
+                    1
+                } else if discriminant(metallicity_difference)
+                    == discriminant(&StarMetallicityDifference::Lower)
+                {
+                    -1
+                } else {
+                    -2
+                }
+            } else if discriminant(peculiarity)
+                == discriminant(&StarPeculiarity::ExcessiveRadiation)
+                || discriminant(peculiarity) == discriminant(&StarPeculiarity::StrongMagneticField)
             {
-                1
-            } else if discriminant(metallicity_difference)
-                == discriminant(&StarMetallicityDifference::Lower)
+                -2
+            } else if discriminant(peculiarity)
+                == discriminant(&StarPeculiarity::PowerfulStellarWinds)
             {
-                -1
+                // Stellar winds strip the atmospheres away
+                -3
+            } else if let StarPeculiarity::RotationAnomaly(speed) = peculiarity {
+                match speed {
+                    RotationAnomalySpeed::MuchSlower => 2,
+                    RotationAnomalySpeed::Slower => 1,
+                    RotationAnomalySpeed::Faster => -1,
+                    RotationAnomalySpeed::MuchFaster => -2,
+                }
             } else {
-                -2
-            }
-        } else if discriminant(peculiarity) == discriminant(&StarPeculiarity::ExcessiveRadiation)
-            || discriminant(peculiarity) == discriminant(&StarPeculiarity::PowerfulStellarWinds)
-            || discriminant(peculiarity) == discriminant(&StarPeculiarity::StrongMagneticField)
-        {
-            -2
-        } else {
-            0
-        };
+                0
+            };
     }
     let mut rng = SeededDiceRoller::new(
         &settings.seed,
@@ -101,15 +101,23 @@ fn generate_orbits_and_bodies(
         let star_age = star.age;
         let star_mass = star.mass;
         let star_luminosity = star.luminosity;
+        let star_metallicity = star.metallicity;
         let star_type = star.spectral_type.clone();
         let star_class = star.luminosity_class.clone();
         let star_traits = star.special_traits.clone();
+        let star_zones = star.zones.clone();
+        let star_distance_from_center = star
+            .orbit
+            .clone()
+            .unwrap_or_default()
+            .average_distance_from_system_center;
         let gas_giant_arrangement = generate_gas_giant_arrangement(
             *major_bodies_left,
             star.orbital_point_id,
             &star.special_traits,
             &star.spectral_type,
             &star.population,
+            star.metallicity,
             system_traits,
             &system_index,
             &coord,
@@ -141,6 +149,7 @@ fn generate_orbits_and_bodies(
                 star_age,
                 star_mass,
                 star_luminosity,
+                star_metallicity,
                 &star.spectral_type,
                 &star.luminosity_class,
                 &star.special_traits,
@@ -230,6 +239,7 @@ fn generate_orbits_and_bodies(
                 star_age,
                 star_mass,
                 star_luminosity,
+                star_metallicity,
                 &star_type,
                 &star_class,
                 &star_traits,
@@ -253,10 +263,83 @@ fn generate_orbits_and_bodies(
         );
             result.append(new_objects);
         }
+
+        if star_traits.contains(&StarPeculiarity::CircumstellarDisk) {
+            if let Some((orbit, disk)) = make_protoplanetary_disk(
+                star_id,
+                &star_name,
+                star_mass,
+                &star_zones,
+                star_distance_from_center,
+                next_id,
+            ) {
+                star_orbital_point.orbits.push(orbit);
+                star_orbital_point.orbits.sort_by(|a, b| {
+                    a.average_distance
+                        .partial_cmp(&b.average_distance)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                result.push(disk);
+            }
+        }
     }
     result
 }
 
+/// Creates the dust-rich disk left around a star whose planetary formation failed. It spans the zones where planets could have
+/// formed, from the inner limit to the snow line.
+fn make_protoplanetary_disk(
+    star_id: u32,
+    star_name: &Rc<str>,
+    star_mass: f64,
+    star_zones: &[StarZone],
+    star_distance_from_center: f64,
+    next_id: &mut u32,
+) -> Option<(Orbit, OrbitalPoint)> {
+    let start = star_zones
+        .iter()
+        .find(|zone| zone.zone_type == ZoneType::InnerZone || zone.zone_type == ZoneType::BioZone)?
+        .start;
+    let end = star_zones
+        .iter()
+        .find(|zone| zone.zone_type == ZoneType::OuterZone)
+        .map_or(start * 10.0, |zone| zone.start);
+    if end <= start {
+        return None;
+    }
+
+    let disk_id = *next_id;
+    *next_id += 1;
+    let average_distance = (start + end) / 2.0;
+    let orbit = Orbit::new(
+        star_id,
+        Some(disk_id),
+        ZoneType::InnerZone,
+        average_distance,
+        start,
+        end,
+        average_distance + star_distance_from_center,
+        0.0,
+        0.0,
+        0.0,
+        (average_distance.powf(1.5) / star_mass.max(0.01).sqrt() * 365.256) as f32,
+        0.0,
+        0.0,
+    );
+    let disk = OrbitalPoint::new(
+        disk_id,
+        Some(orbit.clone()),
+        AstronomicalObject::TelluricDisk(CelestialDisk::new(
+            Some(orbit.clone()),
+            disk_id,
+            format!("{} Disk", star_name).into(),
+            CelestialDiskType::ProtoplanetaryDisk,
+        )),
+        Vec::new(),
+    );
+    Some((orbit, disk))
+}
+
 fn get_orbits_with_gas_giants(
     mut new_objects: &mut Vec<OrbitalPoint>,
     star_orbital_point: &mut OrbitalPoint,
@@ -407,6 +490,11 @@ fn generate_outer_orbits(
     while !outer_orbits_done {
         let multiplier = get_orbit_multiplier(rng);
         let next_orbit = *last_orbit * multiplier;
+        if next_orbit <= 0.0 {
+            // Stars whose every zone is forbidden by a companion have no orbit to start from.
+            outer_orbits_done = true;
+            break;
+        }
         *last_orbit = next_orbit;
 
         let next_orbit_from_center = next_orbit + star_orbit.average_distance_from_system_center;
@@ -488,6 +576,11 @@ fn place_body_stubs(
     mut orbit_contents: Vec<(usize, f64, Option<u32>)>,
 ) -> Vec<(usize, f64, Option<u32>)> {
     let mut populated_orbit_index = 0;
+    let star_metallicity = if let AstronomicalObject::Star(star) = &star_orbital_point.object {
+        star.metallicity
+    } else {
+        0.0
+    };
     star_orbital_point
         .orbits
         .iter_mut()
@@ -549,7 +642,7 @@ fn place_body_stubs(
                                         ..settings.clone()
                                     };
 
-                                    let body_type = generate_inner_body_type(&mut rng, settings);
+                                    let body_type = generate_inner_body_type(&mut rng, settings, star_metallicity);
                                     let mut body_orbital_point = generate_new_body_and_moons(
                                         body_id,
                                         system_traits,
@@ -559,6 +652,7 @@ fn place_body_stubs(
                                         star_age,
                                         star_mass,
                                         star_luminosity,
+                                        star_metallicity,
                                         star_type,
                                         star_class,
                                         star_traits,
@@ -603,7 +697,7 @@ fn place_body_stubs(
                                     };
 
                                     let body_type =
-                                        generate_outer_body_type(&mut rng, settings.clone());
+                                        generate_outer_body_type(&mut rng, settings.clone(), star_metallicity);
                                     let mut body_orbital_point = generate_new_body_and_moons(
                                         body_id,
                                         system_traits,
@@ -613,6 +707,7 @@ fn place_body_stubs(
                                         star_age,
                                         star_mass,
                                         star_luminosity,
+                                        star_metallicity,
                                         star_type,
                                         star_class,
                                         star_traits,
@@ -658,7 +753,7 @@ fn place_body_stubs(
                                         ..settings.clone()
                                     };
 
-                                    let body_type = generate_inner_body_type(&mut rng, settings);
+                                    let body_type = generate_inner_body_type(&mut rng, settings, star_metallicity);
                                     let mut body_orbital_point = generate_new_body_and_moons(
                                         body_id,
                                         system_traits,
@@ -668,6 +763,7 @@ fn place_body_stubs(
                                         star_age,
                                         star_mass,
                                         star_luminosity,
+                                        star_metallicity,
                                         star_type,
                                         star_class,
                                         star_traits,
@@ -717,7 +813,7 @@ fn place_body_stubs(
                                         ..settings.clone()
                                     };
 
-                                    let body_type = generate_outer_body_type(&mut rng, settings);
+                                    let body_type = generate_outer_body_type(&mut rng, settings, star_metallicity);
                                     let mut body_orbital_point = generate_new_body_and_moons(
                                         body_id,
                                         system_traits,
@@ -727,6 +823,7 @@ fn place_body_stubs(
                                         star_age,
                                         star_mass,
                                         star_luminosity,
+                                        star_metallicity,
                                         star_type,
                                         star_class,
                                         star_traits,
@@ -778,7 +875,7 @@ fn place_body_stubs(
                                         ..settings.clone()
                                     };
 
-                                    let body_type = generate_inner_body_type(&mut rng, settings);
+                                    let body_type = generate_inner_body_type(&mut rng, settings, star_metallicity);
                                     let mut body_orbital_point = generate_new_body_and_moons(
                                         body_id,
                                         system_traits,
@@ -788,6 +885,7 @@ fn place_body_stubs(
                                         star_age,
                                         star_mass,
                                         star_luminosity,
+                                        star_metallicity,
                                         star_type,
                                         star_class,
                                         star_traits,
@@ -836,7 +934,7 @@ fn place_body_stubs(
                                         ..settings.clone()
                                     };
 
-                                    let body_type = generate_outer_body_type(&mut rng, settings);
+                                    let body_type = generate_outer_body_type(&mut rng, settings, star_metallicity);
                                     let mut body_orbital_point = generate_new_body_and_moons(
                                         body_id,
                                         system_traits,
@@ -846,6 +944,7 @@ fn place_body_stubs(
                                         star_age,
                                         star_mass,
                                         star_luminosity,
+                                        star_metallicity,
                                         star_type,
                                         star_class,
                                         star_traits,
@@ -906,6 +1005,7 @@ fn replace_stubs(
     star_age: f32,
     star_mass: f64,
     star_luminosity: f32,
+    star_metallicity: f32,
     star_type: &StarSpectralType,
     star_class: &StarLuminosityClass,
     star_traits: &Vec<StarPeculiarity>,
@@ -965,6 +1065,7 @@ fn replace_stubs(
                             _ => false,
                         } {
                             let size_modifier = get_body_size_modifier(
+                                system_traits,
                                 coord,
                                 system_index,
                                 star_orbital_point.id,
@@ -986,6 +1087,7 @@ fn replace_stubs(
                                 star_age,
                                 star_mass,
                                 star_luminosity,
+                                star_metallicity,
                                 star_type,
                                 star_class,
                                 star_traits,
@@ -1198,6 +1300,7 @@ fn generate_body_and_moons(
     star_age: f32,
     star_mass: f64,
     star_luminosity: f32,
+    star_metallicity: f32,
     star_type: &StarSpectralType,
     star_class: &StarLuminosityClass,
     star_traits: &Vec<StarPeculiarity>,
@@ -1269,6 +1372,7 @@ fn generate_body_and_moons(
             star_age,
             star_mass,
             star_luminosity,
+            star_metallicity,
             star_type,
             star_class,
             star_traits,
@@ -1301,6 +1405,7 @@ pub(crate) fn generate_body_from_type(
     star_age: f32,
     star_mass: f64,
     star_luminosity: f32,
+    star_metallicity: f32,
     star_type: &StarSpectralType,
     star_class: &StarLuminosityClass,
     star_traits: &Vec<StarPeculiarity>,
@@ -1333,6 +1438,7 @@ pub(crate) fn generate_body_from_type(
             star_type,
             star_class,
             star_luminosity,
+            star_metallicity,
             star_traits,
             primary_star_mass,
             gas_giant_arrangement,
@@ -1360,6 +1466,7 @@ pub(crate) fn generate_body_from_type(
             star_type,
             star_class,
             star_luminosity,
+            star_metallicity,
             star_traits,
             primary_star_mass,
             gas_giant_arrangement,
@@ -1387,6 +1494,7 @@ pub(crate) fn generate_body_from_type(
             star_type,
             star_class,
             star_luminosity,
+            star_metallicity,
             star_traits,
             primary_star_mass,
             gas_giant_arrangement,
@@ -1413,6 +1521,7 @@ pub(crate) fn generate_body_from_type(
             star_type,
             star_class,
             star_luminosity,
+            star_metallicity,
             star_traits,
             primary_star_mass,
             gas_giant_arrangement,
@@ -1428,6 +1537,7 @@ pub(crate) fn generate_body_from_type(
 }
 
 fn get_body_size_modifier(
+    system_traits: &[SystemPeculiarity],
     coord: SpaceCoordinates,
     system_index: u16,
     star_id: u32,
@@ -1485,6 +1595,20 @@ fn get_body_size_modifier(
         StarSpectralType::L(_) | StarSpectralType::T(_) | StarSpectralType::Y(_) => -50,
         _ => 0,
     };
+    // Denser debris leaves more belts and smaller bodies behind, as do the planets shattered by a cataclysm
+    system_traits.iter().for_each(|t| {
+        size_modifier += match t {
+            SystemPeculiarity::UnusualDebrisDensity(DebrisDensity::MuchLower) => 40,
+            SystemPeculiarity::UnusualDebrisDensity(DebrisDensity::Lower) => 20,
+            SystemPeculiarity::UnusualDebrisDensity(DebrisDensity::Higher) => -30,
+            SystemPeculiarity::UnusualDebrisDensity(DebrisDensity::MuchHigher) => -60,
+            SystemPeculiarity::Cataclysm(CataclysmSeverity::Minor) => -10,
+            SystemPeculiarity::Cataclysm(CataclysmSeverity::Major) => -30,
+            SystemPeculiarity::Cataclysm(CataclysmSeverity::Extreme) => -60,
+            SystemPeculiarity::Cataclysm(CataclysmSeverity::Ultimate) => -100,
+            _ => 0,
+        }
+    });
     size_modifier
 }
 
@@ -1510,6 +1634,7 @@ fn generate_new_body_and_moons(
     star_age: f32,
     star_mass: f64,
     star_luminosity: f32,
+    star_metallicity: f32,
     star_type: &StarSpectralType,
     star_class: &StarLuminosityClass,
     star_traits: &Vec<StarPeculiarity>,
@@ -1561,6 +1686,7 @@ fn generate_new_body_and_moons(
                 star_type,
                 star_class,
                 star_luminosity,
+                star_metallicity,
                 star_traits,
                 primary_star_mass,
                 gas_giant_arrangement,
@@ -1598,6 +1724,7 @@ fn handle_proto_gas_giant_placement(
     star_age: f32,
     star_mass: f64,
     star_luminosity: f32,
+    star_metallicity: f32,
     star_type: &StarSpectralType,
     star_class: &StarLuminosityClass,
     star_traits: &Vec<StarPeculiarity>,
@@ -1678,6 +1805,7 @@ fn handle_proto_gas_giant_placement(
                 star_type,
                 star_class,
                 star_luminosity,
+                star_metallicity,
                 star_traits,
                 primary_star_mass,
                 gas_giant_arrangement,
@@ -1769,7 +1897,13 @@ fn generate_number_of_bodies(
         .expect("The roll should return a result.");
 
     // Special cases
-    if number_of_bodies_index == 1 {
+    if star
+        .special_traits
+        .contains(&StarPeculiarity::CircumstellarDisk)
+    {
+        // The disk is what is left of a failed planetary formation
+        return possible_results[1].result;
+    } else if number_of_bodies_index == 1 {
         star.special_traits.push(StarPeculiarity::CircumstellarDisk)
     } else if number_of_bodies_index == 3 && possible_results[number_of_bodies_index].result == 9 {
         possible_results[number_of_bodies_index].result += rng.roll(1, 4, 0) as i32;
@@ -1786,6 +1920,7 @@ fn generate_gas_giant_arrangement(
     star_traits: &Vec<StarPeculiarity>,
     star_type: &StarSpectralType,
     star_population: &StellarEvolution,
+    star_metallicity: f32,
     system_traits: &Vec<SystemPeculiarity>,
     system_index: &u16,
     coord: &SpaceCoordinates,
@@ -1829,6 +1964,15 @@ fn generate_gas_giant_arrangement(
             epistellar_chances
         };
 
+        // Gas giants are far more common around metal-rich stars
+        let (_, gaseous_factor) = get_metallicity_factors(star_metallicity);
+        conventional_chances = (conventional_chances as f32 * gaseous_factor) as i32;
+        eccentric_chances = (eccentric_chances as f32 * gaseous_factor) as i32;
+        epistellar_chances = (epistellar_chances as f32 * gaseous_factor) as i32;
+        if nothing_chances + conventional_chances + eccentric_chances + epistellar_chances == 0 {
+            nothing_chances = 1;
+        }
+
         SeededDiceRoller::new(
             &galaxy.settings.seed,
             &format!("sys_{}_{}_str_{}_gas_arr", coord, system_index, star_id),
@@ -2208,11 +2352,13 @@ fn should_spawn(mut rng: &mut SeededDiceRoller, spawn_chances: i32) -> bool {
     .expect("A boolean result should have been picked.")
 }
 
+/// Picks the composition of a body orbiting in the inner zone of a star of the given **metallicity**.
 pub(crate) fn generate_inner_body_type(
     mut rng: &mut SeededDiceRoller,
     settings: GenerationSettings,
+    metallicity: f32,
 ) -> CelestialBodyComposition {
-    // TODO: Add modifier according to star population and metallicity
+    let (rocky_factor, gaseous_factor) = get_metallicity_factors(metallicity);
     rng.get_result(&CopyableRollToProcess::new(
         vec![
             // CopyableWeightedResult::new(
@@ -2228,7 +2374,7 @@ pub(crate) fn generate_inner_body_type(
                 if settings.celestial_body.do_not_generate_metallic {
                     0
                 } else {
-                    2
+                    (20.0 * rocky_factor) as u32
                 },
             ),
             CopyableWeightedResult::new(
@@ -2236,7 +2382,7 @@ pub(crate) fn generate_inner_body_type(
                 if settings.celestial_body.do_not_generate_rocky {
                     0
                 } else {
-                    6
+                    (60.0 * rocky_factor) as u32
                 },
             ),
             CopyableWeightedResult::new(
@@ -2244,7 +2390,7 @@ pub(crate) fn generate_inner_body_type(
                 if settings.celestial_body.do_not_generate_icy {
                     0
                 } else {
-                    2
+                    20
                 },
             ),
             CopyableWeightedResult::new(
@@ -2252,7 +2398,7 @@ pub(crate) fn generate_inner_body_type(
                 if settings.celestial_body.do_not_generate_gaseous {
                     0
                 } else {
-                    1
+                    (10.0 * gaseous_factor) as u32
                 },
             ),
         ],
@@ -2261,11 +2407,13 @@ pub(crate) fn generate_inner_body_type(
     .expect("A body type should have been picked.")
 }
 
+/// Picks the composition of a body orbiting in the outer zone of a star of the given **metallicity**.
 pub(crate) fn generate_outer_body_type(
     mut rng: &mut SeededDiceRoller,
     settings: GenerationSettings,
+    metallicity: f32,
 ) -> CelestialBodyComposition {
-    // TODO: Add modifier according to star population and metallicity
+    let (rocky_factor, gaseous_factor) = get_metallicity_factors(metallicity);
     rng.get_result(&CopyableRollToProcess::new(
         vec![
             // CopyableWeightedResult::new(
@@ -2281,7 +2429,7 @@ pub(crate) fn generate_outer_body_type(
                 if settings.celestial_body.do_not_generate_metallic {
                     0
                 } else {
-                    1
+                    (10.0 * rocky_factor) as u32
                 },
             ),
             CopyableWeightedResult::new(
@@ -2289,7 +2437,7 @@ pub(crate) fn generate_outer_body_type(
                 if settings.celestial_body.do_not_generate_rocky {
                     0
                 } else {
-                    3
+                    (30.0 * rocky_factor) as u32
                 },
             ),
             CopyableWeightedResult::new(
@@ -2297,7 +2445,7 @@ pub(crate) fn generate_outer_body_type(
                 if settings.celestial_body.do_not_generate_icy {
                     0
                 } else {
-                    6
+                    60
                 },
             ),
             CopyableWeightedResult::new(
@@ -2305,7 +2453,7 @@ pub(crate) fn generate_outer_body_type(
                 if settings.celestial_body.do_not_generate_gaseous {
                     0
                 } else {
-                    6
+                    (60.0 * gaseous_factor) as u32
                 },
             ),
         ],
@@ -2313,3 +2461,12 @@ pub(crate) fn generate_outer_body_type(
     ))
     .expect("A body type should have been picked.")
 }
+
+/// Returns the factors to apply to the chances of forming rocky and gaseous bodies around a star of the given **metallicity**.
+/// Giant planets are much more frequent around metal-rich stars, while rocky ones only slightly follow that correlation.
+fn get_metallicity_factors(metallicity: f32) -> (f32, f32) {
+    (
+        10f32.powf(0.5 * metallicity).clamp(0.1, 2.0),
+        10f32.powf(2.0 * metallicity).clamp(0.01, 5.0),
+    )
+}
@@ -157,8 +157,8 @@ fn calculate_forbidden_zone(
     all_objects: &[OrbitalPoint],
 ) {
     let companion = get_closest_companion(orbital_point, all_objects);
-    let min_separation = get_min_star_separation(orbital_point, &companion);
-    let max_separation = get_max_star_separation(orbital_point, &companion);
+    let min_separation = get_min_star_separation(orbital_point, &companion, all_objects);
+    let max_separation = get_max_star_separation(orbital_point, &companion, all_objects);
 
     let forbidden_zone_inner_edge = min_separation / 3.0;
     let forbidden_zone_outer_edge = max_separation * 3.0;
@@ -199,33 +199,85 @@ fn get_closest_companion(star: &OrbitalPoint, all_objects: &[OrbitalPoint]) -> O
 }
 
 /// Get the radius before which planets could have a stable orbit around the first star of a binary pair.
-fn get_min_star_separation(object1: &OrbitalPoint, object2: &OrbitalPoint) -> f64 {
-    let orbit1 = object1
-        .own_orbit
-        .as_ref()
-        .expect("An OrbitalPoint's own orbit should always be filled.");
-    let perihelion_distance_object1 = (1.0 - orbit1.eccentricity as f64) * orbit1.average_distance;
-    let orbit2 = object2
-        .own_orbit
-        .as_ref()
-        .expect("An OrbitalPoint's own orbit should always be filled.");
-    let aphelion_distance_object2 = (1.0 + orbit2.eccentricity as f64) * orbit2.average_distance;
-    (aphelion_distance_object2 - perihelion_distance_object1).abs()
+/// Two members of the same pair are on opposite sides of their barycentre, so they are the closest at periastron. Stars of different
+/// pairs are at least as close as the periastron between the barycentres of their pairs, minus how far each star can be from its own.
+fn get_min_star_separation(
+    object1: &OrbitalPoint,
+    object2: &OrbitalPoint,
+    all_objects: &[OrbitalPoint],
+) -> f64 {
+    let (outer1, outer2, inner1, inner2) = get_separation_orbits(object1, object2, all_objects);
+    let outer_periastron =
+        outer1.map_or(0.0, |o| o.min_separation) + outer2.map_or(0.0, |o| o.min_separation);
+    let inner_apastron: f64 = inner1
+        .iter()
+        .chain(inner2.iter())
+        .map(|orbit| orbit.max_separation)
+        .sum();
+    (outer_periastron - inner_apastron).max(0.0)
 }
 
 /// Get the radius after which planets could have a stable orbit around the first star of a binary pair.
-fn get_max_star_separation(object1: &OrbitalPoint, object2: &OrbitalPoint) -> f64 {
-    let orbit1 = object1
-        .own_orbit
-        .as_ref()
-        .expect("An OrbitalPoint's own orbit should always be filled.");
-    let aphelion_distance_object1 = (1.0 + orbit1.eccentricity as f64) * orbit1.average_distance;
-    let orbit2 = object2
-        .own_orbit
-        .as_ref()
-        .expect("An OrbitalPoint's own orbit should always be filled.");
-    let perihelion_distance_object2 = (1.0 - orbit2.eccentricity as f64) * orbit2.average_distance;
-    (aphelion_distance_object1 - perihelion_distance_object2).abs()
+/// Two members of the same pair are on opposite sides of their barycentre, so they are the farthest at apastron. Stars of different
+/// pairs are at most as far as the apastron between the barycentres of their pairs, plus how far each star can be from its own.
+fn get_max_star_separation(
+    object1: &OrbitalPoint,
+    object2: &OrbitalPoint,
+    all_objects: &[OrbitalPoint],
+) -> f64 {
+    let (outer1, outer2, inner1, inner2) = get_separation_orbits(object1, object2, all_objects);
+    let outer_apastron =
+        outer1.map_or(0.0, |o| o.max_separation) + outer2.map_or(0.0, |o| o.max_separation);
+    let inner_apastron: f64 = inner1
+        .iter()
+        .chain(inner2.iter())
+        .map(|orbit| orbit.max_separation)
+        .sum();
+    outer_apastron + inner_apastron
+}
+
+/// Finds the pair both given objects belong to, directly or through the barycentres they orbit. Returns the orbits of the two members
+/// of that pair that hold each object, followed by the orbits between each object and that member.
+fn get_separation_orbits<'a>(
+    object1: &'a OrbitalPoint,
+    object2: &'a OrbitalPoint,
+    all_objects: &'a [OrbitalPoint],
+) -> (
+    Option<&'a Orbit>,
+    Option<&'a Orbit>,
+    Vec<&'a Orbit>,
+    Vec<&'a Orbit>,
+) {
+    let mut chain1 = get_orbit_chain(object1, all_objects);
+    let mut chain2 = get_orbit_chain(object2, all_objects);
+    // Both chains end at the system's center, so the members of the shared pair are found past their common end.
+    while let (Some(orbit1), Some(orbit2)) = (chain1.last(), chain2.last()) {
+        if orbit1.id != orbit2.id {
+            break;
+        }
+        chain1.pop();
+        chain2.pop();
+    }
+    let outer1 = chain1.pop();
+    let outer2 = chain2.pop();
+    (outer1, outer2, chain1, chain2)
+}
+
+/// Returns the orbits from the given object up to the center of the system, going through every barycentre it orbits.
+fn get_orbit_chain<'a>(
+    object: &'a OrbitalPoint,
+    all_objects: &'a [OrbitalPoint],
+) -> Vec<&'a Orbit> {
+    let mut chain = Vec::new();
+    let mut current = object;
+    while let Some(orbit) = current.own_orbit.as_ref() {
+        chain.push(orbit);
+        match all_objects.iter().find(|o| o.id == orbit.primary_body_id) {
+            Some(primary) => current = primary,
+            None => break,
+        }
+    }
+    chain
 }
 
 fn adjust_zones_for_forbidden(star: &mut Star) {
@@ -7,115 +7,388 @@ use std::collections::HashSet;
 mod constants;
 use crate::system::contents::generator::generate_stars_systems;
 use crate::system::contents::zones::generate_star_zones;
+use crate::system::orbital_point::generator::calculate_orbital_period;
 use constants::*;
 
+/// The lightest mass a companion can be given when making it lighter than the main star of its system, that of the lightest brown
+/// dwarfs.
+const LIGHTEST_COMPANION_MASS: f64 = 0.015;
+
 impl StarSystem {
-    /// Generates a brand new star system at the given coordinates
+    /// Generates a brand new star system at the given coordinates. The system is generated again until it is accepted by the
+    /// [SystemSettings], and an error is returned if none was within their retry budget.
     pub fn generate(
         system_index: u16,
         coord: SpaceCoordinates,
         hex: &GalacticHex,
         sub_sector: &GalacticMapDivision,
         galaxy: &mut Galaxy,
-    ) -> Self {
-        let mut center_id: u32 = 0;
-        let mut main_star_id: u32 = 0;
-        let mut all_objects: Vec<OrbitalPoint> = Vec::new();
-        // TODO: Did you add the special traits or is it just an empty array?
-        let mut special_traits: Vec<SystemPeculiarity> = Vec::new();
-
-        let name = get_system_name(system_index, coord, galaxy);
-
-        let mut accept_system = false;
-        let mut i = 0;
-        while !accept_system {
-            all_objects = Vec::new();
-            special_traits = Vec::new();
-
-            let number_of_stars =
-                generate_number_of_stars_in_system(i, system_index, coord, galaxy);
-            let mut stars = generate_stars(
-                i,
-                number_of_stars,
-                system_index,
-                name.clone(),
-                coord,
-                hex,
-                sub_sector,
-                galaxy,
-            );
+    ) -> Result<Self, Rc<str>> {
+        Self::generate_matching(system_index, coord, |_| true, hex, sub_sector, galaxy)
+    }
+
+    /// Generates a brand new star system at the given coordinates that satisfies the given **predicate** on top of the
+    /// [SystemSettings]. The system is generated again until it is accepted, and an error is returned if none was within the settings'
+    /// retry budget.
+    pub fn generate_matching(
+        system_index: u16,
+        coord: SpaceCoordinates,
+        predicate: impl Fn(&StarSystem) -> bool,
+        hex: &GalacticHex,
+        sub_sector: &GalacticMapDivision,
+        galaxy: &mut Galaxy,
+    ) -> Result<Self, Rc<str>> {
+        let settings = galaxy.settings.system.clone();
+        generate_matching_system(
+            system_index,
+            coord,
+            settings.filter.as_ref(),
+            &|system| (!settings.only_interesting || is_interesting(system)) && predicate(system),
+            hex,
+            sub_sector,
+            galaxy,
+        )
+    }
 
-            if stars.len() > 1 {
-                let result = generate_binary_relations(
-                    i,
-                    &mut stars,
-                    &mut all_objects,
+    /// Returns true if this system matches the filters of the given [SystemSettings].
+    pub(crate) fn is_accepted_by(&self, settings: &SystemSettings) -> bool {
+        (!settings.only_interesting || is_interesting(self))
+            && settings.filter.as_ref().map_or(true, |f| f.matches(self))
+    }
+
+    /// Generates a star system at the given coordinates that matches the given [SystemTemplate]. Its main star is generated with the
+    /// wanted type and class, and the bodies it lacks are placed around that star. Whatever the template leaves out is generated as
+    /// usual. The system is only generated again if it still doesn't match the template, and an error is returned if none did within
+    /// the retry budget of the [SystemSettings], whose filters are ignored.
+    pub fn generate_from_template(
+        system_index: u16,
+        coord: SpaceCoordinates,
+        template: &SystemTemplate,
+        hex: &GalacticHex,
+        sub_sector: &GalacticMapDivision,
+        galaxy: &mut Galaxy,
+    ) -> Result<Self, Rc<str>> {
+        generate_matching_system(
+            system_index,
+            coord,
+            Some(template),
+            &|_| true,
+            hex,
+            sub_sector,
+            galaxy,
+        )
+    }
+
+    /// Generates the star system described by the given [CatalogSystem]. Its stars are taken from the catalog while its planets are
+    /// generated as usual.
+    pub fn generate_from_catalog(
+        system_index: u16,
+        coord: SpaceCoordinates,
+        catalog_system: &CatalogSystem,
+        hex: &GalacticHex,
+        sub_sector: &GalacticMapDivision,
+        galaxy: &mut Galaxy,
+    ) -> Self {
+        let name = catalog_system.name.clone();
+        let stars: Vec<Star> = catalog_system
+            .stars
+            .iter()
+            .enumerate()
+            .map(|(star_index, catalog_star)| {
+                let evolution = generate_stellar_evolution(
+                    0,
+                    star_index as u16,
                     system_index,
                     coord,
+                    hex,
+                    sub_sector,
                     galaxy,
                 );
-                center_id = result.0;
-                main_star_id = result.1;
-
-                let mut calculated_ids = HashSet::new();
-                for id in all_objects.iter().map(|op| op.id).collect::<Vec<u32>>() {
-                    calculate_distance_from_system_center(
-                        id,
-                        &mut all_objects,
-                        &mut calculated_ids,
-                    );
-                }
-            } else {
-                let center =
-                    OrbitalPoint::new(0, None, AstronomicalObject::Star(stars.remove(0)), vec![]);
-                center_id = 0;
-                main_star_id = 0;
-                all_objects.push(center);
-            }
+                Star::from_catalog(
+                    catalog_star,
+                    star_index as u16,
+                    system_index,
+                    name.clone(),
+                    coord,
+                    evolution,
+                    hex,
+                    galaxy,
+                )
+            })
+            .collect();
+        let special_traits = generate_system_traits(0, system_index, coord, &stars, hex, galaxy);
+        let (center_id, main_star_id, all_objects) =
+            generate_system_contents(0, stars, &special_traits, system_index, coord, galaxy);
+        Self::new(name, center_id, main_star_id, all_objects, special_traits)
+    }
+}
 
-            // TODO: Generate dynamic parameters for star orbits
-            update_existing_orbits(&mut all_objects);
-            generate_star_zones(&mut all_objects);
-            generate_stars_systems(
-                i,
-                &mut all_objects,
+/// Generates star systems until one matches the given [SystemTemplate] and satisfies the given **predicate**. Returns an error if
+/// none did within the retry budget of the [SystemSettings].
+fn generate_matching_system(
+    system_index: u16,
+    coord: SpaceCoordinates,
+    template: Option<&SystemTemplate>,
+    predicate: &dyn Fn(&StarSystem) -> bool,
+    hex: &GalacticHex,
+    sub_sector: &GalacticMapDivision,
+    galaxy: &mut Galaxy,
+) -> Result<StarSystem, Rc<str>> {
+    let name = template
+        .and_then(|t| t.name.clone())
+        .unwrap_or_else(|| get_system_name(system_index, coord, galaxy));
+
+    if template.is_some_and(|t| t.number_of_stars == Some(0)) {
+        return Err("A system must have at least one star.".into());
+    }
+    // The template's main star is generated as a fixed star, and its bodies are placed once the rest of the system is generated.
+    let settings = template.map_or_else(
+        || galaxy.settings.clone(),
+        |t| t.get_generation_settings(&galaxy.settings),
+    );
+    let max_tries = galaxy.settings.system.max_tries;
+    for i in 0..max_tries {
+        let number_of_stars = match template.and_then(|t| t.number_of_stars) {
+            Some(number_of_stars) => number_of_stars,
+            None => generate_number_of_stars_in_system(i, system_index, coord, galaxy)?,
+        };
+        // A star that can't match its fixed type and class is generated again along with its whole system.
+        let Ok(mut stars) = generate_stars(
+            i,
+            number_of_stars,
+            system_index,
+            name.clone(),
+            coord,
+            hex,
+            sub_sector,
+            &settings,
+            galaxy,
+        ) else {
+            continue;
+        };
+        if template.is_some_and(|t| {
+            t.main_star_spectral_type.is_some() || t.main_star_luminosity_class.is_some()
+        }) && lighten_companions(
+            i,
+            &mut stars,
+            system_index,
+            name.clone(),
+            coord,
+            hex,
+            &settings,
+            galaxy,
+        )
+        .is_err()
+        {
+            continue;
+        }
+        if !template.map_or(true, |t| t.matches_stars(&stars)) {
+            continue;
+        }
+        let special_traits = generate_system_traits(i, system_index, coord, &stars, hex, galaxy);
+
+        let (center_id, main_star_id, mut all_objects) =
+            generate_system_contents(i, stars, &special_traits, system_index, coord, galaxy);
+        if let Some(template) = template {
+            template.place_bodies(
                 &special_traits,
                 system_index,
+                main_star_id,
                 coord,
-                galaxy,
+                &mut all_objects,
+                &settings,
             );
-            update_existing_orbits(&mut all_objects);
-
-            accept_system = if galaxy.settings.system.only_interesting {
-                let mut is_interesting = false;
-
-                is_interesting = all_objects
-                    .iter()
-                    .find(|o| {
-                        if let AstronomicalObject::TelluricBody(body) = o.object.clone() {
-                            if let CelestialBodyDetails::Telluric(details) = body.details {
-                                details.world_type == CelestialBodyWorldType::Terrestrial
-                                    || details.world_type == CelestialBodyWorldType::Ocean
-                            } else {
-                                false
-                            }
-                        } else {
-                            false
-                        }
-                    })
-                    .is_some();
+        }
+        let system = StarSystem::new(
+            name.clone(),
+            center_id,
+            main_star_id,
+            all_objects,
+            special_traits,
+        );
+        if template.map_or(true, |t| t.matches(&system)) && predicate(&system) {
+            return Ok(system);
+        }
+    }
+    Err(format!(
+        "No acceptable system {} was found at {} in {} tries.",
+        system_index, coord, max_tries
+    )
+    .into())
+}
 
-                is_interesting
+/// Returns true if the given system has at least one Terrestrial or Ocean world.
+fn is_interesting(system: &StarSystem) -> bool {
+    system.all_objects.iter().any(|o| {
+        if let AstronomicalObject::TelluricBody(body) = &o.object {
+            if let CelestialBodyDetails::Telluric(details) = &body.details {
+                details.world_type == CelestialBodyWorldType::Terrestrial
+                    || details.world_type == CelestialBodyWorldType::Ocean
             } else {
-                true
-            };
-            i += 1;
-            if i > 5000 {
-                panic!("There should be at least one interesting system in every 5000 tries!");
+                false
             }
+        } else {
+            false
         }
-        Self::new(name, center_id, main_star_id, all_objects, special_traits)
+    })
+}
+
+/// Generates the peculiarities of a system made of the given stars. They depend on the population of its main star, the region of the
+/// galaxy it lies in and the nebulae around it.
+fn generate_system_traits(
+    system_gen_try: u32,
+    system_index: u16,
+    coord: SpaceCoordinates,
+    stars: &[Star],
+    hex: &GalacticHex,
+    galaxy: &Galaxy,
+) -> Vec<SystemPeculiarity> {
+    let mut rng = SeededDiceRoller::new(
+        &format!("{}{}", system_gen_try, &galaxy.settings.seed),
+        &format!("sys_{}_{}_trt", coord, system_index),
+    );
+    let mut special_traits = generate_nebula_traits(coord, hex);
+    let population = stars
+        .iter()
+        .max_by(|a, b| {
+            a.mass
+                .partial_cmp(&b.mass)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map_or(StellarEvolution::Dwarf, |star| star.population);
+
+    let carbon_rich_chances = match population {
+        StellarEvolution::Paleodwarf => 0,
+        StellarEvolution::Subdwarf => 1,
+        StellarEvolution::Dwarf => 3,
+        StellarEvolution::Superdwarf => 6,
+        StellarEvolution::Hyperdwarf => 9,
+    };
+    if rng.roll(1, 100, 0) <= carbon_rich_chances {
+        special_traits.push(SystemPeculiarity::CarbonRich);
+    }
+
+    // Crowded regions see many more close encounters with other stars
+    let mut cataclysm_chances = match hex.region {
+        GalacticRegion::Nucleus | GalacticRegion::Core => 15,
+        GalacticRegion::Bulge | GalacticRegion::Bar | GalacticRegion::GlobularCluster => 10,
+        GalacticRegion::OpenCluster | GalacticRegion::Association => 8,
+        _ => 5,
+    };
+    cataclysm_chances += (stars.len() as i32 - 1).max(0) * 2;
+    if rng.roll(1, 100, 0) <= i64::from(cataclysm_chances) {
+        let roll = rng.roll(1, 20, 0);
+        special_traits.push(SystemPeculiarity::Cataclysm(if roll <= 10 {
+            CataclysmSeverity::Minor
+        } else if roll <= 16 {
+            CataclysmSeverity::Major
+        } else if roll <= 19 {
+            CataclysmSeverity::Extreme
+        } else {
+            CataclysmSeverity::Ultimate
+        }));
     }
+
+    let mut debris_modifier = match population {
+        StellarEvolution::Paleodwarf => -6,
+        StellarEvolution::Subdwarf => -3,
+        StellarEvolution::Dwarf => 0,
+        StellarEvolution::Superdwarf => 2,
+        StellarEvolution::Hyperdwarf => 4,
+    };
+    special_traits.iter().for_each(|t| match t {
+        SystemPeculiarity::Nebulae(NebulaeApparentSize::Tiny) => debris_modifier += 1,
+        SystemPeculiarity::Nebulae(NebulaeApparentSize::Small) => debris_modifier += 2,
+        SystemPeculiarity::Nebulae(NebulaeApparentSize::Large) => debris_modifier += 3,
+        SystemPeculiarity::Nebulae(NebulaeApparentSize::Dominant) => debris_modifier += 5,
+        SystemPeculiarity::Cataclysm(_) => debris_modifier += 3,
+        _ => (),
+    });
+    let roll = rng.roll(2, 10, debris_modifier);
+    if roll <= 3 {
+        special_traits.push(SystemPeculiarity::UnusualDebrisDensity(
+            DebrisDensity::MuchLower,
+        ));
+    } else if roll <= 5 {
+        special_traits.push(SystemPeculiarity::UnusualDebrisDensity(
+            DebrisDensity::Lower,
+        ));
+    } else if roll >= 20 {
+        special_traits.push(SystemPeculiarity::UnusualDebrisDensity(
+            DebrisDensity::MuchHigher,
+        ));
+    } else if roll >= 18 {
+        special_traits.push(SystemPeculiarity::UnusualDebrisDensity(
+            DebrisDensity::Higher,
+        ));
+    }
+
+    special_traits
+}
+
+/// Returns the peculiarities a system gets from the nebulae around it.
+fn generate_nebula_traits(coord: SpaceCoordinates, hex: &GalacticHex) -> Vec<SystemPeculiarity> {
+    let mut special_traits = Vec::new();
+    if let Some(apparent_size) = hex
+        .neighborhood
+        .nebulae
+        .iter()
+        .filter_map(|nebula| nebula.get_apparent_size_from(coord))
+        .max()
+    {
+        special_traits.push(SystemPeculiarity::Nebulae(apparent_size));
+    }
+    special_traits
+}
+
+/// Arranges the given stars into a system and fills it with orbits, zones and bodies. Returns the ids of the system's center and
+/// main star, along with all its objects.
+fn generate_system_contents(
+    system_gen_try: u32,
+    mut stars: Vec<Star>,
+    special_traits: &Vec<SystemPeculiarity>,
+    system_index: u16,
+    coord: SpaceCoordinates,
+    galaxy: &mut Galaxy,
+) -> (u32, u32, Vec<OrbitalPoint>) {
+    let center_id;
+    let main_star_id;
+    let mut all_objects: Vec<OrbitalPoint> = Vec::new();
+    if stars.len() > 1 {
+        let result = generate_binary_relations(
+            system_gen_try,
+            &mut stars,
+            &mut all_objects,
+            system_index,
+            coord,
+            galaxy,
+        );
+        center_id = result.0;
+        main_star_id = result.1;
+
+        let mut calculated_ids = HashSet::new();
+        for id in all_objects.iter().map(|op| op.id).collect::<Vec<u32>>() {
+            calculate_distance_from_system_center(id, &mut all_objects, &mut calculated_ids);
+        }
+    } else {
+        let center = OrbitalPoint::new(0, None, AstronomicalObject::Star(stars.remove(0)), vec![]);
+        center_id = 0;
+        main_star_id = 0;
+        all_objects.push(center);
+    }
+
+    update_existing_orbits(&mut all_objects);
+    generate_star_zones(&mut all_objects);
+    generate_stars_systems(
+        system_gen_try,
+        &mut all_objects,
+        special_traits,
+        system_index,
+        coord,
+        galaxy,
+    );
+    update_existing_orbits(&mut all_objects);
+    (center_id, main_star_id, all_objects)
 }
 
 /// Temporary name generation
@@ -133,31 +406,42 @@ fn get_system_name(system_index: u16, coord: SpaceCoordinates, galaxy: &Galaxy)
     }
 }
 
+/// Returns the number of stars of a system, which is never lower than the number of fixed stars in the [StarSettings]. Returns an error if
+/// the settings fix it to zero.
 fn generate_number_of_stars_in_system(
     system_gen_try: u32,
     system_index: u16,
     coord: SpaceCoordinates,
     galaxy: &mut Galaxy,
-) -> u16 {
+) -> Result<u16, Rc<str>> {
+    let min_number_of_stars = galaxy.settings.star.fixed_stars.len() as u16;
+    if let Some(number_of_stars) = galaxy.settings.star.fixed_number_of_stars {
+        if number_of_stars < 1 {
+            return Err("A system must have at least one star.".into());
+        }
+        return Ok(number_of_stars.max(min_number_of_stars));
+    }
     let mut rng = SeededDiceRoller::new(
         &*format!("{}{}", system_gen_try, &galaxy.settings.seed),
         &format!("sys_{}_{}_ste_evo", coord, system_index),
     );
-    rng.get_result(&CopyableRollToProcess::new(
-        vec![
-            CopyableWeightedResult::new(1, 400),
-            CopyableWeightedResult::new(2, 280),
-            CopyableWeightedResult::new(3, 120),
-            CopyableWeightedResult::new(4, 32),
-            CopyableWeightedResult::new(5, 20),
-            CopyableWeightedResult::new(6, 12),
-            CopyableWeightedResult::new(7, 4),
-            CopyableWeightedResult::new(8, 2),
-            CopyableWeightedResult::new(9, 1),
-        ],
-        RollMethod::SimpleRoll,
-    ))
-    .unwrap()
+    let generated = rng
+        .get_result(&CopyableRollToProcess::new(
+            vec![
+                CopyableWeightedResult::new(1, 400),
+                CopyableWeightedResult::new(2, 280),
+                CopyableWeightedResult::new(3, 120),
+                CopyableWeightedResult::new(4, 32),
+                CopyableWeightedResult::new(5, 20),
+                CopyableWeightedResult::new(6, 12),
+                CopyableWeightedResult::new(7, 4),
+                CopyableWeightedResult::new(8, 2),
+                CopyableWeightedResult::new(9, 1),
+            ],
+            RollMethod::SimpleRoll,
+        ))
+        .unwrap();
+    Ok(generated.max(min_number_of_stars))
 }
 
 fn generate_stars(
@@ -168,33 +452,94 @@ fn generate_stars(
     coord: SpaceCoordinates,
     hex: &GalacticHex,
     sub_sector: &GalacticMapDivision,
+    settings: &GenerationSettings,
     galaxy: &mut Galaxy,
-) -> Vec<Star> {
+) -> Result<Vec<Star>, Rc<str>> {
     let mut stars = Vec::new();
     for star_index in 0..number_of_stars {
-        let evolution = generate_stellar_evolution(
+        let fixed_star = settings.star.fixed_stars.get(star_index as usize).copied();
+        let mut evolution = fixed_star
+            .and_then(|fixed_star| fixed_star.population)
+            .unwrap_or_else(|| {
+                generate_stellar_evolution(
+                    system_gen_try,
+                    star_index,
+                    system_index,
+                    coord,
+                    hex,
+                    sub_sector,
+                    galaxy,
+                )
+            });
+        // Metal-poor stars are subdwarfs instead of main sequence dwarfs, so an unfixed population must agree with a fixed class.
+        let metal_poor =
+            evolution == StellarEvolution::Subdwarf || evolution == StellarEvolution::Paleodwarf;
+        match fixed_star
+            .filter(|fixed_star| fixed_star.population.is_none())
+            .and_then(|fixed_star| fixed_star.luminosity_class)
+        {
+            Some(StarLuminosityClass::VI) if !metal_poor => {
+                evolution = StellarEvolution::Subdwarf;
+            }
+            Some(StarLuminosityClass::V) if metal_poor => {
+                evolution = StellarEvolution::Dwarf;
+            }
+            _ => (),
+        }
+
+        stars.push(Star::generate(
             system_gen_try,
             star_index,
             system_index,
+            system_name.clone(),
             coord,
+            evolution,
             hex,
-            sub_sector,
             galaxy,
-        );
+            settings,
+        )?);
+    }
+    Ok(stars)
+}
 
-        stars.push(Star::generate(
+/// Generates again every companion heavier than the first of the given stars, with a mass as much lighter than the first star as it
+/// was heavier, so that the first star stays the main star of the system. Returns an error if a companion can't match its [FixedStar]
+/// anymore.
+fn lighten_companions(
+    system_gen_try: u32,
+    stars: &mut [Star],
+    system_index: u16,
+    system_name: Rc<str>,
+    coord: SpaceCoordinates,
+    hex: &GalacticHex,
+    settings: &GenerationSettings,
+    galaxy: &Galaxy,
+) -> Result<(), Rc<str>> {
+    let Some((main_star, companions)) = stars.split_first_mut() else {
+        return Ok(());
+    };
+    for (star_index, companion) in companions.iter_mut().enumerate() {
+        if companion.mass <= main_star.mass {
+            continue;
+        }
+        let mut settings = settings.clone();
+        settings.star.fixed_mass = Some(
+            (main_star.mass * main_star.mass / companion.mass)
+                .max(LIGHTEST_COMPANION_MASS.min(main_star.mass)),
+        );
+        *companion = Star::generate(
             system_gen_try,
-            star_index,
+            star_index as u16 + 1,
             system_index,
             system_name.clone(),
             coord,
-            evolution,
+            companion.population,
             hex,
             galaxy,
-            &galaxy.settings,
-        ));
+            &settings,
+        )?;
     }
-    stars
+    Ok(())
 }
 
 /// Generates the Population of stars in a system.
@@ -252,10 +597,12 @@ fn generate_stellar_evolution(
         _ => (),
     }
     galaxy.special_traits.iter().for_each(|t| match t {
-        GalaxySpecialTrait::MetalPoor => modifier -= 5,
-        GalaxySpecialTrait::Younger => modifier -= 2,
-        GalaxySpecialTrait::SubSize(_) => modifier -= 1,
-        GalaxySpecialTrait::Dusty | GalaxySpecialTrait::SuperSize(_) => modifier += 1,
+        GalaxySpecialTrait::MetalPoor => modifier -= 6,
+        GalaxySpecialTrait::Younger | GalaxySpecialTrait::Older => modifier -= 2,
+        GalaxySpecialTrait::SubSize(_) | GalaxySpecialTrait::Dead => modifier -= 1,
+        GalaxySpecialTrait::Dusty
+        | GalaxySpecialTrait::SuperSize(_)
+        | GalaxySpecialTrait::GasRich => modifier += 1,
         GalaxySpecialTrait::Starburst => modifier += 2,
         _ => (),
     });
@@ -539,19 +886,30 @@ fn find_center_of_binary_pair(
         generate_distance_between_stars(star_index, system_index, min_distance, 0, coord, galaxy);
     let barycentre_distance_from_most_massive =
         calculate_barycentre(actual_distance, most_massive_mass, less_massive_mass);
+    let less_massive_distance = actual_distance - barycentre_distance_from_most_massive;
+    let (eccentricity, inclination) = generate_star_orbit_eccentricity_and_inclination(
+        actual_distance,
+        min_distance,
+        next_id,
+        system_index,
+        coord,
+        galaxy,
+    );
+    let orbital_period =
+        calculate_orbital_period(actual_distance, most_massive_mass, less_massive_mass) as f32;
 
     let most_massive_orbit = Orbit::new(
         next_id,
         Some(most_massive_point.id),
         ZoneType::ForbiddenZone,
         barycentre_distance_from_most_massive,
-        0.0,
-        0.0,
+        barycentre_distance_from_most_massive * (1.0 - eccentricity as f64),
+        barycentre_distance_from_most_massive * (1.0 + eccentricity as f64),
         barycentre_distance_from_most_massive,
+        eccentricity,
+        inclination,
         0.0,
-        0.0,
-        0.0,
-        0.0,
+        orbital_period,
         0.0,
         f32::INFINITY,
     );
@@ -559,14 +917,14 @@ fn find_center_of_binary_pair(
         next_id,
         Some(less_massive_point.id),
         ZoneType::ForbiddenZone,
-        actual_distance - barycentre_distance_from_most_massive,
-        0.0,
-        0.0,
-        actual_distance - barycentre_distance_from_most_massive,
-        0.0,
-        0.0,
-        0.0,
+        less_massive_distance,
+        less_massive_distance * (1.0 - eccentricity as f64),
+        less_massive_distance * (1.0 + eccentricity as f64),
+        less_massive_distance,
+        eccentricity,
+        inclination,
         0.0,
+        orbital_period,
         0.0,
         f32::INFINITY,
     );
@@ -577,10 +935,11 @@ fn find_center_of_binary_pair(
     most_massive_point.set_own_orbit(most_massive_orbit);
     less_massive_point.set_own_orbit(less_massive_orbit);
 
-    let most_massive_distance_and_radius =
-        most_massive_radius as f64 + barycentre_distance_from_most_massive;
+    // The pair takes the most room at apastron, when both members are the farthest from their barycentre.
+    let most_massive_distance_and_radius = most_massive_radius as f64
+        + barycentre_distance_from_most_massive * (1.0 + eccentricity as f64);
     let less_massive_distance_and_radius =
-        less_massive_radius as f64 + actual_distance - barycentre_distance_from_most_massive;
+        less_massive_radius as f64 + less_massive_distance * (1.0 + eccentricity as f64);
     let radius = if most_massive_distance_and_radius > less_massive_distance_and_radius {
         most_massive_distance_and_radius
     } else {
@@ -749,7 +1108,512 @@ fn generate_distance_between_stars(
     generated
 }
 
+/// Generates the eccentricity and inclination (in degrees) of the orbits of two stars or centers of mass around their barycentre.
+/// Close pairs are circularized by their tides and stay near the system's plane, while wide ones can be very eccentric and inclined.
+/// The eccentricity never brings the pair closer than **min_distance** at periastron.
+fn generate_star_orbit_eccentricity_and_inclination(
+    distance_between: f64,
+    min_distance: f64,
+    next_id: u32,
+    system_index: u16,
+    coord: SpaceCoordinates,
+    galaxy: &Galaxy,
+) -> (f32, f32) {
+    let mut rng = SeededDiceRoller::new(
+        &galaxy.settings.seed,
+        &format!("sys_{}_{}_bin_{}_orb", coord, system_index, next_id),
+    );
+    let modifier = if distance_between < 0.5 {
+        -6
+    } else if distance_between < 6.0 {
+        -4
+    } else if distance_between < 72.0 {
+        -2
+    } else {
+        0
+    };
+    let roll = rng.roll(3, 6, modifier);
+    let eccentricity = if roll <= 3 {
+        0.0
+    } else if roll <= 4 {
+        0.1
+    } else if roll <= 5 {
+        0.2
+    } else if roll <= 6 {
+        0.3
+    } else if roll <= 8 {
+        0.4
+    } else if roll <= 11 {
+        0.5
+    } else if roll <= 13 {
+        0.6
+    } else if roll <= 15 {
+        0.7
+    } else if roll <= 16 {
+        0.8
+    } else {
+        0.95
+    };
+    let max_eccentricity = (1.0 - min_distance / distance_between).max(0.0);
+    let eccentricity = (eccentricity as f64).min(max_eccentricity) as f32;
+
+    let inclination = if distance_between < 0.5 {
+        rng.roll(1, 300, -1) as f32 / 100.0
+    } else if distance_between < 72.0 {
+        rng.roll(1, 3000, -1) as f32 / 100.0
+    } else {
+        rng.roll(1, 18000, -1) as f32 / 100.0
+    };
+
+    (eccentricity, inclination)
+}
+
 /// Finds where the barycentre between two stars or centers of mass is.
 fn calculate_barycentre(distance_between: f64, heaviest_mass: f64, lowest_mass: f64) -> f64 {
     distance_between * (lowest_mass as f64 / (heaviest_mass as f64 + lowest_mass as f64))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_system_peculiarities() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let mut found_carbon_rich = false;
+        let mut found_cataclysm = false;
+        let mut found_debris = false;
+        for x in 0..100 {
+            let hex = galaxy
+                .get_hex(SpaceCoordinates::new(x, 0, 0))
+                .expect("Should return a hex.");
+            for system in hex.contents.iter() {
+                for special_trait in system.special_traits.iter() {
+                    match special_trait {
+                        SystemPeculiarity::CarbonRich => {
+                            found_carbon_rich = true;
+                            for point in system.all_objects.iter() {
+                                if let AstronomicalObject::TelluricBody(CelestialBody {
+                                    details: CelestialBodyDetails::Telluric(details),
+                                    ..
+                                }) = &point.object
+                                {
+                                    assert!(details.special_traits.iter().any(|t| matches!(
+                                        t,
+                                        CelestialBodySpecialTrait::UnusualElementPresence((
+                                            ChemicalComponent::Carbon,
+                                            _
+                                        ))
+                                    )));
+                                }
+                            }
+                        }
+                        SystemPeculiarity::Cataclysm(_) => found_cataclysm = true,
+                        SystemPeculiarity::UnusualDebrisDensity(_) => found_debris = true,
+                        _ => (),
+                    }
+                }
+            }
+        }
+        assert!(found_carbon_rich);
+        assert!(found_cataclysm);
+        assert!(found_debris);
+    }
+
+    #[test]
+    fn generate_eccentric_and_inclined_star_orbits() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let mut found_eccentric = false;
+        let mut found_inclined = false;
+        let mut found_triple = false;
+        for x in 0..100 {
+            let hex = galaxy
+                .get_hex(SpaceCoordinates::new(x, 0, 0))
+                .expect("Should return a hex.");
+            for system in hex.contents.iter() {
+                let stars: Vec<&OrbitalPoint> = system
+                    .all_objects
+                    .iter()
+                    .filter(|point| matches!(point.object, AstronomicalObject::Star(_)))
+                    .collect();
+                if stars.len() == 3 {
+                    found_triple |= check_forbidden_zones_of_triple_star(system, &stars);
+                }
+                if stars.len() != 2 {
+                    continue;
+                }
+                let orbits: Vec<Orbit> = stars
+                    .iter()
+                    .map(|point| point.own_orbit.clone().expect("Should have an orbit."))
+                    .collect();
+                assert_eq!(orbits[0].primary_body_id, orbits[1].primary_body_id);
+                assert_eq!(orbits[0].eccentricity, orbits[1].eccentricity);
+                assert_eq!(orbits[0].inclination, orbits[1].inclination);
+                assert_eq!(orbits[0].orbital_period, orbits[1].orbital_period);
+                assert!(orbits[0].orbital_period > 0.0);
+                assert!(orbits[0].eccentricity >= 0.0 && orbits[0].eccentricity < 1.0);
+                for orbit in orbits.iter() {
+                    assert!(orbit.min_separation <= orbit.average_distance);
+                    assert!(orbit.max_separation >= orbit.average_distance);
+                }
+                found_eccentric |= orbits[0].eccentricity > 0.0;
+                found_inclined |= orbits[0].inclination > 30.0;
+
+                let periastron = orbits[0].min_separation + orbits[1].min_separation;
+                for point in stars.iter() {
+                    if let AstronomicalObject::Star(star) = &point.object {
+                        let forbidden_zone = star
+                            .zones
+                            .iter()
+                            .find(|zone| zone.zone_type == ZoneType::ForbiddenZone)
+                            .expect("Should have a forbidden zone.");
+                        assert!((forbidden_zone.start - periastron / 3.0).abs() < 0.0001);
+                    }
+                }
+            }
+        }
+        assert!(found_eccentric);
+        assert!(found_inclined);
+        assert!(found_triple);
+    }
+
+    /// Checks that the forbidden zone of the lone star of a triple system, whose closest companion is either the barycentre of the
+    /// other pair or one of its stars, is bounded by the separation between both barycentres. Returns false if the system isn't made
+    /// of a pair orbiting a lone star.
+    fn check_forbidden_zones_of_triple_star(system: &StarSystem, stars: &[&OrbitalPoint]) -> bool {
+        let orbits: Vec<&Orbit> = stars
+            .iter()
+            .map(|point| point.own_orbit.as_ref().expect("Should have an orbit."))
+            .collect();
+        let Some(lone) = (0..3).find(|&i| {
+            (0..3)
+                .filter(|&j| j != i)
+                .all(|j| orbits[j].primary_body_id != orbits[i].primary_body_id)
+        }) else {
+            return false;
+        };
+        let pair: Vec<&Orbit> = (0..3).filter(|&j| j != lone).map(|j| orbits[j]).collect();
+        if pair[0].primary_body_id != pair[1].primary_body_id {
+            return false;
+        }
+        let pair_orbit = system
+            .all_objects
+            .iter()
+            .find(|point| point.id == pair[0].primary_body_id)
+            .and_then(|point| point.own_orbit.as_ref())
+            .expect("The pair's barycentre should have an orbit.");
+        assert_eq!(pair_orbit.primary_body_id, orbits[lone].primary_body_id);
+
+        let outer_periastron = orbits[lone].min_separation + pair_orbit.min_separation;
+        let outer_apastron = orbits[lone].max_separation + pair_orbit.max_separation;
+        let inner_apastron = pair[0].max_separation.max(pair[1].max_separation);
+        if let AstronomicalObject::Star(star) = &stars[lone].object {
+            let forbidden_zone = star
+                .zones
+                .iter()
+                .find(|zone| zone.zone_type == ZoneType::ForbiddenZone)
+                .expect("Should have a forbidden zone.");
+            assert!(forbidden_zone.start <= outer_periastron / 3.0 + 0.0001);
+            assert!(
+                forbidden_zone.start >= (outer_periastron - inner_apastron).max(0.0) / 3.0 - 0.0001
+            );
+            assert!(forbidden_zone.end >= outer_apastron * 3.0 - 0.0001);
+            assert!(forbidden_zone.end <= (outer_apastron + inner_apastron) * 3.0 + 0.0001);
+        }
+        true
+    }
+
+    #[test]
+    fn surround_stars_with_a_circumstellar_disk_with_a_protoplanetary_disk() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let mut found_disk = false;
+        for x in 0..100 {
+            let hex = galaxy
+                .get_hex(SpaceCoordinates::new(x, 0, 0))
+                .expect("Should return a hex.");
+            for system in hex.contents.iter() {
+                for point in system.all_objects.iter() {
+                    if let AstronomicalObject::TelluricDisk(CelestialDisk {
+                        details: CelestialDiskType::ProtoplanetaryDisk,
+                        ..
+                    }) = &point.object
+                    {
+                        found_disk = true;
+                        let orbit = point.own_orbit.clone().expect("Should have an orbit.");
+                        let star = system
+                            .get_point(orbit.primary_body_id)
+                            .expect("Should orbit a star.");
+                        if let AstronomicalObject::Star(star) = &star.object {
+                            assert!(star
+                                .special_traits
+                                .contains(&StarPeculiarity::CircumstellarDisk));
+                        } else {
+                            panic!("The disk should orbit a star.");
+                        }
+                        assert!(orbit.min_separation < orbit.max_separation);
+                    }
+                }
+            }
+        }
+        assert!(found_disk);
+    }
+
+    #[test]
+    fn generate_configured_stars() {
+        let companion = FixedStar {
+            spectral_type: Some(StarSpectralType::M(5)),
+            luminosity_class: Some(StarLuminosityClass::V),
+            ..Default::default()
+        };
+        let giant = FixedStar {
+            spectral_type: Some(StarSpectralType::K(0)),
+            random_subtype: true,
+            luminosity_class: Some(StarLuminosityClass::III),
+            ..Default::default()
+        };
+        for (number_of_stars, fixed_stars) in [
+            (
+                Some(2),
+                vec![
+                    FixedStar {
+                        spectral_type: Some(StarSpectralType::G(2)),
+                        luminosity_class: Some(StarLuminosityClass::V),
+                        population: Some(StellarEvolution::Dwarf),
+                        ..Default::default()
+                    },
+                    companion,
+                ],
+            ),
+            (None, vec![giant]),
+            (Some(1), vec![companion, companion]),
+        ] {
+            let settings = &GenerationSettings {
+                seed: "default".into(),
+                star: StarSettings {
+                    fixed_number_of_stars: number_of_stars,
+                    fixed_stars: fixed_stars.clone(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+            let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+            let mut found = 0;
+            for x in 0..10 {
+                let hex = galaxy
+                    .get_hex(SpaceCoordinates::new(x, 0, 0))
+                    .expect("Should return a hex.");
+                for system in hex.contents.iter() {
+                    let stars: Vec<&Star> = system
+                        .all_objects
+                        .iter()
+                        .filter_map(|point| match &point.object {
+                            AstronomicalObject::Star(star) => Some(star),
+                            _ => None,
+                        })
+                        .collect();
+                    if let Some(number_of_stars) = number_of_stars {
+                        assert_eq!(
+                            stars.len(),
+                            (number_of_stars as usize).max(fixed_stars.len())
+                        );
+                    }
+                    for fixed_star in fixed_stars.iter() {
+                        let wanted = fixed_star.spectral_type.expect("Should have a type.");
+                        assert!(stars.iter().any(|star| {
+                            Some(star.luminosity_class) == fixed_star.luminosity_class
+                                && if fixed_star.random_subtype {
+                                    discriminant(&star.spectral_type) == discriminant(&wanted)
+                                } else {
+                                    star.spectral_type == wanted
+                                }
+                                && fixed_star
+                                    .population
+                                    .map_or(true, |population| star.population == population)
+                        }));
+                    }
+                    found += 1;
+                }
+            }
+            assert!(found > 0);
+        }
+    }
+
+    #[test]
+    fn refuse_to_generate_systems_without_stars() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            star: StarSettings {
+                fixed_number_of_stars: Some(0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        assert!((0..10).any(|x| galaxy.get_hex(SpaceCoordinates::new(x, 0, 0)).is_err()));
+    }
+
+    #[test]
+    fn refuse_to_generate_fixed_stars_that_cannot_exist() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            star: StarSettings {
+                fixed_age: Some(0.01),
+                fixed_stars: vec![FixedStar {
+                    spectral_type: Some(StarSpectralType::K(0)),
+                    luminosity_class: Some(StarLuminosityClass::III),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            system: SystemSettings {
+                max_tries: 10,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        assert!((0..10).any(|x| galaxy.get_hex(SpaceCoordinates::new(x, 0, 0)).is_err()));
+    }
+
+    #[test]
+    fn generate_systems_matching_filters_and_predicates() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            system: SystemSettings {
+                filter: Some(SystemTemplate {
+                    min_gas_giants: Some(2),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let coord = SpaceCoordinates::new(0, 0, 0);
+        let sub_sector = galaxy
+            .get_division_at_level(coord, 1)
+            .expect("Should return a subsector.");
+        let hex = galaxy.get_hex(coord).expect("Should return a hex.");
+        assert!(!hex.contents.is_empty());
+        for system in hex.contents.iter() {
+            assert!(system
+                .all_objects
+                .iter()
+                .filter(|point| matches!(point.object, AstronomicalObject::GaseousBody(_)))
+                .nth(1)
+                .is_some());
+        }
+
+        let has_many_stars = |system: &StarSystem| {
+            system
+                .all_objects
+                .iter()
+                .filter(|point| matches!(point.object, AstronomicalObject::Star(_)))
+                .count()
+                > 1
+        };
+        let system =
+            StarSystem::generate_matching(0, coord, has_many_stars, &hex, &sub_sector, &mut galaxy)
+                .expect("Should find a system.");
+        assert!(has_many_stars(&system));
+
+        galaxy.settings.system.max_tries = 10;
+        assert!(
+            StarSystem::generate_matching(0, coord, |_| false, &hex, &sub_sector, &mut galaxy)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn complete_rare_templates_without_rerolling_whole_systems() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            system: SystemSettings {
+                max_tries: 10,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let coord = SpaceCoordinates::new(0, 0, 0);
+        let sub_sector = galaxy
+            .get_division_at_level(coord, 1)
+            .expect("Should return a subsector.");
+        let hex = galaxy.get_hex(coord).expect("Should return a hex.");
+        for template in [
+            SystemTemplate {
+                main_star_spectral_type: Some(StarSpectralType::K(0)),
+                main_star_luminosity_class: Some(StarLuminosityClass::V),
+                bodies: vec![BodyTemplate {
+                    world_type: Some(CelestialBodyWorldType::Ocean),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            SystemTemplate {
+                number_of_stars: Some(1),
+                main_star_spectral_type: Some(StarSpectralType::B(0)),
+                bodies: vec![
+                    BodyTemplate {
+                        size: Some(CelestialBodySize::Large),
+                        min_distance: Some(20.0),
+                        max_distance: Some(40.0),
+                        ..Default::default()
+                    },
+                    BodyTemplate {
+                        world_type: Some(CelestialBodyWorldType::VolatilesGiant),
+                        min_distance: Some(100.0),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        ] {
+            for system_index in 0..5 {
+                let system = StarSystem::generate_from_template(
+                    system_index,
+                    coord,
+                    &template,
+                    &hex,
+                    &sub_sector,
+                    &mut galaxy,
+                )
+                .expect("Should complete the template.");
+                assert!(template.matches(&system));
+            }
+        }
+        assert!(StarSystem::generate_from_template(
+            0,
+            coord,
+            &SystemTemplate {
+                number_of_stars: Some(0),
+                ..Default::default()
+            },
+            &hex,
+            &sub_sector,
+            &mut galaxy,
+        )
+        .is_err());
+    }
+}
@@ -7,7 +7,9 @@ mod display;
 pub mod generator;
 pub mod neighborhood;
 pub mod orbital_point;
+mod sol;
 pub mod star;
+pub mod template;
 pub mod types;
 
 #[derive(Clone, PartialEq, PartialOrd, Debug, SmartDefault, Serialize, Deserialize)]
@@ -77,4 +79,43 @@ impl StarSystem {
     pub fn get_point_mut(&mut self, id: u32) -> Option<&mut OrbitalPoint> {
         self.all_objects.iter_mut().find(|p| p.id == id)
     }
+
+    /// Returns the main world of the system, the telluric body most suitable for life, or the largest one in case of a tie. Returns
+    /// [None] if the system has no telluric body.
+    pub fn get_main_world(&self) -> Option<&CelestialBody> {
+        self.all_objects
+            .iter()
+            .filter_map(|p| match &p.object {
+                AstronomicalObject::TelluricBody(body) => match &body.details {
+                    CelestialBodyDetails::Telluric(details) => Some((body, details.world_type)),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .max_by(|(a, a_type), (b, b_type)| {
+                get_habitability_rank(*a_type)
+                    .cmp(&get_habitability_rank(*b_type))
+                    .then(
+                        a.radius
+                            .partial_cmp(&b.radius)
+                            .unwrap_or(std::cmp::Ordering::Equal),
+                    )
+            })
+            .map(|(body, _)| body)
+    }
+}
+
+/// Returns how suitable for life a world of the given type is, the higher the better.
+fn get_habitability_rank(world_type: CelestialBodyWorldType) -> u8 {
+    match world_type {
+        CelestialBodyWorldType::Terrestrial => 6,
+        CelestialBodyWorldType::Ocean => 5,
+        CelestialBodyWorldType::Greenhouse | CelestialBodyWorldType::Ammonia => 4,
+        CelestialBodyWorldType::GeoActive => 3,
+        CelestialBodyWorldType::Ice | CelestialBodyWorldType::DirtySnowball => 2,
+        CelestialBodyWorldType::Hadean | CelestialBodyWorldType::Rock => 1,
+        CelestialBodyWorldType::ProtoWorld
+        | CelestialBodyWorldType::Chthonian
+        | CelestialBodyWorldType::VolatilesGiant => 0,
+    }
 }
@@ -4,14 +4,31 @@ use crate::prelude::*;
 impl StellarNeighborhood {
     /// Generates a [StellarNeighborhood] using the given parameters.
     pub fn generate(coord: SpaceCoordinates, galaxy: &mut Galaxy) -> Self {
+        let nebulae: Vec<GalacticNebula> = galaxy
+            .get_nebulae_near(coord)
+            .expect("Should have returned nebulae.")
+            .iter()
+            .map(|(nebula, _)| *nebula)
+            .collect();
+        let age = generate_age(coord, &nebulae, galaxy);
         Self {
-            age: generate_age(coord, galaxy),
+            age,
+            metallicity: generate_metallicity(coord, age, galaxy),
+            density: generate_density(coord, galaxy),
+            radiation: generate_radiation(coord, age, &nebulae, galaxy),
+            nebulae,
+            cluster: get_cluster(coord, galaxy),
         }
     }
 }
 
-/// Uses data about the [GalacticMapDivision]s a neighborhood belongs to to generate its age.
-fn generate_age(coord: SpaceCoordinates, galaxy: &mut Galaxy) -> StellarNeighborhoodAge {
+/// Uses data about the [GalacticMapDivision]s a neighborhood belongs to, the nebulae it lies in and the special traits of its [Galaxy]
+/// to generate its age.
+fn generate_age(
+    coord: SpaceCoordinates,
+    nebulae: &[GalacticNebula],
+    galaxy: &mut Galaxy,
+) -> StellarNeighborhoodAge {
     let divisions = galaxy
         .get_divisions_for_coord(coord)
         .expect("Should have returned divisions.");
@@ -40,6 +57,27 @@ fn generate_age(coord: SpaceCoordinates, galaxy: &mut Galaxy) -> StellarNeighbor
         GalacticRegion::OpenCluster => modifier -= 5,
         _ => (),
     });
+    galaxy
+        .special_traits
+        .iter()
+        .for_each(|special_trait| match special_trait {
+            GalaxySpecialTrait::Dead => modifier += 6,
+            GalaxySpecialTrait::Dormant => modifier += 4,
+            GalaxySpecialTrait::GasPoor | GalaxySpecialTrait::Older => modifier += 2,
+            GalaxySpecialTrait::Interacting => modifier -= 1,
+            GalaxySpecialTrait::GasRich | GalaxySpecialTrait::Younger => modifier -= 2,
+            GalaxySpecialTrait::Starburst => modifier -= 4,
+            _ => (),
+        });
+    nebulae
+        .iter()
+        .filter(|nebula| nebula.contains(coord))
+        .for_each(|nebula| match nebula.category {
+            NebulaCategory::Emission => modifier -= 4,
+            NebulaCategory::Reflection | NebulaCategory::Dark => modifier -= 2,
+            NebulaCategory::SupernovaRemnant => modifier -= 1,
+            NebulaCategory::Planetary => (),
+        });
 
     let mut rng = SeededDiceRoller::new(
         &galaxy.settings.seed,
@@ -106,7 +144,9 @@ fn generate_age(coord: SpaceCoordinates, galaxy: &mut Galaxy) -> StellarNeighbor
         }
         StellarNeighborhoodAge::Ancient(_) => {
             age = StellarNeighborhoodAge::Ancient(
-                (universe_age - 200).min(universe_age - rng.roll(1, 10, -1) as u64 * 1000),
+                (universe_age - 200)
+                    .min(universe_age.saturating_sub(rng.roll(1, 10, -1) as u64 * 1000))
+                    .max(1),
             );
         }
     }
@@ -114,6 +154,212 @@ fn generate_age(coord: SpaceCoordinates, galaxy: &mut Galaxy) -> StellarNeighbor
     age
 }
 
+/// Computes the average metallicity of a neighborhood using its distance to the galactic center, the regions it belongs to, its age
+/// and the galaxy's own properties.
+fn generate_metallicity(
+    coord: SpaceCoordinates,
+    age: StellarNeighborhoodAge,
+    galaxy: &mut Galaxy,
+) -> f32 {
+    // Bigger galaxies retain more of the metals produced by their stars
+    let mut metallicity: f32 = if galaxy.is_dominant {
+        0.4
+    } else if galaxy.is_major {
+        0.2
+    } else {
+        -0.6
+    };
+    match galaxy.category {
+        GalaxyCategory::Intergalactic(_, _, _) => metallicity -= 1.5,
+        GalaxyCategory::Intracluster(_, _, _) => metallicity -= 0.5,
+        _ => (),
+    }
+    match galaxy.sub_category {
+        GalaxySubCategory::DwarfAmorphous
+        | GalaxySubCategory::DwarfSpiral
+        | GalaxySubCategory::DwarfElliptical
+        | GalaxySubCategory::DwarfLenticular => metallicity -= 0.4,
+        _ => (),
+    }
+    // Galaxies that had less time to form stars are less enriched
+    metallicity += 0.5 * (galaxy.age.max(0.1) / 13.0).log10();
+    galaxy.special_traits.iter().for_each(|t| match t {
+        GalaxySpecialTrait::MetalPoor => metallicity -= 0.7,
+        GalaxySpecialTrait::Dusty => metallicity += 0.2,
+        _ => (),
+    });
+
+    // Metallicity decreases the farther we get from the galactic center
+    let gal_end = galaxy.get_galactic_end();
+    let galactic_radius = gal_end.x.max(gal_end.y).max(gal_end.z).max(1) as f32;
+    let distance_to_center =
+        ((coord.x.pow(2) + coord.y.pow(2) + coord.z.pow(2)) as f32).sqrt() / galactic_radius;
+    metallicity -= distance_to_center.min(1.5) * 0.8;
+
+    let divisions = galaxy
+        .get_divisions_for_coord(coord)
+        .expect("Should have returned divisions.");
+    let sub_sector_index = divisions
+        .iter()
+        .find(|div| div.level == 1)
+        .expect("Should have found a subsector.")
+        .index;
+    let mut regions: Vec<GalacticRegion> = Vec::new();
+    divisions.iter().for_each(|div| {
+        if !regions.contains(&div.region) {
+            regions.push(div.region);
+        }
+    });
+    // Only the most extreme region in each direction is taken into account, as regions are nested
+    let region_modifiers: Vec<f32> = regions
+        .iter()
+        .map(|region| match region {
+            GalacticRegion::Void => -1.5,
+            GalacticRegion::GlobularCluster | GalacticRegion::Halo | GalacticRegion::Aura => -1.2,
+            GalacticRegion::Stream => -0.8,
+            GalacticRegion::Exile => -0.5,
+            GalacticRegion::Nucleus | GalacticRegion::Core => 0.2,
+            GalacticRegion::Bulge | GalacticRegion::Bar => 0.1,
+            GalacticRegion::Arm | GalacticRegion::OpenCluster | GalacticRegion::Association => 0.05,
+            _ => 0.0,
+        })
+        .collect();
+    metallicity += region_modifiers.iter().fold(0.0_f32, |a, b| a.min(*b))
+        + region_modifiers.iter().fold(0.0_f32, |a, b| a.max(*b));
+
+    // Older generations of stars were born in a less enriched medium
+    metallicity += match age {
+        StellarNeighborhoodAge::Young(_) => 0.1,
+        StellarNeighborhoodAge::Mature => 0.0,
+        StellarNeighborhoodAge::Old(_) => -0.3,
+        StellarNeighborhoodAge::Ancient(_) => -0.8,
+    };
+
+    let mut rng = SeededDiceRoller::new(
+        &galaxy.settings.seed,
+        &format!("ste_nei_{}_met", sub_sector_index),
+    );
+    metallicity += rng.gen_range(-0.15..0.15) as f32;
+
+    (metallicity * 100.0).round().clamp(-500.0, 100.0) / 100.0
+}
+
+/// Uses the region of the smallest division a neighborhood belongs to and the galaxy's special traits to find out how densely packed
+/// its stars are.
+fn generate_density(coord: SpaceCoordinates, galaxy: &mut Galaxy) -> StellarNeighborhoodDensity {
+    let region = galaxy
+        .get_divisions_for_coord(coord)
+        .expect("Should have returned divisions.")
+        .iter()
+        .find(|div| div.level == 0)
+        .expect("Should have found a division.")
+        .region;
+    let mut density: i8 = match region {
+        GalacticRegion::Void => 0,
+        GalacticRegion::Aura => 1,
+        GalacticRegion::Halo | GalacticRegion::Exile | GalacticRegion::Stream => 2,
+        GalacticRegion::Association
+        | GalacticRegion::Ellipse
+        | GalacticRegion::Disk
+        | GalacticRegion::Multiple => 3,
+        GalacticRegion::Arm | GalacticRegion::OpenCluster => 4,
+        GalacticRegion::Bar | GalacticRegion::Bulge | GalacticRegion::GlobularCluster => 5,
+        GalacticRegion::Core | GalacticRegion::Nucleus => 6,
+    };
+    galaxy.special_traits.iter().for_each(|t| match t {
+        GalaxySpecialTrait::Compact(_) => density += 1,
+        GalaxySpecialTrait::Expansive(_) => density -= 1,
+        _ => (),
+    });
+
+    match density {
+        i8::MIN..=0 => StellarNeighborhoodDensity::Empty,
+        1 => StellarNeighborhoodDensity::VerySparse,
+        2 => StellarNeighborhoodDensity::Sparse,
+        3 => StellarNeighborhoodDensity::Standard,
+        4 => StellarNeighborhoodDensity::Dense,
+        5 => StellarNeighborhoodDensity::VeryDense,
+        _ => StellarNeighborhoodDensity::Crowded,
+    }
+}
+
+/// Uses the regions a neighborhood belongs to, its age, its nebulae and the galaxy's special traits to find out how much radiation
+/// can be found there.
+fn generate_radiation(
+    coord: SpaceCoordinates,
+    age: StellarNeighborhoodAge,
+    nebulae: &[GalacticNebula],
+    galaxy: &mut Galaxy,
+) -> StellarNeighborhoodRadiation {
+    let divisions = galaxy
+        .get_divisions_for_coord(coord)
+        .expect("Should have returned divisions.");
+    let is_central = divisions
+        .iter()
+        .any(|div| div.region == GalacticRegion::Core || div.region == GalacticRegion::Nucleus);
+    let mut radiation = 0;
+    if is_central {
+        radiation += 2;
+    } else if divisions.iter().any(|div| {
+        div.region == GalacticRegion::Bulge
+            || div.region == GalacticRegion::Bar
+            || div.region == GalacticRegion::GlobularCluster
+    }) {
+        radiation += 1;
+    } else if divisions.iter().all(|div| {
+        div.region == GalacticRegion::Void
+            || div.region == GalacticRegion::Aura
+            || div.region == GalacticRegion::Halo
+            || div.region == GalacticRegion::Exile
+    }) {
+        radiation -= 1;
+    }
+
+    // Massive young stars are still shining in young clusters and associations
+    if let StellarNeighborhoodAge::Young(_) = age {
+        if divisions.iter().any(|div| {
+            div.region == GalacticRegion::OpenCluster || div.region == GalacticRegion::Association
+        }) {
+            radiation += 1;
+        }
+    }
+    if let Some(apparent_size) = nebulae
+        .iter()
+        .filter(|nebula| nebula.category == NebulaCategory::SupernovaRemnant)
+        .filter_map(|nebula| nebula.get_apparent_size_from(coord))
+        .max()
+    {
+        radiation += match apparent_size {
+            NebulaeApparentSize::Dominant => 3,
+            NebulaeApparentSize::Large => 2,
+            NebulaeApparentSize::Small => 1,
+            NebulaeApparentSize::Tiny => 0,
+        };
+    }
+    galaxy.special_traits.iter().for_each(|t| match t {
+        GalaxySpecialTrait::ActiveNucleus if is_central => radiation += 2,
+        GalaxySpecialTrait::Starburst => radiation += 1,
+        _ => (),
+    });
+
+    match radiation {
+        i32::MIN..=-1 => StellarNeighborhoodRadiation::Low,
+        0 => StellarNeighborhoodRadiation::Normal,
+        1 => StellarNeighborhoodRadiation::High,
+        2 | 3 => StellarNeighborhoodRadiation::VeryHigh,
+        _ => StellarNeighborhoodRadiation::Lethal,
+    }
+}
+
+/// Returns the star cluster, association or stellar stream the given coordinates belong to, if any.
+fn get_cluster(coord: SpaceCoordinates, galaxy: &Galaxy) -> Option<GalacticSubRegion> {
+    galaxy
+        .sub_regions
+        .iter()
+        .find(|sub_region| sub_region.region != GalacticRegion::Exile && sub_region.contains(coord))
+        .copied()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,4 +399,109 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn generate_younger_neighborhoods_in_starburst_galaxies() {
+        let mut rng = SeededDiceRoller::new("seed", "step");
+        let mut starburst_young = 0;
+        let mut dead_young = 0;
+        let mut dead_old = 0;
+        for i in 0..300 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+            let mut starburst = Galaxy::generate(neighborhood, (i as u16) % 5, &settings);
+            let mut dead = starburst.clone();
+            starburst.special_traits = vec![GalaxySpecialTrait::Starburst];
+            dead.special_traits = vec![GalaxySpecialTrait::Dead];
+            let gal_end = starburst.get_galactic_end();
+            let coord = SpaceCoordinates::new(
+                rng.gen_u32() as i64 % gal_end.x,
+                rng.gen_u32() as i64 % gal_end.y,
+                rng.gen_u32() as i64 % gal_end.z,
+            )
+            .rel(starburst.get_galactic_start());
+            let starburst_hex = starburst
+                .get_hex(coord)
+                .expect("Should have generated a hex.");
+            let dead_hex = dead.get_hex(coord).expect("Should have generated a hex.");
+            if let StellarNeighborhoodAge::Young(_) = starburst_hex.neighborhood.age {
+                starburst_young += 1;
+            }
+            match dead_hex.neighborhood.age {
+                StellarNeighborhoodAge::Young(_) => dead_young += 1,
+                StellarNeighborhoodAge::Old(_) | StellarNeighborhoodAge::Ancient(_) => {
+                    dead_old += 1
+                }
+                StellarNeighborhoodAge::Mature => (),
+            }
+        }
+        assert!(starburst_young > dead_young);
+        assert!(dead_old > 150);
+    }
+
+    #[test]
+    fn generate_metal_poorer_neighborhoods_far_from_the_center() {
+        let mut center_metallicity = 0.0;
+        let mut edge_metallicity = 0.0;
+        for i in 0..50 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+            let mut galaxy = Galaxy::generate(neighborhood, (i as u16) % 5, &settings);
+            let gal_end = galaxy.get_galactic_end();
+            let center = galaxy
+                .get_hex(SpaceCoordinates::new(0, 0, 0))
+                .expect("Should have generated a hex.");
+            let edge = galaxy
+                .get_hex(SpaceCoordinates::new(gal_end.x - 1, 0, 0))
+                .expect("Should have generated a hex.");
+            assert!(
+                center.neighborhood.metallicity >= -5.0 && center.neighborhood.metallicity <= 1.0
+            );
+            assert!(edge.neighborhood.metallicity >= -5.0 && edge.neighborhood.metallicity <= 1.0);
+            center_metallicity += center.neighborhood.metallicity;
+            edge_metallicity += edge.neighborhood.metallicity;
+        }
+        assert!(center_metallicity > edge_metallicity);
+    }
+
+    #[test]
+    fn generate_neighborhoods_matching_their_regions() {
+        for i in 0..50 {
+            let settings = &GenerationSettings {
+                seed: Rc::from(i.to_string()),
+                ..Default::default()
+            };
+            let neighborhood =
+                GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+            let mut galaxy = Galaxy::generate(neighborhood, (i as u16) % 5, &settings);
+            let gal_end = galaxy.get_galactic_end();
+            let center = galaxy
+                .get_hex(SpaceCoordinates::new(0, 0, 0))
+                .expect("Should have generated a hex.");
+            let edge = galaxy
+                .get_hex(SpaceCoordinates::new(gal_end.x - 1, gal_end.y - 1, 0))
+                .expect("Should have generated a hex.");
+            assert!(center.neighborhood.density >= edge.neighborhood.density);
+
+            if let Some(sub_region) = galaxy
+                .sub_regions
+                .iter()
+                .find(|sub_region| sub_region.region != GalacticRegion::Exile)
+                .copied()
+            {
+                let hex = galaxy
+                    .get_hex(sub_region.start)
+                    .expect("Should have generated a hex.");
+                assert!(hex.neighborhood.cluster.is_some());
+            }
+        }
+    }
 }
@@ -4,23 +4,70 @@ pub mod generator;
 pub mod types;
 
 /// A more or less coherent neighborhood of stars.
-#[derive(
-    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize, Deserialize,
-)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
 pub struct StellarNeighborhood {
     /// The age of this neighborhood.
     pub age: StellarNeighborhoodAge,
+    /// The average metallicity of this neighborhood's stars, expressed as [Fe/H] in dex. 0 is the Sun's metallicity, -1 means
+    /// ten times less metals than the Sun and +0.5 about three times more.
+    pub metallicity: f32,
+    /// How densely packed the stars of this neighborhood are.
+    pub density: StellarNeighborhoodDensity,
+    /// The level of ambient radiation in this neighborhood.
+    pub radiation: StellarNeighborhoodRadiation,
+    /// The nebulae that can be seen from this neighborhood, from the biggest to the smallest in its sky.
+    pub nebulae: Vec<GalacticNebula>,
+    /// The star cluster, association or stellar stream this neighborhood belongs to, if any.
+    pub cluster: Option<GalacticSubRegion>,
 }
 
 impl StellarNeighborhood {
     /// Creates a new instance of [StellarNeighborhood].
-    pub fn new(age: StellarNeighborhoodAge) -> Self {
-        Self { age }
+    pub fn new(
+        age: StellarNeighborhoodAge,
+        metallicity: f32,
+        density: StellarNeighborhoodDensity,
+        radiation: StellarNeighborhoodRadiation,
+        nebulae: Vec<GalacticNebula>,
+        cluster: Option<GalacticSubRegion>,
+    ) -> Self {
+        Self {
+            age,
+            metallicity,
+            density,
+            radiation,
+            nebulae,
+            cluster,
+        }
     }
 }
 
 impl Display for StellarNeighborhood {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} stellar neighborhood", self.age,)
+        write!(
+            f,
+            "{} stellar neighborhood with a metallicity of {:.2} [Fe/H], a {} density and {} radiation{}{}",
+            self.age,
+            self.metallicity,
+            self.density,
+            self.radiation,
+            if self.nebulae.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    ", near {}",
+                    self.nebulae
+                        .iter()
+                        .map(|n| format!("{}", n.category))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            },
+            if let Some(cluster) = self.cluster {
+                format!(", part of a {}", cluster.region)
+            } else {
+                String::new()
+            }
+        )
     }
 }
@@ -37,3 +37,70 @@ impl Display for StellarNeighborhoodAge {
         }
     }
 }
+
+/// How densely packed the stars of a [StellarNeighborhood] are.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, SmartDefault, Serialize, Deserialize,
+)]
+pub enum StellarNeighborhoodDensity {
+    /// Next to no stars at all, as found in the void between galaxies.
+    Empty,
+    /// Stars are a rare sight, like in the auras of irregular galaxies.
+    VerySparse,
+    /// Stars are few and far between, like in galactic halos.
+    Sparse,
+    /// The usual density of a galactic disk.
+    #[default]
+    Standard,
+    /// Stars are more numerous than usual, like in spiral arms and open clusters.
+    Dense,
+    /// Stars are packed closely together, like in bars, bulges and globular clusters.
+    VeryDense,
+    /// Stars are so close to each other that their light drowns the night sky, like in galactic cores.
+    Crowded,
+}
+
+impl Display for StellarNeighborhoodDensity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StellarNeighborhoodDensity::Empty => write!(f, "Empty"),
+            StellarNeighborhoodDensity::VerySparse => write!(f, "Very Sparse"),
+            StellarNeighborhoodDensity::Sparse => write!(f, "Sparse"),
+            StellarNeighborhoodDensity::Standard => write!(f, "Standard"),
+            StellarNeighborhoodDensity::Dense => write!(f, "Dense"),
+            StellarNeighborhoodDensity::VeryDense => write!(f, "Very Dense"),
+            StellarNeighborhoodDensity::Crowded => write!(f, "Crowded"),
+        }
+    }
+}
+
+/// The level of ambient radiation in a [StellarNeighborhood], coming from supernova remnants, massive young stars or an active
+/// galactic nucleus.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, SmartDefault, Serialize, Deserialize,
+)]
+pub enum StellarNeighborhoodRadiation {
+    /// Less radiation than usual, far from any source.
+    Low,
+    /// The usual background radiation of a galactic disk.
+    #[default]
+    Normal,
+    /// Noticeably more radiation than usual, which can be a nuisance for unshielded ships and exposed worlds.
+    High,
+    /// Dangerous radiation levels, requiring proper shielding.
+    VeryHigh,
+    /// Radiation is so intense that unprotected life cannot survive there for long.
+    Lethal,
+}
+
+impl Display for StellarNeighborhoodRadiation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StellarNeighborhoodRadiation::Low => write!(f, "Low"),
+            StellarNeighborhoodRadiation::Normal => write!(f, "Normal"),
+            StellarNeighborhoodRadiation::High => write!(f, "High"),
+            StellarNeighborhoodRadiation::VeryHigh => write!(f, "Very High"),
+            StellarNeighborhoodRadiation::Lethal => write!(f, "Lethal"),
+        }
+    }
+}
@@ -53,6 +53,7 @@ pub fn complete_orbit_with_orbital_period(
     star_id: u32,
     orbited_object_mass: f64,
     gas_giant_arrangement: GasGiantArrangement,
+    system_traits: &Vec<SystemPeculiarity>,
     orbital_point_id: u32,
     own_orbit: &Option<Orbit>,
     orbit_distance: f64,
@@ -75,6 +76,7 @@ pub fn complete_orbit_with_orbital_period(
         system_index,
         star_id,
         gas_giant_arrangement,
+        system_traits,
         orbital_point_id,
         orbit_distance,
         &settings,
@@ -387,7 +389,7 @@ fn generate_axial_tilt(
     axial_tilt
 }
 
-fn calculate_day_length(
+pub(crate) fn calculate_day_length(
     orbited_object_orbital_period: Option<f32>,
     is_moon: bool,
     this_orbit: &Orbit,
@@ -658,6 +660,7 @@ pub fn calculate_planet_orbit_eccentricity(
     system_index: u16,
     star_id: u32,
     gas_giant_arrangement: GasGiantArrangement,
+    system_traits: &Vec<SystemPeculiarity>,
     orbital_point_id: u32,
     orbit_distance: f64,
     settings: &GenerationSettings,
@@ -686,6 +689,16 @@ pub fn calculate_planet_orbit_eccentricity(
         } else {
             0
         };
+    // The bodies that survived a cataclysm were thrown onto much more eccentric orbits
+    system_traits.iter().for_each(|t| {
+        eccentricity_modifier += match t {
+            SystemPeculiarity::Cataclysm(CataclysmSeverity::Minor) => 2,
+            SystemPeculiarity::Cataclysm(CataclysmSeverity::Major) => 4,
+            SystemPeculiarity::Cataclysm(CataclysmSeverity::Extreme) => 6,
+            SystemPeculiarity::Cataclysm(CataclysmSeverity::Ultimate) => 8,
+            _ => 0,
+        }
+    });
     let roll = rng.roll(3, 6, eccentricity_modifier);
     let eccentricity = ((if roll <= 3 {
         0.0
@@ -729,6 +742,46 @@ pub fn calculate_planet_orbit_eccentricity(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cataclysm_raises_orbit_eccentricity() {
+        let settings = GenerationSettings {
+            seed: "default".into(),
+            ..Default::default()
+        };
+        let coord = SpaceCoordinates::new(0, 0, 0);
+        let average_eccentricity = |system_traits: &Vec<SystemPeculiarity>| {
+            (0..200)
+                .map(|id| {
+                    calculate_planet_orbit_eccentricity(
+                        &coord,
+                        0,
+                        0,
+                        GasGiantArrangement::NoGasGiant,
+                        system_traits,
+                        id,
+                        1.0,
+                        &settings,
+                        280,
+                        CelestialBodySize::Standard,
+                        false,
+                        false,
+                    )
+                    .0
+                })
+                .sum::<f64>()
+                / 200.0
+        };
+        let calm = average_eccentricity(&vec![]);
+        let minor = average_eccentricity(&vec![SystemPeculiarity::Cataclysm(
+            CataclysmSeverity::Minor,
+        )]);
+        let ultimate = average_eccentricity(&vec![SystemPeculiarity::Cataclysm(
+            CataclysmSeverity::Ultimate,
+        )]);
+        assert!(calm < minor);
+        assert!(minor < ultimate);
+    }
+
     #[test]
     fn test_moon_orbital_period() {
         let radius = 0.00257; // in AU
@@ -0,0 +1,1240 @@
+use super::StarSystem;
+use crate::internal::*;
+use crate::prelude::*;
+use crate::system::contents::utils::calculate_blackbody_temperature;
+use crate::system::contents::zones::generate_star_zones;
+use crate::system::orbital_point::generator::calculate_day_length;
+
+/// The orbital elements of a body of the Solar System, as measured by astronomers.
+struct OrbitalElements {
+    /// The semi-major axis of the orbit, in AU.
+    distance: f64,
+    /// The eccentricity of the orbit.
+    eccentricity: f32,
+    /// The inclination of the orbit in degrees, to the ecliptic for bodies orbiting the Sun and to their planet's equator for moons.
+    inclination: f32,
+    /// The axial tilt of the body, in degrees.
+    axial_tilt: f32,
+    /// The sidereal orbital period, in terran days.
+    orbital_period: f32,
+    /// The sidereal rotation period in terran days, negative if the rotation is retrograde.
+    rotation: f32,
+}
+
+impl StarSystem {
+    /// Returns a copy of our own Solar System: the Sun, its eight planets, its dwarf planets, their major moons, the asteroid and Kuiper
+    /// belts, and Saturn's rings. The data comes from actual measurements and can serve as a reference when checking generated systems.
+    pub fn sol() -> Self {
+        let sun = Star::new(
+            "Sun".into(),
+            1.0,
+            1.0,
+            1.0,
+            4.6,
+            5772,
+            StellarEvolution::Dwarf,
+            0.0,
+            StarSpectralType::G(2),
+            StarLuminosityClass::V,
+            vec![],
+            0,
+            None,
+            vec![],
+        );
+        let mut all_objects = vec![OrbitalPoint::new(
+            0,
+            None,
+            AstronomicalObject::Star(sun),
+            vec![],
+        )];
+        generate_star_zones(&mut all_objects);
+
+        add_inner_planets(&mut all_objects);
+        add_jupiter(&mut all_objects);
+        add_saturn(&mut all_objects);
+        add_ice_giants(&mut all_objects);
+        add_trans_neptunian_objects(&mut all_objects);
+
+        all_objects
+            .iter_mut()
+            .for_each(|o| o.update_object_own_orbit());
+        Self::new("Sol".into(), 0, 0, all_objects, vec![])
+    }
+}
+
+/// Adds Mercury, Venus, the Earth and its Moon, Mars, the asteroid belt and Ceres.
+fn add_inner_planets(all_objects: &mut Vec<OrbitalPoint>) {
+    add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 0.387,
+            eccentricity: 0.2056,
+            inclination: 7.005,
+            axial_tilt: 0.034,
+            orbital_period: 87.97,
+            rotation: 58.646,
+        },
+        make_telluric_body(
+            "Mercury",
+            0.0553,
+            0.383,
+            5.427,
+            0.38,
+            CelestialBodySize::Tiny,
+            TelluricBodyDetails {
+                temperature_category: WorldTemperatureCategory::Infernal,
+                ..make_world_details(
+                    TelluricBodyComposition::Metallic,
+                    CelestialBodyWorldType::Rock,
+                    CelestialBodyCoreHeat::WarmCore,
+                    MagneticFieldStrength::Moderate,
+                    vec![CelestialBodySpecialTrait::UnusualRotation(
+                        TelluricRotationDifference::Resonant,
+                    )],
+                )
+            },
+        ),
+    );
+    add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 0.723,
+            eccentricity: 0.0068,
+            inclination: 3.395,
+            axial_tilt: 2.64,
+            orbital_period: 224.7,
+            rotation: -243.02,
+        },
+        make_telluric_body(
+            "Venus",
+            0.815,
+            0.949,
+            5.243,
+            0.904,
+            CelestialBodySize::Standard,
+            TelluricBodyDetails {
+                atmospheric_pressure: 92.0,
+                atmospheric_composition: vec![
+                    (96.5, ChemicalComponent::CarbonDioxide),
+                    (3.5, ChemicalComponent::Nitrogen),
+                ],
+                volcanism: 20.0,
+                temperature_category: WorldTemperatureCategory::Infernal,
+                ..make_world_details(
+                    TelluricBodyComposition::Rocky,
+                    CelestialBodyWorldType::Greenhouse,
+                    CelestialBodyCoreHeat::WarmCore,
+                    MagneticFieldStrength::None,
+                    vec![CelestialBodySpecialTrait::UnusualRotation(
+                        TelluricRotationDifference::Retrograde,
+                    )],
+                )
+            },
+        ),
+    );
+    let earth = add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 1.0,
+            eccentricity: 0.0167,
+            inclination: 0.0,
+            axial_tilt: 23.44,
+            orbital_period: 365.256,
+            rotation: 0.99727,
+        },
+        make_telluric_body(
+            "Earth",
+            1.0,
+            1.0,
+            5.514,
+            1.0,
+            CelestialBodySize::Standard,
+            TelluricBodyDetails {
+                atmospheric_pressure: 1.0,
+                atmospheric_composition: vec![
+                    (78.08, ChemicalComponent::Nitrogen),
+                    (20.95, ChemicalComponent::Oxygen),
+                    (0.93, ChemicalComponent::Argon),
+                    (0.04, ChemicalComponent::CarbonDioxide),
+                ],
+                hydrosphere: 68.0,
+                ice_over_water: 3.0,
+                land_area_percentage: 26.0,
+                ice_over_land: 3.0,
+                volcanism: 10.0,
+                tectonic_activity: 30.0,
+                humidity: 70.0,
+                temperature_category: WorldTemperatureCategory::Temperate,
+                climate: WorldClimateType::Terrestrial,
+                ..make_world_details(
+                    TelluricBodyComposition::Rocky,
+                    CelestialBodyWorldType::Terrestrial,
+                    CelestialBodyCoreHeat::ActiveCore,
+                    MagneticFieldStrength::Strong,
+                    vec![],
+                )
+            },
+        ),
+    );
+    add_body(
+        all_objects,
+        earth,
+        OrbitalElements {
+            distance: 0.00257,
+            eccentricity: 0.0549,
+            inclination: 5.145,
+            axial_tilt: 6.68,
+            orbital_period: 27.322,
+            rotation: 27.322,
+        },
+        make_telluric_body(
+            "Moon",
+            0.0123,
+            0.2727,
+            3.344,
+            0.1654,
+            CelestialBodySize::Tiny,
+            make_world_details(
+                TelluricBodyComposition::Rocky,
+                CelestialBodyWorldType::Rock,
+                CelestialBodyCoreHeat::FrozenCore,
+                MagneticFieldStrength::None,
+                vec![CelestialBodySpecialTrait::TideLocked(
+                    TideLockTarget::Orbited,
+                )],
+            ),
+        ),
+    );
+    add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 1.524,
+            eccentricity: 0.0934,
+            inclination: 1.85,
+            axial_tilt: 25.19,
+            orbital_period: 686.98,
+            rotation: 1.026,
+        },
+        make_telluric_body(
+            "Mars",
+            0.107,
+            0.532,
+            3.934,
+            0.3794,
+            CelestialBodySize::Small,
+            TelluricBodyDetails {
+                atmospheric_pressure: 0.006,
+                atmospheric_composition: vec![
+                    (95.1, ChemicalComponent::CarbonDioxide),
+                    (2.6, ChemicalComponent::Nitrogen),
+                    (1.9, ChemicalComponent::Argon),
+                    (0.16, ChemicalComponent::Oxygen),
+                ],
+                land_area_percentage: 99.0,
+                ice_over_land: 1.0,
+                volcanism: 1.0,
+                ..make_world_details(
+                    TelluricBodyComposition::Rocky,
+                    CelestialBodyWorldType::Rock,
+                    CelestialBodyCoreHeat::FrozenCore,
+                    MagneticFieldStrength::Weak,
+                    vec![],
+                )
+            },
+        ),
+    );
+    add_belt(
+        all_objects,
+        2.7,
+        2.2,
+        3.2,
+        AstronomicalObject::TelluricDisk(CelestialDisk::new(
+            None,
+            0,
+            "Asteroid Belt".into(),
+            CelestialDiskType::Belt(CelestialBeltDetails::new(CelestialBeltType::Asteroid)),
+        )),
+    );
+    add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 2.767,
+            eccentricity: 0.0758,
+            inclination: 10.59,
+            axial_tilt: 4.0,
+            orbital_period: 1680.0,
+            rotation: 0.3781,
+        },
+        make_telluric_body(
+            "Ceres",
+            0.000157,
+            0.0739,
+            2.162,
+            0.029,
+            CelestialBodySize::Tiny,
+            make_world_details(
+                TelluricBodyComposition::Rocky,
+                CelestialBodyWorldType::DirtySnowball,
+                CelestialBodyCoreHeat::FrozenCore,
+                MagneticFieldStrength::None,
+                vec![],
+            ),
+        ),
+    );
+}
+
+/// Adds Jupiter and its Galilean moons.
+fn add_jupiter(all_objects: &mut Vec<OrbitalPoint>) {
+    let jupiter = add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 5.203,
+            eccentricity: 0.0489,
+            inclination: 1.303,
+            axial_tilt: 3.13,
+            orbital_period: 4332.59,
+            rotation: 0.41354,
+        },
+        make_giant(
+            "Jupiter",
+            317.8,
+            11.21,
+            1.326,
+            2.528,
+            CelestialBodySize::Supergiant,
+            false,
+            vec![],
+        ),
+    );
+    add_body(
+        all_objects,
+        jupiter,
+        OrbitalElements {
+            distance: 0.002819,
+            eccentricity: 0.0041,
+            inclination: 0.05,
+            axial_tilt: 0.0,
+            orbital_period: 1.769,
+            rotation: 1.769,
+        },
+        make_telluric_body(
+            "Io",
+            0.015,
+            0.286,
+            3.528,
+            0.183,
+            CelestialBodySize::Tiny,
+            TelluricBodyDetails {
+                volcanism: 90.0,
+                ..make_world_details(
+                    TelluricBodyComposition::Rocky,
+                    CelestialBodyWorldType::GeoActive,
+                    CelestialBodyCoreHeat::IntenseCore,
+                    MagneticFieldStrength::None,
+                    vec![CelestialBodySpecialTrait::TideLocked(
+                        TideLockTarget::Orbited,
+                    )],
+                )
+            },
+        ),
+    );
+    for (
+        name,
+        distance,
+        eccentricity,
+        inclination,
+        orbital_period,
+        mass,
+        radius,
+        density,
+        gravity,
+    ) in [
+        (
+            "Europa", 0.004486, 0.009, 0.47, 3.551, 0.008, 0.245, 3.013, 0.134,
+        ),
+        (
+            "Ganymede", 0.007155, 0.0013, 0.2, 7.155, 0.0248, 0.413, 1.936, 0.146,
+        ),
+        (
+            "Callisto", 0.012585, 0.0074, 0.19, 16.689, 0.018, 0.378, 1.834, 0.126,
+        ),
+    ] {
+        let has_ocean = name != "Callisto";
+        add_body(
+            all_objects,
+            jupiter,
+            OrbitalElements {
+                distance,
+                eccentricity,
+                inclination,
+                axial_tilt: 0.0,
+                orbital_period,
+                rotation: orbital_period,
+            },
+            make_telluric_body(
+                name,
+                mass,
+                radius,
+                density,
+                gravity,
+                CelestialBodySize::Tiny,
+                make_world_details(
+                    TelluricBodyComposition::Icy,
+                    CelestialBodyWorldType::Ice,
+                    if has_ocean {
+                        CelestialBodyCoreHeat::WarmCore
+                    } else {
+                        CelestialBodyCoreHeat::FrozenCore
+                    },
+                    if name == "Ganymede" {
+                        MagneticFieldStrength::Weak
+                    } else {
+                        MagneticFieldStrength::None
+                    },
+                    if has_ocean {
+                        vec![
+                            CelestialBodySpecialTrait::TideLocked(TideLockTarget::Orbited),
+                            CelestialBodySpecialTrait::SubSurfaceOceans(ChemicalComponent::Water),
+                        ]
+                    } else {
+                        vec![CelestialBodySpecialTrait::TideLocked(
+                            TideLockTarget::Orbited,
+                        )]
+                    },
+                ),
+            ),
+        );
+    }
+}
+
+/// Adds Saturn, its rings and its major moons.
+fn add_saturn(all_objects: &mut Vec<OrbitalPoint>) {
+    let saturn = add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 9.537,
+            eccentricity: 0.0565,
+            inclination: 2.485,
+            axial_tilt: 26.73,
+            orbital_period: 10759.22,
+            rotation: 0.44401,
+        },
+        make_giant(
+            "Saturn",
+            95.16,
+            9.45,
+            0.687,
+            1.065,
+            CelestialBodySize::Giant,
+            false,
+            vec![],
+        ),
+    );
+    let rings = add_body(
+        all_objects,
+        saturn,
+        OrbitalElements {
+            distance: 0.0007,
+            eccentricity: 0.0,
+            inclination: 0.0,
+            axial_tilt: 0.0,
+            orbital_period: 0.0,
+            rotation: 0.0,
+        },
+        AstronomicalObject::IcyDisk(CelestialDisk::new(
+            None,
+            0,
+            "Rings of Saturn".into(),
+            CelestialDiskType::Ring(CelestialRingDetails::new(
+                CelestialRingLevel::Spectacular,
+                CelestialRingComposition::Ice,
+            )),
+        )),
+    );
+    set_separations(all_objects, rings, 0.00045, 0.00094);
+    for (
+        name,
+        distance,
+        eccentricity,
+        inclination,
+        orbital_period,
+        mass,
+        radius,
+        density,
+        gravity,
+    ) in [
+        (
+            "Mimas", 0.001243, 0.0196, 1.574, 0.942, 0.0000063, 0.0311, 1.149, 0.0065,
+        ),
+        (
+            "Enceladus",
+            0.001591,
+            0.0047,
+            0.009,
+            1.37,
+            0.0000181,
+            0.0395,
+            1.609,
+            0.0115,
+        ),
+        (
+            "Tethys", 0.00197, 0.0001, 1.12, 1.888, 0.000103, 0.0834, 0.984, 0.0149,
+        ),
+        (
+            "Dione", 0.002523, 0.0022, 0.019, 2.737, 0.000184, 0.0883, 1.478, 0.0237,
+        ),
+        (
+            "Rhea", 0.003524, 0.001, 0.345, 4.518, 0.000387, 0.1199, 1.236, 0.0269,
+        ),
+        (
+            "Iapetus", 0.023802, 0.0286, 15.47, 79.32, 0.000302, 0.1153, 1.088, 0.0228,
+        ),
+    ] {
+        let mut special_traits = vec![CelestialBodySpecialTrait::TideLocked(
+            TideLockTarget::Orbited,
+        )];
+        if name == "Enceladus" {
+            special_traits.push(CelestialBodySpecialTrait::SubSurfaceOceans(
+                ChemicalComponent::Water,
+            ));
+        }
+        add_body(
+            all_objects,
+            saturn,
+            OrbitalElements {
+                distance,
+                eccentricity,
+                inclination,
+                axial_tilt: 0.0,
+                orbital_period,
+                rotation: orbital_period,
+            },
+            make_telluric_body(
+                name,
+                mass,
+                radius,
+                density,
+                gravity,
+                CelestialBodySize::Tiny,
+                make_world_details(
+                    TelluricBodyComposition::Icy,
+                    CelestialBodyWorldType::Ice,
+                    if name == "Enceladus" {
+                        CelestialBodyCoreHeat::WarmCore
+                    } else {
+                        CelestialBodyCoreHeat::FrozenCore
+                    },
+                    MagneticFieldStrength::None,
+                    special_traits,
+                ),
+            ),
+        );
+    }
+    add_body(
+        all_objects,
+        saturn,
+        OrbitalElements {
+            distance: 0.008168,
+            eccentricity: 0.0288,
+            inclination: 0.348,
+            axial_tilt: 0.0,
+            orbital_period: 15.945,
+            rotation: 15.945,
+        },
+        make_telluric_body(
+            "Titan",
+            0.0225,
+            0.404,
+            1.88,
+            0.138,
+            CelestialBodySize::Small,
+            TelluricBodyDetails {
+                atmospheric_pressure: 1.45,
+                atmospheric_composition: vec![
+                    (95.0, ChemicalComponent::Nitrogen),
+                    (4.9, ChemicalComponent::Methane),
+                    (0.1, ChemicalComponent::Hydrogen),
+                ],
+                ..make_world_details(
+                    TelluricBodyComposition::Icy,
+                    CelestialBodyWorldType::Ice,
+                    CelestialBodyCoreHeat::WarmCore,
+                    MagneticFieldStrength::None,
+                    vec![
+                        CelestialBodySpecialTrait::TideLocked(TideLockTarget::Orbited),
+                        CelestialBodySpecialTrait::Lakes(ChemicalComponent::Methane),
+                        CelestialBodySpecialTrait::SubSurfaceOceans(ChemicalComponent::Water),
+                    ],
+                )
+            },
+        ),
+    );
+    sort_moons(all_objects, saturn);
+}
+
+/// Adds Uranus, Neptune and their major moons.
+fn add_ice_giants(all_objects: &mut Vec<OrbitalPoint>) {
+    let uranus = add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 19.19,
+            eccentricity: 0.0457,
+            inclination: 0.773,
+            axial_tilt: 97.77,
+            orbital_period: 30688.5,
+            rotation: 0.71833,
+        },
+        make_giant(
+            "Uranus",
+            14.54,
+            4.007,
+            1.27,
+            0.886,
+            CelestialBodySize::Giant,
+            true,
+            vec![CelestialBodySpecialTrait::UnusualAxialTilt(
+                TelluricAxialTiltDifference::Extreme,
+            )],
+        ),
+    );
+    for (
+        name,
+        distance,
+        eccentricity,
+        inclination,
+        orbital_period,
+        mass,
+        radius,
+        density,
+        gravity,
+    ) in [
+        (
+            "Miranda", 0.000868, 0.0013, 4.232, 1.413, 0.0000111, 0.0369, 1.2, 0.0081,
+        ),
+        (
+            "Ariel", 0.001277, 0.0012, 0.26, 2.52, 0.000226, 0.0908, 1.592, 0.0274,
+        ),
+        (
+            "Umbriel", 0.00178, 0.0039, 0.128, 4.144, 0.000215, 0.0917, 1.39, 0.0234,
+        ),
+        (
+            "Titania", 0.002916, 0.0011, 0.34, 8.706, 0.000574, 0.1237, 1.711, 0.0386,
+        ),
+        (
+            "Oberon", 0.0039, 0.0014, 0.058, 13.463, 0.000508, 0.1194, 1.63, 0.0354,
+        ),
+    ] {
+        add_body(
+            all_objects,
+            uranus,
+            OrbitalElements {
+                distance,
+                eccentricity,
+                inclination,
+                axial_tilt: 0.0,
+                orbital_period,
+                rotation: orbital_period,
+            },
+            make_telluric_body(
+                name,
+                mass,
+                radius,
+                density,
+                gravity,
+                CelestialBodySize::Tiny,
+                make_world_details(
+                    TelluricBodyComposition::Icy,
+                    CelestialBodyWorldType::Ice,
+                    CelestialBodyCoreHeat::FrozenCore,
+                    MagneticFieldStrength::None,
+                    vec![CelestialBodySpecialTrait::TideLocked(
+                        TideLockTarget::Orbited,
+                    )],
+                ),
+            ),
+        );
+    }
+
+    let neptune = add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 30.07,
+            eccentricity: 0.0113,
+            inclination: 1.77,
+            axial_tilt: 28.32,
+            orbital_period: 60195.0,
+            rotation: 0.67125,
+        },
+        make_giant(
+            "Neptune",
+            17.15,
+            3.883,
+            1.638,
+            1.14,
+            CelestialBodySize::Giant,
+            true,
+            vec![],
+        ),
+    );
+    add_body(
+        all_objects,
+        neptune,
+        OrbitalElements {
+            distance: 0.002371,
+            eccentricity: 0.000016,
+            inclination: 156.885,
+            axial_tilt: 0.0,
+            orbital_period: 5.877,
+            rotation: 5.877,
+        },
+        make_telluric_body(
+            "Triton",
+            0.00359,
+            0.2122,
+            2.061,
+            0.0794,
+            CelestialBodySize::Tiny,
+            TelluricBodyDetails {
+                atmospheric_pressure: 0.000014,
+                atmospheric_composition: vec![
+                    (99.9, ChemicalComponent::Nitrogen),
+                    (0.1, ChemicalComponent::Methane),
+                ],
+                ..make_world_details(
+                    TelluricBodyComposition::Icy,
+                    CelestialBodyWorldType::Hadean,
+                    CelestialBodyCoreHeat::WarmCore,
+                    MagneticFieldStrength::None,
+                    vec![
+                        CelestialBodySpecialTrait::RetrogradeOrbit,
+                        CelestialBodySpecialTrait::TideLocked(TideLockTarget::Orbited),
+                    ],
+                )
+            },
+        ),
+    );
+}
+
+/// Adds the Kuiper belt, along with Pluto and its moon Charon, Haumea, Makemake and Eris.
+fn add_trans_neptunian_objects(all_objects: &mut Vec<OrbitalPoint>) {
+    let pluto = add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance: 39.48,
+            eccentricity: 0.2488,
+            inclination: 17.16,
+            axial_tilt: 122.53,
+            orbital_period: 90560.0,
+            rotation: 6.387,
+        },
+        make_telluric_body(
+            "Pluto",
+            0.0022,
+            0.1868,
+            1.854,
+            0.063,
+            CelestialBodySize::Tiny,
+            TelluricBodyDetails {
+                atmospheric_pressure: 0.00001,
+                atmospheric_composition: vec![
+                    (99.0, ChemicalComponent::Nitrogen),
+                    (0.5, ChemicalComponent::Methane),
+                    (0.5, ChemicalComponent::CarbonMonoxide),
+                ],
+                ..make_world_details(
+                    TelluricBodyComposition::Icy,
+                    CelestialBodyWorldType::Hadean,
+                    CelestialBodyCoreHeat::FrozenCore,
+                    MagneticFieldStrength::None,
+                    vec![
+                        CelestialBodySpecialTrait::TideLocked(TideLockTarget::Satellite),
+                        CelestialBodySpecialTrait::UnusualAxialTilt(
+                            TelluricAxialTiltDifference::Extreme,
+                        ),
+                    ],
+                )
+            },
+        ),
+    );
+    add_body(
+        all_objects,
+        pluto,
+        OrbitalElements {
+            distance: 0.0001313,
+            eccentricity: 0.0002,
+            inclination: 0.08,
+            axial_tilt: 0.0,
+            orbital_period: 6.387,
+            rotation: 6.387,
+        },
+        make_telluric_body(
+            "Charon",
+            0.000254,
+            0.0949,
+            1.702,
+            0.0294,
+            CelestialBodySize::Tiny,
+            make_world_details(
+                TelluricBodyComposition::Icy,
+                CelestialBodyWorldType::Ice,
+                CelestialBodyCoreHeat::FrozenCore,
+                MagneticFieldStrength::None,
+                vec![CelestialBodySpecialTrait::TideLocked(
+                    TideLockTarget::Orbited,
+                )],
+            ),
+        ),
+    );
+    add_belt(
+        all_objects,
+        42.0,
+        30.0,
+        50.0,
+        AstronomicalObject::IcyDisk(CelestialDisk::new(
+            None,
+            0,
+            "Kuiper Belt".into(),
+            CelestialDiskType::Belt(CelestialBeltDetails::new(CelestialBeltType::Comet)),
+        )),
+    );
+    // The axial tilts of the farthest dwarf planets are still unknown.
+    for (name, elements, mass, radius, density, gravity, special_traits) in [
+        (
+            "Haumea",
+            OrbitalElements {
+                distance: 43.13,
+                eccentricity: 0.1912,
+                inclination: 28.21,
+                axial_tilt: 0.0,
+                orbital_period: 103410.0,
+                rotation: 0.16314,
+            },
+            0.000671,
+            0.1282,
+            1.885,
+            0.0408,
+            vec![CelestialBodySpecialTrait::UnusualRotation(
+                TelluricRotationDifference::Fast,
+            )],
+        ),
+        (
+            "Makemake",
+            OrbitalElements {
+                distance: 45.79,
+                eccentricity: 0.1559,
+                inclination: 28.98,
+                axial_tilt: 0.0,
+                orbital_period: 111845.0,
+                rotation: 0.9511,
+            },
+            0.00052,
+            0.1123,
+            1.9,
+            0.05,
+            vec![],
+        ),
+        (
+            "Eris",
+            OrbitalElements {
+                distance: 67.86,
+                eccentricity: 0.4361,
+                inclination: 44.04,
+                axial_tilt: 0.0,
+                orbital_period: 204199.0,
+                rotation: 15.786,
+            },
+            0.0028,
+            0.1825,
+            2.43,
+            0.084,
+            vec![],
+        ),
+    ] {
+        add_body(
+            all_objects,
+            0,
+            elements,
+            make_telluric_body(
+                name,
+                mass,
+                radius,
+                density,
+                gravity,
+                CelestialBodySize::Tiny,
+                make_world_details(
+                    TelluricBodyComposition::Icy,
+                    CelestialBodyWorldType::Ice,
+                    CelestialBodyCoreHeat::FrozenCore,
+                    MagneticFieldStrength::None,
+                    special_traits,
+                ),
+            ),
+        );
+    }
+}
+
+/// Places the given object on its own [OrbitalPoint], orbiting the point with the given id, and returns the id of the new point. The
+/// zone of the orbit and the blackbody temperature of bodies are deduced from the distance to the Sun.
+fn add_body(
+    all_objects: &mut Vec<OrbitalPoint>,
+    primary_body_id: u32,
+    elements: OrbitalElements,
+    mut object: AstronomicalObject,
+) -> u32 {
+    let id = all_objects.len() as u32;
+    let primary_orbit = all_objects
+        .iter()
+        .find(|o| o.id == primary_body_id)
+        .and_then(|o| o.own_orbit.clone());
+    let is_moon = primary_orbit.is_some();
+    let distance_from_sun = primary_orbit
+        .as_ref()
+        .map(|orbit| orbit.average_distance_from_system_center)
+        .unwrap_or(elements.distance);
+    let zone = primary_orbit
+        .as_ref()
+        .map(|orbit| orbit.zone)
+        .unwrap_or_else(|| get_sun_zone(all_objects, elements.distance));
+
+    let mut orbit = Orbit::new(
+        primary_body_id,
+        Some(id),
+        zone,
+        elements.distance,
+        elements.distance * (1.0 - elements.eccentricity as f64),
+        elements.distance * (1.0 + elements.eccentricity as f64),
+        distance_from_sun,
+        elements.eccentricity,
+        elements.inclination,
+        elements.axial_tilt,
+        elements.orbital_period,
+        elements.rotation,
+        0.0,
+    );
+    if elements.rotation != 0.0 {
+        orbit.day_length = calculate_day_length(
+            primary_orbit.as_ref().map(|o| o.orbital_period),
+            is_moon,
+            &orbit,
+        );
+    }
+
+    match &mut object {
+        AstronomicalObject::TelluricBody(body)
+        | AstronomicalObject::IcyBody(body)
+        | AstronomicalObject::GaseousBody(body) => {
+            body.blackbody_temperature = calculate_blackbody_temperature(1.0, distance_from_sun);
+        }
+        _ => {}
+    }
+
+    if let Some(primary) = all_objects.iter_mut().find(|o| o.id == primary_body_id) {
+        primary.orbits.push(orbit.clone());
+    }
+    all_objects.push(OrbitalPoint::new(id, Some(orbit), object, vec![]));
+    id
+}
+
+/// Places the given belt around the Sun, spreading it between the given distances in AU.
+fn add_belt(
+    all_objects: &mut Vec<OrbitalPoint>,
+    distance: f64,
+    min_separation: f64,
+    max_separation: f64,
+    belt: AstronomicalObject,
+) {
+    let id = add_body(
+        all_objects,
+        0,
+        OrbitalElements {
+            distance,
+            eccentricity: 0.0,
+            inclination: 0.0,
+            axial_tilt: 0.0,
+            orbital_period: (distance.powf(1.5) * 365.256) as f32,
+            rotation: 0.0,
+        },
+        belt,
+    );
+    set_separations(all_objects, id, min_separation, max_separation);
+}
+
+/// Replaces the minimum and maximum separations of the orbit of the point with the given id, in every place the orbit is referenced.
+fn set_separations(
+    all_objects: &mut [OrbitalPoint],
+    id: u32,
+    min_separation: f64,
+    max_separation: f64,
+) {
+    for point in all_objects.iter_mut() {
+        for orbit in point
+            .own_orbit
+            .iter_mut()
+            .chain(point.orbits.iter_mut())
+            .filter(|orbit| orbit.id == Some(id))
+        {
+            orbit.min_separation = min_separation;
+            orbit.max_separation = max_separation;
+        }
+    }
+}
+
+/// Sorts the orbits around the point with the given id from the closest to the farthest.
+fn sort_moons(all_objects: &mut [OrbitalPoint], id: u32) {
+    if let Some(point) = all_objects.iter_mut().find(|o| o.id == id) {
+        point.orbits.sort_by(|a, b| {
+            a.average_distance
+                .partial_cmp(&b.average_distance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+}
+
+/// Returns the type of the Sun's zone found at the given distance in AU. Objects lying past the outer zone, like the farthest dwarf
+/// planets, are still considered part of it.
+fn get_sun_zone(all_objects: &[OrbitalPoint], distance: f64) -> ZoneType {
+    if let AstronomicalObject::Star(sun) = &all_objects[0].object {
+        sun.zones
+            .iter()
+            .find(|zone| zone.start <= distance && distance < zone.end)
+            .map(|zone| zone.zone_type)
+            .unwrap_or(ZoneType::OuterZone)
+    } else {
+        ZoneType::OuterZone
+    }
+}
+
+/// Returns a telluric body with the given characteristics, in Earth masses, Earth radii, g/cm³ and Gs.
+fn make_telluric_body(
+    name: &str,
+    mass: f64,
+    radius: f64,
+    density: f32,
+    gravity: f32,
+    size: CelestialBodySize,
+    details: TelluricBodyDetails,
+) -> AstronomicalObject {
+    AstronomicalObject::TelluricBody(CelestialBody::new(
+        None,
+        0,
+        name.into(),
+        mass,
+        radius,
+        density,
+        gravity,
+        0,
+        0,
+        size,
+        CelestialBodyDetails::Telluric(details),
+    ))
+}
+
+/// Returns a gas giant, or an ice giant if **is_icy** is true, with the given characteristics, in Earth masses, Earth radii, g/cm³ and
+/// Gs.
+fn make_giant(
+    name: &str,
+    mass: f64,
+    radius: f64,
+    density: f32,
+    gravity: f32,
+    size: CelestialBodySize,
+    is_icy: bool,
+    special_traits: Vec<CelestialBodySpecialTrait>,
+) -> AstronomicalObject {
+    let special_traits = if special_traits.is_empty() {
+        vec![CelestialBodySpecialTrait::NoPeculiarity]
+    } else {
+        special_traits
+    };
+    let body = |details| {
+        CelestialBody::new(
+            None,
+            0,
+            name.into(),
+            mass,
+            radius,
+            density,
+            gravity,
+            0,
+            0,
+            size,
+            details,
+        )
+    };
+    if is_icy {
+        AstronomicalObject::IcyBody(body(CelestialBodyDetails::Icy(IcyBodyDetails::new(
+            CelestialBodyWorldType::VolatilesGiant,
+            special_traits,
+        ))))
+    } else {
+        AstronomicalObject::GaseousBody(body(CelestialBodyDetails::Gaseous(
+            GaseousBodyDetails::new(special_traits),
+        )))
+    }
+}
+
+/// Returns the details of a frozen and lifeless world without atmosphere, entirely covered by ice if it is mostly made of ices, that
+/// can then be adjusted to match the actual world.
+fn make_world_details(
+    body_type: TelluricBodyComposition,
+    world_type: CelestialBodyWorldType,
+    core_heat: CelestialBodyCoreHeat,
+    magnetic_field: MagneticFieldStrength,
+    special_traits: Vec<CelestialBodySpecialTrait>,
+) -> TelluricBodyDetails {
+    let is_icy = body_type == TelluricBodyComposition::Icy;
+    TelluricBodyDetails {
+        body_type,
+        world_type,
+        special_traits: if special_traits.is_empty() {
+            vec![CelestialBodySpecialTrait::NoPeculiarity]
+        } else {
+            special_traits
+        },
+        core_heat,
+        magnetic_field,
+        land_area_percentage: if is_icy { 0.0 } else { 100.0 },
+        ice_over_land: if is_icy { 100.0 } else { 0.0 },
+        humidity: -1.0,
+        temperature_category: WorldTemperatureCategory::Frozen,
+        climate: WorldClimateType::Dead,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_our_own_solar_system() {
+        let sol = StarSystem::sol();
+        assert_eq!(&*sol.name, "Sol");
+        if let AstronomicalObject::Star(sun) = &sol.get_main_star().object {
+            assert_eq!(&*sun.name, "Sun");
+            assert_eq!(sun.spectral_type, StarSpectralType::G(2));
+            assert!(!sun.zones.is_empty());
+        } else {
+            panic!("The main star should be the Sun.");
+        }
+
+        let names: Vec<&str> = sol
+            .get_center()
+            .orbits
+            .iter()
+            .filter_map(|orbit| sol.get_point(orbit.id.expect("Should be occupied.")))
+            .map(|point| match &point.object {
+                AstronomicalObject::TelluricBody(body)
+                | AstronomicalObject::IcyBody(body)
+                | AstronomicalObject::GaseousBody(body) => &*body.name,
+                AstronomicalObject::TelluricDisk(disk) | AstronomicalObject::IcyDisk(disk) => {
+                    &*disk.name
+                }
+                _ => "",
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "Mercury",
+                "Venus",
+                "Earth",
+                "Mars",
+                "Asteroid Belt",
+                "Ceres",
+                "Jupiter",
+                "Saturn",
+                "Uranus",
+                "Neptune",
+                "Pluto",
+                "Kuiper Belt",
+                "Haumea",
+                "Makemake",
+                "Eris"
+            ]
+        );
+
+        for point in sol.all_objects.iter().skip(1) {
+            let orbit = point.own_orbit.clone().expect("Should have an orbit.");
+            let primary = sol
+                .get_point(orbit.primary_body_id)
+                .expect("Should orbit an existing point.");
+            assert!(primary.orbits.contains(&orbit));
+            assert!(orbit.min_separation <= orbit.average_distance);
+            assert!(orbit.max_separation >= orbit.average_distance);
+        }
+
+        let earth = sol.get_main_world().expect("Should have a main world.");
+        assert_eq!(&*earth.name, "Earth");
+        assert_eq!(
+            earth.orbit.clone().unwrap_or_default().zone,
+            ZoneType::BioZone
+        );
+        assert!((earth.orbit.clone().unwrap_or_default().day_length - 1.0).abs() < 0.01);
+        assert_eq!(format!("{}", earth.get_uwp()), "?867???-?");
+        let moon = sol
+            .all_objects
+            .iter()
+            .find(|point| match &point.object {
+                AstronomicalObject::TelluricBody(body) => &*body.name == "Moon",
+                _ => false,
+            })
+            .and_then(|point| point.own_orbit.clone())
+            .expect("Should have a moon.");
+        assert!((moon.day_length - 29.53).abs() < 0.01);
+    }
+
+    #[test]
+    fn use_our_own_solar_system_when_asked() {
+        let settings = &GenerationSettings {
+            seed: "default".into(),
+            system: SystemSettings {
+                use_ours: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let neighborhood = GalacticNeighborhood::generate(Universe::generate(&settings), &settings);
+        let mut galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        let home = galaxy.get_home_coordinates();
+        let hex = galaxy.get_hex(home).expect("Should return a hex.");
+        assert_eq!(hex.contents, vec![StarSystem::sol()]);
+        let mut found = 0;
+        for x in 1..10 {
+            let hex = galaxy
+                .get_hex(home + SpaceCoordinates::new(x, 0, 0))
+                .expect("Should return a hex.");
+            for system in hex.contents.iter() {
+                assert_ne!(system, &StarSystem::sol());
+                found += 1;
+            }
+        }
+        assert!(found > 0);
+
+        let other_galaxy = Galaxy::generate(neighborhood, 1, &settings);
+        assert!(other_galaxy.pinned_systems.is_empty());
+
+        let settings = &GenerationSettings {
+            system: SystemSettings {
+                filter: Some(SystemTemplate {
+                    min_gas_giants: Some(5),
+                    ..Default::default()
+                }),
+                ..settings.system.clone()
+            },
+            ..settings.clone()
+        };
+        let galaxy = Galaxy::generate(neighborhood, 0, &settings);
+        assert!(galaxy.pinned_systems.is_empty());
+    }
+}
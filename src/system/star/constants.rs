@@ -3,6 +3,8 @@ use crate::internal::*;
 use crate::prelude::*;
 
 pub const BROWN_DWARF_MIN_MASS: f64 = 0.015;
+/// How many times the range of masses is halved when looking for the mass of a fixed star.
+pub const MASS_BISECTION_STEPS: u8 = 16;
 pub const RED_DWARF_POP_HYPERDWARF_MIN_MASS: f64 = 1.0 / 25.0;
 pub const RED_DWARF_POP_SUPERDWARF_MIN_MASS: f64 = 1.0 / 16.0;
 pub const RED_DWARF_POP_DWARF_MIN_MASS: f64 = 0.07;
@@ -177,6 +179,54 @@ pub const TEMPERATURE_TO_SPECTRAL_TYPE_DATASET: &[(u32, u32); 69] = &[
     (0, 109),
 ];
 
+/// The bolometric corrections of main sequence stars by spectral type, in the same order as [TEMPERATURE_TO_SPECTRAL_TYPE_DATASET]:
+/// the magnitudes to add to the absolute visual magnitude of a star to account for the light it emits outside of the visible spectrum.
+/// Mostly from Pecaut & Mamajek (2013), rough estimates for brown dwarfs.
+pub const BOLOMETRIC_CORRECTION_TO_SPECTRAL_TYPE_DATASET: &[(f32, u32); 42] = &[
+    (-4.01, 13), // O
+    (-3.85, 15),
+    (-3.58, 17),
+    (-3.33, 19),
+    (-3.16, 20), // B
+    (-2.70, 21),
+    (-2.35, 22),
+    (-1.94, 23),
+    (-1.46, 25),
+    (-0.94, 27),
+    (-0.66, 28),
+    (-0.40, 29),
+    (-0.25, 30), // A
+    (-0.08, 32),
+    (-0.02, 35),
+    (0.00, 37),
+    (-0.01, 40), // F
+    (-0.02, 45),
+    (-0.05, 50), // G
+    (-0.09, 52),
+    (-0.11, 55),
+    (-0.16, 58),
+    (-0.19, 60), // K
+    (-0.29, 62),
+    (-0.42, 63),
+    (-0.58, 65),
+    (-0.87, 67),
+    (-1.16, 70), // M
+    (-1.43, 71),
+    (-1.60, 72),
+    (-1.85, 73),
+    (-2.34, 74),
+    (-2.86, 75),
+    (-3.76, 76),
+    (-4.30, 77),
+    (-4.60, 78),
+    (-4.90, 79),
+    (-5.50, 80), // L
+    (-6.50, 83),
+    (-7.50, 88),
+    (-8.50, 95),   // T
+    (-10.00, 100), // Y
+];
+
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 pub struct TemperatureAndLuminosity(pub f32, pub f32);
 
@@ -190,6 +240,7 @@ pub fn get_test_stars() -> Vec<Star> {
             9.0,   // Age
             16500, // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::DA,
             StarLuminosityClass::VII,
             vec![],
@@ -207,6 +258,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.228,  // Age
             25200,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::DA,
             StarLuminosityClass::VII,
             vec![],
@@ -224,6 +276,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.003,     // Age
             13700,     // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::B(3),
             StarLuminosityClass::Ia,
             vec![],
@@ -241,6 +294,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.008,    // Age
             12100,    // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::F(8),
             StarLuminosityClass::Ia,
             vec![],
@@ -258,6 +312,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.022,   // Age
             22900,   // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::B(2),
             StarLuminosityClass::II,
             vec![],
@@ -275,6 +330,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.025,   // Age
             7400,    // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::A(9),
             StarLuminosityClass::II,
             vec![],
@@ -292,6 +348,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.095, // Age
             5160,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::G(2),
             StarLuminosityClass::Ib,
             vec![],
@@ -309,6 +366,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.5,    // Age
             6294,   // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::F(0),
             StarLuminosityClass::II,
             vec![],
@@ -326,6 +384,7 @@ pub fn get_test_stars() -> Vec<Star> {
             -1.0,      // Age
             240,       // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::Y(4),
             StarLuminosityClass::V,
             vec![],
@@ -343,6 +402,7 @@ pub fn get_test_stars() -> Vec<Star> {
             -1.0,      // Age
             1350,      // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::L(7),
             StarLuminosityClass::V,
             vec![],
@@ -360,6 +420,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.25,    // Age
             2749,    // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::M(6),
             StarLuminosityClass::V,
             vec![],
@@ -377,6 +438,7 @@ pub fn get_test_stars() -> Vec<Star> {
             4.85,     // Age
             2992,     // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::M(5),
             StarLuminosityClass::V,
             vec![],
@@ -394,6 +456,7 @@ pub fn get_test_stars() -> Vec<Star> {
             10.0,   // Age
             3134,   // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::M(4),
             StarLuminosityClass::V,
             vec![],
@@ -411,6 +474,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.9,      // Age
             3248,     // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::M(3),
             StarLuminosityClass::V,
             vec![],
@@ -428,6 +492,7 @@ pub fn get_test_stars() -> Vec<Star> {
             5.6,    // Age
             3100,   // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::M(4),
             StarLuminosityClass::V,
             vec![],
@@ -445,6 +510,7 @@ pub fn get_test_stars() -> Vec<Star> {
             7.5,    // Age
             3547,   // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::M(2),
             StarLuminosityClass::V,
             vec![],
@@ -462,6 +528,7 @@ pub fn get_test_stars() -> Vec<Star> {
             4.57,   // Age
             3672,   // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::M(0),
             StarLuminosityClass::V,
             vec![],
@@ -479,6 +546,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.44,  // Age
             4711,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::K(5),
             StarLuminosityClass::V,
             vec![],
@@ -496,6 +564,7 @@ pub fn get_test_stars() -> Vec<Star> {
             7.0,   // Age
             5072,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::K(0),
             StarLuminosityClass::V,
             vec![],
@@ -513,6 +582,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.6,   // Age
             5084,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::K(2),
             StarLuminosityClass::V,
             vec![],
@@ -530,6 +600,7 @@ pub fn get_test_stars() -> Vec<Star> {
             5.3,    // Age
             5260,   // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::K(1),
             StarLuminosityClass::V,
             vec![],
@@ -547,6 +618,7 @@ pub fn get_test_stars() -> Vec<Star> {
             2.1,   // Age
             5488,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::G(8),
             StarLuminosityClass::V,
             vec![],
@@ -564,6 +636,7 @@ pub fn get_test_stars() -> Vec<Star> {
             3.4,   // Age
             6043,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::G(0),
             StarLuminosityClass::V,
             vec![],
@@ -581,6 +654,7 @@ pub fn get_test_stars() -> Vec<Star> {
             4.6,  // Age
             5772, // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::G(2),
             StarLuminosityClass::V,
             vec![],
@@ -598,6 +672,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.3,   // Age
             5708,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::G(5),
             StarLuminosityClass::V,
             vec![],
@@ -615,6 +690,7 @@ pub fn get_test_stars() -> Vec<Star> {
             5.3,    // Age
             5790,   // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::G(2),
             StarLuminosityClass::V,
             vec![],
@@ -632,6 +708,7 @@ pub fn get_test_stars() -> Vec<Star> {
             3.12, // Age
             6213, // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::F(8),
             StarLuminosityClass::V,
             vec![],
@@ -649,6 +726,7 @@ pub fn get_test_stars() -> Vec<Star> {
             1.6,  // Age
             6399, // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::F(7),
             StarLuminosityClass::V,
             vec![],
@@ -666,6 +744,7 @@ pub fn get_test_stars() -> Vec<Star> {
             2.4,   // Age
             6550,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::F(5),
             StarLuminosityClass::V,
             vec![],
@@ -683,6 +762,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.1,  // Age
             7760, // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::A(7),
             StarLuminosityClass::V,
             vec![],
@@ -700,6 +780,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.44,  // Age
             8590,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::A(3),
             StarLuminosityClass::V,
             vec![],
@@ -717,6 +798,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.228, // Age
             7301,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::A(7),
             StarLuminosityClass::V,
             vec![],
@@ -734,6 +816,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.228, // Age
             9940,  // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::A(0),
             StarLuminosityClass::V,
             vec![],
@@ -751,6 +834,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.009, // Age
             14768, // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::B(7),
             StarLuminosityClass::V,
             vec![],
@@ -768,6 +852,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.022, // Age
             17201, // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::B(3),
             StarLuminosityClass::V,
             vec![],
@@ -785,6 +870,7 @@ pub fn get_test_stars() -> Vec<Star> {
             0.028,    // Age
             36000,    // Temperature
             StellarEvolution::Dwarf,
+            0.0, // Metallicity
             StarSpectralType::O(9),
             StarLuminosityClass::V,
             vec![],
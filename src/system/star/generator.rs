@@ -11,7 +11,7 @@ use crate::system::contents::elements::MOST_COMMON_ELEMENTS;
 use constants::*;
 
 impl Star {
-    /// Generates a new star.
+    /// Generates a new star. Returns an error if the star can't match the [FixedStar] it was given.
     pub fn generate(
         system_gen_try: u32,
         star_index: u16,
@@ -22,7 +22,7 @@ impl Star {
         hex: &GalacticHex,
         galaxy: &Galaxy,
         settings: &GenerationSettings,
-    ) -> Self {
+    ) -> Result<Self, Rc<str>> {
         let seed: Rc<str> = format!("{}{}", system_gen_try, &galaxy.settings.seed).into();
         let age = if settings.star.fixed_age.is_some() {
             settings.star.fixed_age.unwrap() * 1000.0
@@ -45,150 +45,698 @@ impl Star {
             simulate_mass_loss_over_the_years(generated_mass, age)
         };
 
-        // Main sequence estimations
-        let ms_luminosity = calculate_main_sequence_luminosity(mass);
-        let ms_radius = calculate_radius(mass, 0.0, 1.0, 0.0, 0.0, 0, 0, coord, galaxy);
-        let ms_temperature =
-            calculate_temperature_using_luminosity(ms_luminosity, ms_radius as f64) as u32;
+        let mut age = age;
+        if let Some(fixed_star) = settings
+            .star
+            .fixed_stars
+            .get(star_index as usize)
+            .filter(|f| f.spectral_type.is_some() || f.luminosity_class.is_some())
+        {
+            let score;
+            (mass, age, score) = find_mass_and_age(
+                *fixed_star,
+                mass,
+                age,
+                population,
+                star_index,
+                system_index,
+                coord,
+                &seed,
+                galaxy,
+                settings,
+            );
+            if score > 0 {
+                return Err(format!(
+                    "No star {} of system {} at {} could match its fixed type and class.",
+                    star_index, system_index, coord
+                )
+                .into());
+            }
+        }
+
+        let (mass, radius, luminosity, temperature, spectral_type, luminosity_class) = evolve_star(
+            mass,
+            age,
+            population,
+            star_index,
+            system_index,
+            coord,
+            &seed,
+            galaxy,
+            settings,
+        );
+
+        let name = get_star_name(star_index, system_name.clone(), settings);
+        let metallicity = if settings.star.use_ours {
+            0.0
+        } else {
+            generate_metallicity(star_index, system_index, coord, population, hex, &*seed)
+        };
+
+        let special_traits = generate_special_traits(
+            star_index,
+            system_index,
+            coord,
+            population,
+            spectral_type,
+            luminosity_class,
+            age / 1000.0,
+            &seed,
+            settings,
+        );
+
+        Ok(Self {
+            name,
+            mass,
+            luminosity,
+            radius,
+            age: age / 1000.0,
+            temperature,
+            population,
+            metallicity,
+            spectral_type,
+            luminosity_class,
+            special_traits,
+            orbital_point_id: star_index as u32,
+            orbit: None,
+            zones: vec![],
+        })
+    }
+
+    /// Creates a star from its entry in a [StarCatalog]. The catalog gives the star's luminosity, spectral type and luminosity class,
+    /// and possibly its mass, while the other parameters are derived from them or generated as usual.
+    pub fn from_catalog(
+        catalog_star: &CatalogStar,
+        star_index: u16,
+        system_index: u16,
+        system_name: Rc<str>,
+        coord: SpaceCoordinates,
+        population: StellarEvolution,
+        hex: &GalacticHex,
+        galaxy: &Galaxy,
+    ) -> Self {
+        let settings = &galaxy.settings;
+        let seed: Rc<str> = format!("{}{}", 0, &settings.seed).into();
+        let age = generate_age(
+            star_index,
+            system_index,
+            coord,
+            hex,
+            &seed,
+            &galaxy.neighborhood.universe,
+        );
+        let luminosity = catalog_star.get_luminosity();
+
+        // Stars whose spectral type is unknown are assumed to be on the main sequence.
+        let (spectral_type, luminosity_class) = match catalog_star.spectral_type {
+            Some(spectral_type) => (
+                spectral_type,
+                catalog_star
+                    .luminosity_class
+                    .unwrap_or(StarLuminosityClass::V),
+            ),
+            None => (
+                calculate_spectral_type(
+                    (5778.0 * f32::powf(luminosity, 0.136)).clamp(250.0, 54000.0) as u32,
+                ),
+                catalog_star
+                    .luminosity_class
+                    .unwrap_or(StarLuminosityClass::V),
+            ),
+        };
+        let mass = catalog_star
+            .mass
+            .unwrap_or_else(|| estimate_mass(luminosity, spectral_type, luminosity_class));
+        let temperature = calculate_temperature_using_spectral_type(spectral_type);
+        let radius = match spectral_type {
+            StarSpectralType::DA
+            | StarSpectralType::DB
+            | StarSpectralType::DC
+            | StarSpectralType::DO
+            | StarSpectralType::DZ
+            | StarSpectralType::DQ
+            | StarSpectralType::DX => calculate_white_dwarf_radius(mass),
+            StarSpectralType::XNS | StarSpectralType::XBH => {
+                calculate_precise_radius_of_neutron_star_or_black_hole(mass)
+            }
+            _ => calculate_radius_using_luminosity_and_temperature(luminosity, temperature),
+        };
+
+        let name = if catalog_star.name.is_empty() {
+            get_star_name(star_index, system_name, settings)
+        } else {
+            catalog_star.name.clone()
+        };
+        // Catalogued subdwarfs are metal-poor stars, whatever their neighbourhood.
+        let population = if luminosity_class == StarLuminosityClass::VI
+            && population != StellarEvolution::Subdwarf
+            && population != StellarEvolution::Paleodwarf
+        {
+            StellarEvolution::Subdwarf
+        } else {
+            population
+        };
+        let metallicity =
+            generate_metallicity(star_index, system_index, coord, population, hex, &seed);
+        let special_traits = generate_special_traits(
+            star_index,
+            system_index,
+            coord,
+            population,
+            spectral_type,
+            luminosity_class,
+            age / 1000.0,
+            &seed,
+            settings,
+        );
+
+        Self {
+            name,
+            mass,
+            luminosity,
+            radius,
+            age: age / 1000.0,
+            temperature,
+            population,
+            metallicity,
+            spectral_type,
+            luminosity_class,
+            special_traits,
+            orbital_point_id: star_index as u32,
+            orbit: None,
+            zones: vec![],
+        }
+    }
+}
+
+/// Simulates the evolution of a star of the given current **mass** up to the given **age** in millions of years. Returns its mass,
+/// radius, luminosity, temperature, spectral type and luminosity class, in that order.
+fn evolve_star(
+    mut mass: f64,
+    age: f32,
+    population: StellarEvolution,
+    star_index: u16,
+    system_index: u16,
+    coord: SpaceCoordinates,
+    seed: &str,
+    galaxy: &Galaxy,
+    settings: &GenerationSettings,
+) -> (f64, f64, f32, u32, StarSpectralType, StarLuminosityClass) {
+    // Main sequence estimations
+    let ms_luminosity = calculate_main_sequence_luminosity(mass);
+    let ms_radius = calculate_radius(mass, 0.0, 1.0, 0.0, 0.0, 0, 0, coord, galaxy);
+    let ms_temperature =
+        calculate_temperature_using_luminosity(ms_luminosity, ms_radius as f64) as u32;
+
+    let mut main_lifespan = calculate_lifespan(mass, ms_luminosity);
+    main_lifespan = adjust_lifespan_to_population(main_lifespan, population);
+    let subgiant_lifespan = calculate_subgiant_lifespan(mass, main_lifespan);
+    let giant_lifespan = calculate_giant_lifespan(mass, main_lifespan);
+    let full_lifespan = main_lifespan + subgiant_lifespan + giant_lifespan;
+
+    let age_range = get_age_range_in_star_lifecycle_dataset(
+        age,
+        main_lifespan,
+        subgiant_lifespan,
+        giant_lifespan,
+    );
+
+    let mut radius: f64;
+    let mut luminosity: f32;
+    let mut temperature: u32;
+    let mut spectral_type: StarSpectralType;
+    let luminosity_class: StarLuminosityClass;
+    mass = adjust_mass_to_population(mass, population);
+
+    if age_range > 6.0 {
+        // If remnant
+        mass = calculate_remnant_mass(mass, settings);
+
+        if mass < 1.4 {
+            // White dwarf
+            radius = calculate_white_dwarf_radius(mass);
+            let initial_luminosity = calculate_white_dwarf_initial_luminosity(mass);
+            let initial_temperature =
+                calculate_temperature_using_luminosity(initial_luminosity, radius as f64);
+            temperature = calculate_white_dwarf_temperature(initial_temperature, age);
+            luminosity = calculate_luminosity_using_temperature(temperature, radius as f64);
+            spectral_type =
+                generate_white_dwarf_spectral_type(star_index, system_index, coord, seed);
+            luminosity_class = StarLuminosityClass::VII;
+        } else if mass < 3.2 {
+            // Neutron star
+            let precise_radius = calculate_precise_radius_of_neutron_star_or_black_hole(mass);
+            temperature = calculate_neutron_star_temperature(age, full_lifespan);
+            luminosity = calculate_luminosity_using_temperature(temperature, precise_radius);
+            radius = precise_radius;
+            spectral_type = StarSpectralType::XNS;
+            luminosity_class = StarLuminosityClass::XNS;
+        } else {
+            // Black hole
+            let precise_radius = calculate_precise_radius_of_neutron_star_or_black_hole(mass);
+            temperature = 0;
+            luminosity = 0.0;
+            radius = precise_radius;
+            spectral_type = StarSpectralType::XBH;
+            luminosity_class = StarLuminosityClass::XBH;
+        }
+    } else {
+        // If main sequence, subgiant or giant
+        let mass_range = get_mass_range_in_star_lifecycle_dataset(mass);
+        let nearest_values = get_nearest_star_lifecycle_dataset_cells(age_range, mass_range);
 
-        let mut main_lifespan = calculate_lifespan(mass, ms_luminosity);
-        main_lifespan = adjust_lifespan_to_population(main_lifespan, population);
-        let subgiant_lifespan = calculate_subgiant_lifespan(mass, main_lifespan);
-        let giant_lifespan = calculate_giant_lifespan(mass, main_lifespan);
-        let full_lifespan = main_lifespan + subgiant_lifespan + giant_lifespan;
+        // Compute interpolated values
+        let interpolated_temperature = get_interpolated_temperature(
+            mass,
+            ms_temperature,
+            nearest_values,
+            age_range,
+            mass_range,
+        );
+        let interpolated_lum_factor =
+            get_interpolated_luminosity_factor(nearest_values, age_range, mass_range);
+        let interpolated_luminosity =
+            get_interpolated_luminosity(mass, ms_luminosity, interpolated_lum_factor);
+        let interpolated_radius = get_interpolated_radius(
+            mass,
+            ms_radius,
+            interpolated_luminosity,
+            interpolated_temperature,
+        );
 
-        let age_range = get_age_range_in_star_lifecycle_dataset(
+        // Then mix main sequence and interpolated values if applicable
+        radius = mix_values(ms_radius, interpolated_radius as f64, age, main_lifespan) as f64;
+        luminosity = mix_values(
+            ms_luminosity as f64,
+            interpolated_luminosity as f64,
+            age,
+            main_lifespan,
+        ) as f32;
+        temperature = mix_values(
+            ms_temperature as f64,
+            interpolated_temperature as f64,
+            age,
+            main_lifespan,
+        ) as u32;
+
+        spectral_type = calculate_spectral_type(temperature);
+        luminosity_class = calculate_luminosity_class(
+            luminosity,
+            spectral_type,
             age,
             main_lifespan,
             subgiant_lifespan,
-            giant_lifespan,
+            population,
         );
+    }
 
-        let mut radius: f64;
-        let mut luminosity: f32;
-        let temperature: u32;
-        let spectral_type: StarSpectralType;
-        let luminosity_class: StarLuminosityClass;
-        mass = adjust_mass_to_population(mass, population);
-
-        if age_range > 6.0 {
-            // If remnant
-            mass = calculate_remnant_mass(mass, settings);
-
-            if mass < 1.4 {
-                // White dwarf
-                radius = calculate_white_dwarf_radius(mass);
-                let initial_luminosity = calculate_white_dwarf_initial_luminosity(mass);
-                let initial_temperature =
-                    calculate_temperature_using_luminosity(initial_luminosity, radius as f64);
-                temperature = calculate_white_dwarf_temperature(initial_temperature, age);
-                luminosity = calculate_luminosity_using_temperature(temperature, radius as f64);
-                spectral_type =
-                    generate_white_dwarf_spectral_type(star_index, system_index, coord, &*seed);
-                luminosity_class = StarLuminosityClass::VII;
-            } else if mass < 3.2 {
-                // Neutron star
-                let precise_radius = calculate_precise_radius_of_neutron_star_or_black_hole(mass);
-                temperature = calculate_neutron_star_temperature(age, full_lifespan);
-                luminosity = calculate_luminosity_using_temperature(temperature, precise_radius);
-                radius = precise_radius;
-                spectral_type = StarSpectralType::XNS;
-                luminosity_class = StarLuminosityClass::XNS;
-            } else {
-                // Black hole
-                let precise_radius = calculate_precise_radius_of_neutron_star_or_black_hole(mass);
-                temperature = 0;
-                luminosity = 0.0;
-                radius = precise_radius;
-                spectral_type = StarSpectralType::XBH;
-                luminosity_class = StarLuminosityClass::XBH;
-            }
-        } else {
-            // If main sequence, subgiant or giant
-            let mass_range = get_mass_range_in_star_lifecycle_dataset(mass);
-            let nearest_values = get_nearest_star_lifecycle_dataset_cells(age_range, mass_range);
+    radius = adjust_radius_to_population(radius, population, luminosity_class);
+    luminosity = adjust_luminosity_to_population(luminosity, population);
+    if luminosity_class == StarLuminosityClass::VI {
+        // Shining as much from a smaller surface, a subdwarf is hotter than a main sequence star of the same mass
+        temperature = calculate_temperature_using_luminosity(luminosity, radius) as u32;
+        spectral_type = calculate_spectral_type(temperature);
+    }
 
-            // Compute interpolated values
-            let interpolated_temperature = get_interpolated_temperature(
-                mass,
-                ms_temperature,
-                nearest_values,
-                age_range,
-                mass_range,
-            );
-            let interpolated_lum_factor =
-                get_interpolated_luminosity_factor(nearest_values, age_range, mass_range);
-            let interpolated_luminosity =
-                get_interpolated_luminosity(mass, ms_luminosity, interpolated_lum_factor);
-            let interpolated_radius = get_interpolated_radius(
-                mass,
-                ms_radius,
-                interpolated_luminosity,
-                interpolated_temperature,
-            );
+    (
+        mass,
+        radius,
+        luminosity,
+        temperature,
+        spectral_type,
+        luminosity_class,
+    )
+}
 
-            // Then mix main sequence and interpolated values if applicable
-            radius = mix_values(ms_radius, interpolated_radius as f64, age, main_lifespan) as f64;
-            luminosity = mix_values(
-                ms_luminosity as f64,
-                interpolated_luminosity as f64,
-                age,
-                main_lifespan,
-            ) as f32;
-            temperature = mix_values(
-                ms_temperature as f64,
-                interpolated_temperature as f64,
-                age,
-                main_lifespan,
-            ) as u32;
+/// Searches for the current mass and the age in millions of years a star should have to match the given [FixedStar]. The search
+/// uses the same evolution model as the generator so that every value of the star stays consistent. The generated **age** is tried
+/// first, then ages spanning the whole life of the universe. For each age, the mass is found by bisection, as at a given age heavier
+/// stars are always further along their evolution. The closest star is returned along with its score, which is only zero if it
+/// matches exactly.
+fn find_mass_and_age(
+    fixed_star: FixedStar,
+    generated_mass: f64,
+    generated_age: f32,
+    population: StellarEvolution,
+    star_index: u16,
+    system_index: u16,
+    coord: SpaceCoordinates,
+    seed: &str,
+    galaxy: &Galaxy,
+    settings: &GenerationSettings,
+) -> (f64, f32, u32) {
+    let mut rng = SeededDiceRoller::new(
+        seed,
+        &format!("star_{}_{}_{}_fixed", coord, system_index, star_index),
+    );
+    let wanted_type = fixed_star.spectral_type.map(|spectral_type| {
+        if fixed_star.random_subtype {
+            spectral_type.with_subtype(rng.roll(1, 10, -1) as u8)
+        } else {
+            spectral_type
+        }
+    });
+    let wanted_stage = fixed_star
+        .luminosity_class
+        .map(get_evolution_stage)
+        .or_else(|| wanted_type.and_then(get_usual_evolution_stage));
+    let wanted_temperature = wanted_type.map(calculate_temperature_using_spectral_type);
+    let mut ages = vec![generated_age];
+    if settings.star.fixed_age.is_none() {
+        let universe_age = galaxy.neighborhood.universe.age * 1000.0;
+        ages.extend((0..=40).map(|i| universe_age.powf(i as f32 / 40.0)));
+    }
 
-            spectral_type = calculate_spectral_type(temperature);
-            luminosity_class = calculate_luminosity_class(
-                luminosity,
-                spectral_type,
+    let mut best = (generated_mass, generated_age);
+    let mut best_score = u32::MAX;
+    for age in ages {
+        let mut candidates: Vec<(u32, f64)> = Vec::new();
+        let mut evaluate = |mass: f64| -> (u32, u8) {
+            let (_, _, _, temperature, spectral_type, luminosity_class) = evolve_star(
+                mass,
                 age,
-                main_lifespan,
-                subgiant_lifespan,
+                population,
+                star_index,
+                system_index,
+                coord,
+                seed,
+                galaxy,
+                settings,
             );
+            let mut score = 0;
+            if fixed_star
+                .luminosity_class
+                .is_some_and(|wanted| wanted != luminosity_class)
+            {
+                score += 100;
+            }
+            if let Some(wanted) = wanted_type {
+                score += if discriminant(&wanted) != discriminant(&spectral_type) {
+                    50
+                } else {
+                    u32::from(
+                        wanted
+                            .get_subtype()
+                            .unwrap_or(0)
+                            .abs_diff(spectral_type.get_subtype().unwrap_or(0)),
+                    )
+                };
+            }
+            candidates.push((score, mass));
+            (temperature, get_evolution_stage(luminosity_class))
+        };
+
+        if settings.star.fixed_mass.is_some() {
+            evaluate(generated_mass);
+        } else {
+            // Bisects on the logarithm of the mass, from the lightest brown dwarf to the heaviest possible star.
+            let (mut low, mut high) = (BROWN_DWARF_MIN_MASS.ln(), 150.0_f64.ln());
+            if let Some(wanted_stage) = wanted_stage {
+                // First narrows the masses down to those at the wanted stage of their evolution.
+                let (mut lightest, mut heaviest) = (low, high);
+                for _ in 0..MASS_BISECTION_STEPS {
+                    let middle = (lightest + heaviest) / 2.0;
+                    if evaluate(middle.exp()).1 < wanted_stage {
+                        lightest = middle;
+                    } else {
+                        heaviest = middle;
+                    }
+                }
+                low = heaviest;
+                let (mut lightest, mut heaviest) = (low, high);
+                for _ in 0..MASS_BISECTION_STEPS {
+                    let middle = (lightest + heaviest) / 2.0;
+                    if evaluate(middle.exp()).1 > wanted_stage {
+                        heaviest = middle;
+                    } else {
+                        lightest = middle;
+                    }
+                }
+                high = lightest;
+            }
+            if let Some(wanted_temperature) = wanted_temperature {
+                // Then looks for the temperature, which can either rise or fall with the mass within a stage.
+                let is_rising = evaluate(low.exp()).0 <= evaluate(high.exp()).0;
+                for _ in 0..MASS_BISECTION_STEPS {
+                    let middle = (low + high) / 2.0;
+                    if (evaluate(middle.exp()).0 < wanted_temperature) == is_rising {
+                        low = middle;
+                    } else {
+                        high = middle;
+                    }
+                }
+            }
         }
 
-        radius = adjust_radius_to_population(radius, population);
-        luminosity = adjust_luminosity_to_population(luminosity, population);
+        if let Some(&(score, mass)) = candidates.iter().min_by_key(|(score, _)| *score) {
+            if score < best_score {
+                best = (mass, age);
+                best_score = score;
+                if score == 0 {
+                    break;
+                }
+            }
+        }
+    }
+    (best.0, best.1, best_score)
+}
 
-        let name = get_star_name(star_index, system_name.clone(), settings);
+/// Returns how far along its evolution a star of the given class is. At a given age, this only grows with the mass of the star.
+fn get_evolution_stage(luminosity_class: StarLuminosityClass) -> u8 {
+    match luminosity_class {
+        StarLuminosityClass::Y => 0,
+        StarLuminosityClass::V | StarLuminosityClass::VI => 1,
+        StarLuminosityClass::IV => 2,
+        StarLuminosityClass::III => 3,
+        StarLuminosityClass::II => 4,
+        StarLuminosityClass::Ib => 5,
+        StarLuminosityClass::Ia => 6,
+        StarLuminosityClass::O => 7,
+        StarLuminosityClass::VII | StarLuminosityClass::XNS | StarLuminosityClass::XBH => 8,
+    }
+}
 
-        // TODO: Did you add the special traits or is it just an empty array?
-        let mut special_traits = Vec::new();
-        if population == StellarEvolution::Paleodwarf {
-            special_traits.push(StarPeculiarity::NoMetals);
+/// Returns how far along its evolution a star of the given type usually is, or [None] if it can't be told from its type alone.
+fn get_usual_evolution_stage(spectral_type: StarSpectralType) -> Option<u8> {
+    match spectral_type {
+        StarSpectralType::WR(_) => None,
+        StarSpectralType::L(_) | StarSpectralType::T(_) | StarSpectralType::Y(_) => {
+            Some(get_evolution_stage(StarLuminosityClass::Y))
         }
+        StarSpectralType::DA
+        | StarSpectralType::DB
+        | StarSpectralType::DC
+        | StarSpectralType::DO
+        | StarSpectralType::DZ
+        | StarSpectralType::DQ
+        | StarSpectralType::DX
+        | StarSpectralType::XNS
+        | StarSpectralType::XBH => Some(get_evolution_stage(StarLuminosityClass::VII)),
+        _ => Some(get_evolution_stage(StarLuminosityClass::V)),
+    }
+}
 
-        // TODO: Still have to take into account metallicity
-        let elements_abundance: Vec<ChemicalComponent> = {
-            let mut rng = SeededDiceRoller::new(
-                &settings.seed,
-                &format!("sys_{}_{}_{}_elem_abnd", coord, system_index, star_index),
-            );
-            let mut elements = Vec::new();
-            let mut roll = rng.gen_u8();
-
-            let random_element_abundance_threshold = 156;
-            let non_metal_threshold = match population {
-                StellarEvolution::Paleodwarf => 0,
-                StellarEvolution::Subdwarf => 126,
-                StellarEvolution::Dwarf => 240,
-                StellarEvolution::Superdwarf => 250,
-                StellarEvolution::Hyperdwarf => 255,
-            };
-            let common_threshold = match population {
-                StellarEvolution::Paleodwarf => 0,
-                StellarEvolution::Subdwarf => 56,
-                StellarEvolution::Dwarf => 126,
-                StellarEvolution::Superdwarf => 156,
-                StellarEvolution::Hyperdwarf => 180,
-            };
+/// Estimates the mass of a star whose mass is unknown, using its luminosity and type.
+fn estimate_mass(
+    luminosity: f32,
+    spectral_type: StarSpectralType,
+    luminosity_class: StarLuminosityClass,
+) -> f64 {
+    match (spectral_type, luminosity_class) {
+        (
+            StarSpectralType::DA
+            | StarSpectralType::DB
+            | StarSpectralType::DC
+            | StarSpectralType::DO
+            | StarSpectralType::DZ
+            | StarSpectralType::DQ
+            | StarSpectralType::DX,
+            _,
+        ) => 0.6,
+        (StarSpectralType::L(_) | StarSpectralType::T(_) | StarSpectralType::Y(_), _) => 0.05,
+        (StarSpectralType::XNS, _) => 1.4,
+        (StarSpectralType::XBH, _) => 10.0,
+        (
+            _,
+            StarLuminosityClass::O
+            | StarLuminosityClass::Ia
+            | StarLuminosityClass::Ib
+            | StarLuminosityClass::II
+            | StarLuminosityClass::III,
+        ) => (luminosity as f64).powf(0.25).clamp(0.8, 60.0),
+        _ => (luminosity as f64).powf(1.0 / 3.5).clamp(0.08, 150.0),
+    }
+}
+
+/// Returns the given spectral type as a number, from 10 for O0 stars to 109 for Y9 ones, or [None] for the types that have no
+/// subtypes.
+fn get_spectral_type_as_int(spectral_type: StarSpectralType) -> Option<u32> {
+    match spectral_type {
+        StarSpectralType::O(d) => Some(10 + d as u32),
+        StarSpectralType::B(d) => Some(20 + d as u32),
+        StarSpectralType::A(d) => Some(30 + d as u32),
+        StarSpectralType::F(d) => Some(40 + d as u32),
+        StarSpectralType::G(d) => Some(50 + d as u32),
+        StarSpectralType::K(d) => Some(60 + d as u32),
+        StarSpectralType::M(d) => Some(70 + d as u32),
+        StarSpectralType::L(d) => Some(80 + d as u32),
+        StarSpectralType::T(d) => Some(90 + d as u32),
+        StarSpectralType::Y(d) => Some(100 + d as u32),
+        _ => None,
+    }
+}
+
+/// Returns the typical temperature of a star of the given spectral type, interpolated from the temperatures dataset.
+fn calculate_temperature_using_spectral_type(spectral_type: StarSpectralType) -> u32 {
+    let Some(class_as_int) = get_spectral_type_as_int(spectral_type) else {
+        return match spectral_type {
+            StarSpectralType::WR(_) => 100000,
+            StarSpectralType::XNS => 600000,
+            StarSpectralType::XBH => 0,
+            _ => 10000,
+        };
+    };
+    TEMPERATURE_TO_SPECTRAL_TYPE_DATASET
+        .windows(2)
+        .skip(1)
+        .find(|pair| pair[0].1 <= class_as_int && class_as_int <= pair[1].1)
+        .map_or(54000, |pair| {
+            let (upper_temp, lower_class) = pair[0];
+            let (lower_temp, upper_class) = pair[1];
+            (upper_temp as f32
+                - (class_as_int - lower_class) as f32 * (upper_temp - lower_temp) as f32
+                    / (upper_class - lower_class) as f32) as u32
+        })
+}
+
+/// Returns the bolometric correction of a star of the given spectral type, the magnitude to add to its absolute visual magnitude to
+/// get its absolute bolometric magnitude, interpolated from the bolometric corrections dataset. Cool and very hot stars emit most of
+/// their light outside of the visible spectrum.
+pub(crate) fn get_bolometric_correction(spectral_type: StarSpectralType) -> f32 {
+    let Some(class_as_int) = get_spectral_type_as_int(spectral_type) else {
+        return match spectral_type {
+            StarSpectralType::WR(_) => -4.5,
+            StarSpectralType::XNS | StarSpectralType::XBH => 0.0,
+            // White dwarfs
+            _ => -0.5,
+        };
+    };
+    let dataset = BOLOMETRIC_CORRECTION_TO_SPECTRAL_TYPE_DATASET;
+    if class_as_int <= dataset[0].1 {
+        return dataset[0].0;
+    }
+    dataset
+        .windows(2)
+        .find(|pair| pair[0].1 <= class_as_int && class_as_int <= pair[1].1)
+        .map_or(dataset[dataset.len() - 1].0, |pair| {
+            let (upper_correction, lower_class) = pair[0];
+            let (lower_correction, upper_class) = pair[1];
+            upper_correction
+                + (class_as_int - lower_class) as f32 * (lower_correction - upper_correction)
+                    / (upper_class - lower_class) as f32
+        })
+}
+
+/// Generates the peculiarities of a star, like its activity, variability or the unusual abundance or lack of some elements. The
+/// given **age** is in billions of years.
+fn generate_special_traits(
+    star_index: u16,
+    system_index: u16,
+    coord: SpaceCoordinates,
+    population: StellarEvolution,
+    spectral_type: StarSpectralType,
+    luminosity_class: StarLuminosityClass,
+    age: f32,
+    seed: &str,
+    settings: &GenerationSettings,
+) -> Vec<StarPeculiarity> {
+    let mut special_traits = generate_activity_traits(
+        star_index,
+        system_index,
+        coord,
+        population,
+        spectral_type,
+        luminosity_class,
+        age,
+        seed,
+    );
+    if population == StellarEvolution::Paleodwarf {
+        special_traits.push(StarPeculiarity::NoMetals);
+    }
+
+    // TODO: Still have to take into account metallicity
+    let elements_abundance: Vec<ChemicalComponent> = {
+        let mut rng = SeededDiceRoller::new(
+            &settings.seed,
+            &format!("sys_{}_{}_{}_elem_abnd", coord, system_index, star_index),
+        );
+        let mut elements = Vec::new();
+        let mut roll = rng.gen_u8();
+
+        let random_element_abundance_threshold = 156;
+        let non_metal_threshold = match population {
+            StellarEvolution::Paleodwarf => 0,
+            StellarEvolution::Subdwarf => 126,
+            StellarEvolution::Dwarf => 240,
+            StellarEvolution::Superdwarf => 250,
+            StellarEvolution::Hyperdwarf => 255,
+        };
+        let common_threshold = match population {
+            StellarEvolution::Paleodwarf => 0,
+            StellarEvolution::Subdwarf => 56,
+            StellarEvolution::Dwarf => 126,
+            StellarEvolution::Superdwarf => 156,
+            StellarEvolution::Hyperdwarf => 180,
+        };
 
-            while roll >= random_element_abundance_threshold {
+        while roll >= random_element_abundance_threshold {
+            let specific_roll = rng.gen_u8();
+            if specific_roll >= non_metal_threshold {
+                let el = generate_random_non_metal_element(&mut rng);
+                if !elements.contains(&el) {
+                    elements.push(el);
+                }
+            } else if specific_roll >= common_threshold {
+                let el = generate_random_element(&mut rng);
+                if !elements.contains(&el) {
+                    elements.push(el);
+                }
+            } else {
+                let el = generate_random_common_element(&mut rng);
+                if !elements.contains(&el) {
+                    elements.push(el);
+                }
+            }
+            roll = rng.gen_u8();
+        }
+        elements
+    };
+    let elements_lack: Vec<ChemicalComponent> = {
+        let mut rng = SeededDiceRoller::new(
+            seed,
+            &format!("sys_{}_{}_{}_elem_lack", coord, system_index, star_index),
+        );
+        let mut elements = Vec::new();
+        let mut roll = rng.gen_u8();
+
+        let random_element_lack_threshold = 156;
+        let non_metal_threshold = match population {
+            StellarEvolution::Paleodwarf => 0,
+            StellarEvolution::Subdwarf => 236,
+            StellarEvolution::Dwarf => 200,
+            StellarEvolution::Superdwarf => 156,
+            StellarEvolution::Hyperdwarf => 100,
+        };
+        let common_threshold = match population {
+            StellarEvolution::Paleodwarf => 0,
+            StellarEvolution::Subdwarf => 56,
+            StellarEvolution::Dwarf => 126,
+            StellarEvolution::Superdwarf => 156,
+            StellarEvolution::Hyperdwarf => 180,
+        };
+
+        if population != StellarEvolution::Paleodwarf {
+            while roll >= random_element_lack_threshold {
                 let specific_roll = rng.gen_u8();
                 if specific_roll >= non_metal_threshold {
                     let el = generate_random_non_metal_element(&mut rng);
@@ -206,118 +754,241 @@ impl Star {
                         elements.push(el);
                     }
                 }
-                roll = rng.gen_u8();
+                roll = rng.gen_u8()
             }
-            elements
-        };
-        let elements_lack: Vec<ChemicalComponent> = {
-            let mut rng = SeededDiceRoller::new(
-                &*seed,
-                &format!("sys_{}_{}_{}_elem_lack", coord, system_index, star_index),
-            );
-            let mut elements = Vec::new();
-            let mut roll = rng.gen_u8();
-
-            let random_element_lack_threshold = 156;
-            let non_metal_threshold = match population {
-                StellarEvolution::Paleodwarf => 0,
-                StellarEvolution::Subdwarf => 236,
-                StellarEvolution::Dwarf => 200,
-                StellarEvolution::Superdwarf => 156,
-                StellarEvolution::Hyperdwarf => 100,
-            };
-            let common_threshold = match population {
-                StellarEvolution::Paleodwarf => 0,
-                StellarEvolution::Subdwarf => 56,
-                StellarEvolution::Dwarf => 126,
-                StellarEvolution::Superdwarf => 156,
-                StellarEvolution::Hyperdwarf => 180,
-            };
+        }
+        elements
+    };
+    let mut rng = SeededDiceRoller::new(
+        seed,
+        &format!("sys_{}_{}_{}_elem_comp", coord, system_index, star_index),
+    );
 
-            if population != StellarEvolution::Paleodwarf {
-                while roll >= random_element_lack_threshold {
-                    let specific_roll = rng.gen_u8();
-                    if specific_roll >= non_metal_threshold {
-                        let el = generate_random_non_metal_element(&mut rng);
-                        if !elements.contains(&el) {
-                            elements.push(el);
-                        }
-                    } else if specific_roll >= common_threshold {
-                        let el = generate_random_element(&mut rng);
-                        if !elements.contains(&el) {
-                            elements.push(el);
-                        }
-                    } else {
-                        let el = generate_random_common_element(&mut rng);
-                        if !elements.contains(&el) {
-                            elements.push(el);
-                        }
-                    }
-                    roll = rng.gen_u8()
-                }
-            }
-            elements
-        };
-        let mut rng = SeededDiceRoller::new(
-            &*seed,
-            &format!("sys_{}_{}_{}_elem_comp", coord, system_index, star_index),
-        );
+    elements_abundance.iter().for_each(|el| {
+        let roll = rng.roll(1, 12, 0);
+        if roll <= 7 {
+            special_traits.push(StarPeculiarity::UnusualElementPresence((
+                *el,
+                ElementPresenceOccurrence::High,
+            )));
+        } else if roll <= 11 {
+            special_traits.push(StarPeculiarity::UnusualElementPresence((
+                *el,
+                ElementPresenceOccurrence::VeryHigh,
+            )));
+        } else {
+            special_traits.push(StarPeculiarity::UnusualElementPresence((
+                *el,
+                ElementPresenceOccurrence::Omnipresence,
+            )));
+        }
+    });
+    elements_lack.iter().for_each(|el| {
+        let roll = rng.roll(1, 12, 0);
+        if roll <= 7 {
+            special_traits.push(StarPeculiarity::UnusualElementPresence((
+                *el,
+                ElementPresenceOccurrence::Low,
+            )));
+        } else if roll <= 11 {
+            special_traits.push(StarPeculiarity::UnusualElementPresence((
+                *el,
+                ElementPresenceOccurrence::VeryLow,
+            )));
+        } else {
+            special_traits.push(StarPeculiarity::UnusualElementPresence((
+                *el,
+                ElementPresenceOccurrence::Absence,
+            )));
+        }
+    });
 
-        elements_abundance.iter().for_each(|el| {
-            let roll = rng.roll(1, 12, 0);
-            if roll <= 7 {
-                special_traits.push(StarPeculiarity::UnusualElementPresence((
-                    *el,
-                    ElementPresenceOccurrence::High,
-                )));
-            } else if roll <= 11 {
-                special_traits.push(StarPeculiarity::UnusualElementPresence((
-                    *el,
-                    ElementPresenceOccurrence::VeryHigh,
-                )));
-            } else {
-                special_traits.push(StarPeculiarity::UnusualElementPresence((
-                    *el,
-                    ElementPresenceOccurrence::Omnipresence,
-                )));
-            }
-        });
-        elements_lack.iter().for_each(|el| {
-            let roll = rng.roll(1, 12, 0);
-            if roll <= 7 {
-                special_traits.push(StarPeculiarity::UnusualElementPresence((
-                    *el,
-                    ElementPresenceOccurrence::Low,
-                )));
-            } else if roll <= 11 {
-                special_traits.push(StarPeculiarity::UnusualElementPresence((
-                    *el,
-                    ElementPresenceOccurrence::VeryLow,
-                )));
-            } else {
-                special_traits.push(StarPeculiarity::UnusualElementPresence((
-                    *el,
-                    ElementPresenceOccurrence::Absence,
-                )));
-            }
-        });
+    special_traits
+}
 
-        Self {
-            name,
-            mass,
-            luminosity,
-            radius,
-            age: age / 1000.0,
-            temperature,
-            population,
-            spectral_type,
-            luminosity_class,
-            special_traits,
-            orbital_point_id: star_index as u32,
-            orbit: None,
-            zones: vec![],
-        }
+/// Generates the peculiarities of a star that come from its activity, its rotation, its variability or its formation. Massive and
+/// evolved stars are the most active ones, as are young red dwarfs, and only young stars still have their circumstellar disk. The
+/// given **age** is in billions of years.
+fn generate_activity_traits(
+    star_index: u16,
+    system_index: u16,
+    coord: SpaceCoordinates,
+    population: StellarEvolution,
+    spectral_type: StarSpectralType,
+    luminosity_class: StarLuminosityClass,
+    age: f32,
+    seed: &str,
+) -> Vec<StarPeculiarity> {
+    let mut rng = SeededDiceRoller::new(
+        seed,
+        &format!("sys_{}_{}_{}_pec", coord, system_index, star_index),
+    );
+    let mut special_traits = Vec::new();
+    let is_massive = matches!(
+        spectral_type,
+        StarSpectralType::WR(_) | StarSpectralType::O(_) | StarSpectralType::B(_)
+    );
+    let is_evolved = matches!(
+        luminosity_class,
+        StarLuminosityClass::O
+            | StarLuminosityClass::Ia
+            | StarLuminosityClass::Ib
+            | StarLuminosityClass::II
+            | StarLuminosityClass::III
+    );
+    let is_red_dwarf = matches!(spectral_type, StarSpectralType::M(_));
+    let is_young = age < 1.0;
+
+    let chaotic_orbits_chances = if age < 0.1 { 40 } else { 10 };
+    if rng.roll(1, 1000, 0) <= chaotic_orbits_chances {
+        special_traits.push(StarPeculiarity::ChaoticOrbits);
+    }
+
+    let radiation_chances = if luminosity_class == StarLuminosityClass::XNS {
+        500
+    } else if is_massive {
+        300
+    } else if matches!(spectral_type, StarSpectralType::A(_)) {
+        80
+    } else if is_red_dwarf && is_young {
+        100
+    } else {
+        10
+    };
+    if rng.roll(1, 1000, 0) <= radiation_chances {
+        special_traits.push(StarPeculiarity::ExcessiveRadiation);
+    }
+
+    if rng.roll(1, 1000, 0) <= 40 {
+        // Old populations are more likely to host stars that seem older than their neighbors, and the other way around
+        let modifier = match population {
+            StellarEvolution::Paleodwarf => -2,
+            StellarEvolution::Subdwarf => -1,
+            StellarEvolution::Dwarf => 0,
+            StellarEvolution::Superdwarf => 1,
+            StellarEvolution::Hyperdwarf => 2,
+        } + i32::from(is_young);
+        let roll = rng.roll(1, 8, modifier);
+        special_traits.push(StarPeculiarity::AgeDifference(if roll <= 1 {
+            StarAgeDifference::MuchOlder
+        } else if roll <= 4 {
+            StarAgeDifference::Older
+        } else if roll <= 7 {
+            StarAgeDifference::Younger
+        } else {
+            StarAgeDifference::MuchYounger
+        }));
+    }
+
+    if rng.roll(1, 1000, 0) <= 30 {
+        // Stars slow down as they age
+        let roll = rng.roll(1, 8, if is_young { 2 } else { -1 });
+        special_traits.push(StarPeculiarity::RotationAnomaly(if roll <= 1 {
+            RotationAnomalySpeed::MuchSlower
+        } else if roll <= 4 {
+            RotationAnomalySpeed::Slower
+        } else if roll <= 7 {
+            RotationAnomalySpeed::Faster
+        } else {
+            RotationAnomalySpeed::MuchFaster
+        }));
+    }
+
+    let winds_chances = if matches!(spectral_type, StarSpectralType::WR(_)) {
+        1000
+    } else if matches!(spectral_type, StarSpectralType::O(_)) {
+        600
+    } else if is_evolved {
+        300
+    } else if matches!(spectral_type, StarSpectralType::B(_)) {
+        250
+    } else {
+        10
+    };
+    if rng.roll(1, 1000, 0) <= winds_chances {
+        special_traits.push(StarPeculiarity::PowerfulStellarWinds);
+    }
+
+    let magnetic_field_chances = if luminosity_class == StarLuminosityClass::XNS {
+        1000
+    } else if is_red_dwarf && is_young {
+        150
+    } else if matches!(spectral_type, StarSpectralType::A(_)) {
+        100
+    } else {
+        20
+    };
+    if rng.roll(1, 1000, 0) <= magnetic_field_chances {
+        special_traits.push(StarPeculiarity::StrongMagneticField);
+    }
+
+    // Evolved stars pulsate slowly, red dwarfs flare often and hot stars vary quickly
+    let (variable_chances, min_interval, max_interval) = if is_evolved {
+        (250, 2, 6)
+    } else if is_red_dwarf {
+        (100, 0, 2)
+    } else if matches!(
+        spectral_type,
+        StarSpectralType::A(_) | StarSpectralType::F(_)
+    ) || is_massive
+    {
+        (50, 1, 3)
+    } else {
+        (20, 0, 6)
+    };
+    if rng.roll(1, 1000, 0) <= variable_chances {
+        let intervals = [
+            VariableStarInterval::Minutes,
+            VariableStarInterval::Hours,
+            VariableStarInterval::Days,
+            VariableStarInterval::Months,
+            VariableStarInterval::Years,
+            VariableStarInterval::Decades,
+            VariableStarInterval::Centuries,
+        ];
+        let index = rng.roll(1, max_interval - min_interval + 1, min_interval as i32 - 1);
+        special_traits.push(StarPeculiarity::VariableStar(intervals[index as usize]));
     }
+
+    let disk_chances = if population == StellarEvolution::Paleodwarf {
+        0
+    } else if age < 0.01 {
+        500
+    } else if age < 0.1 {
+        150
+    } else {
+        10
+    };
+    if rng.roll(1, 1000, 0) <= disk_chances {
+        special_traits.push(StarPeculiarity::CircumstellarDisk);
+    }
+
+    special_traits
+}
+
+/// Returns the metallicity of a star, using the one of its neighborhood and adjusting it to the star's population.
+fn generate_metallicity(
+    star_index: u16,
+    system_index: u16,
+    coord: SpaceCoordinates,
+    population: StellarEvolution,
+    hex: &GalacticHex,
+    seed: &str,
+) -> f32 {
+    let mut rng = SeededDiceRoller::new(
+        seed,
+        &format!("sys_{}_{}_{}_met", coord, system_index, star_index),
+    );
+    let population_modifier = match population {
+        StellarEvolution::Paleodwarf => -3.0,
+        StellarEvolution::Subdwarf => -0.8,
+        StellarEvolution::Dwarf => 0.0,
+        StellarEvolution::Superdwarf => 0.2,
+        StellarEvolution::Hyperdwarf => 0.4,
+    };
+    let metallicity =
+        hex.neighborhood.metallicity + population_modifier + rng.gen_range(-0.2..0.2) as f32;
+    (metallicity * 100.0).round().clamp(-500.0, 100.0) / 100.0
 }
 
 /// Returns the name of the star by combining its index and the system name.
@@ -527,8 +1198,17 @@ fn adjust_lifespan_to_population(lifespan: f32, population: StellarEvolution) ->
     }
 }
 
-fn adjust_radius_to_population(radius: f64, population: StellarEvolution) -> f64 {
+/// Metal-poor subdwarfs of class VI are smaller than their main sequence counterparts, and the poorer the smaller.
+fn adjust_radius_to_population(
+    radius: f64,
+    population: StellarEvolution,
+    luminosity_class: StarLuminosityClass,
+) -> f64 {
     match population {
+        StellarEvolution::Subdwarf if luminosity_class == StarLuminosityClass::VI => radius * 0.7,
+        StellarEvolution::Paleodwarf if luminosity_class == StarLuminosityClass::VI => {
+            radius * 0.45
+        }
         StellarEvolution::Hyperdwarf => radius * 1.5,
         StellarEvolution::Superdwarf => radius * 1.25,
         StellarEvolution::Subdwarf => radius * 0.75,
@@ -537,6 +1217,8 @@ fn adjust_radius_to_population(radius: f64, population: StellarEvolution) -> f64
     }
 }
 
+/// Metal-poor stars are brighter than metal-rich ones of the same mass. As they also are hotter, subdwarfs of class VI still sit below
+/// the main sequence, being dimmer than main sequence stars of the same spectral type.
 fn adjust_luminosity_to_population(luminosity: f32, population: StellarEvolution) -> f32 {
     match population {
         StellarEvolution::Hyperdwarf => luminosity * 0.5,
@@ -832,6 +1514,7 @@ fn calculate_luminosity_class(
     age: f32,
     main_lifespan: f32,
     subgiant_lifespan: f32,
+    population: StellarEvolution,
 ) -> StarLuminosityClass {
     match spectral_type {
         StarSpectralType::L(_) | StarSpectralType::T(_) | StarSpectralType::Y(_) => {
@@ -855,7 +1538,11 @@ fn calculate_luminosity_class(
         _ => (),
     }
     return if age <= main_lifespan {
-        StarLuminosityClass::V
+        if population == StellarEvolution::Subdwarf || population == StellarEvolution::Paleodwarf {
+            StarLuminosityClass::VI
+        } else {
+            StarLuminosityClass::V
+        }
     } else if age <= subgiant_lifespan {
         StarLuminosityClass::IV
     } else {
@@ -1012,6 +1699,104 @@ fn mix_values(a: f64, b: f64, age: f32, main_lifespan: f32) -> f64 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn generate_activity_traits_by_type_and_age() {
+        let coord = SpaceCoordinates::new(0, 0, 0);
+        let mut young_disks = 0;
+        let mut old_disks = 0;
+        for i in 0..500 {
+            let wolf_rayet = generate_activity_traits(
+                i,
+                0,
+                coord,
+                StellarEvolution::Dwarf,
+                StarSpectralType::WR(5),
+                StarLuminosityClass::Ia,
+                0.005,
+                "default",
+            );
+            assert!(wolf_rayet.contains(&StarPeculiarity::PowerfulStellarWinds));
+            let neutron_star = generate_activity_traits(
+                i,
+                1,
+                coord,
+                StellarEvolution::Dwarf,
+                StarSpectralType::XNS,
+                StarLuminosityClass::XNS,
+                1.0,
+                "default",
+            );
+            assert!(neutron_star.contains(&StarPeculiarity::StrongMagneticField));
+
+            for (age, disks) in [(0.005, &mut young_disks), (5.0, &mut old_disks)] {
+                if generate_activity_traits(
+                    i,
+                    2,
+                    coord,
+                    StellarEvolution::Dwarf,
+                    StarSpectralType::G(2),
+                    StarLuminosityClass::V,
+                    age,
+                    "default",
+                )
+                .contains(&StarPeculiarity::CircumstellarDisk)
+                {
+                    *disks += 1;
+                }
+            }
+        }
+        assert!(young_disks > old_disks * 5);
+    }
+
+    #[test]
+    fn evolve_metal_poor_main_sequence_stars_into_subdwarfs() {
+        let coord = SpaceCoordinates::new(0, 0, 0);
+        let settings = &GenerationSettings {
+            ..Default::default()
+        };
+        let galaxy = &Galaxy {
+            ..Default::default()
+        };
+        let evolve_dwarf = |mass: f64| {
+            evolve_star(
+                mass,
+                1000.0,
+                StellarEvolution::Dwarf,
+                0,
+                0,
+                coord,
+                "default",
+                galaxy,
+                settings,
+            )
+        };
+        for mass in [0.2, 0.5, 0.8] {
+            let (_, dwarf_radius, dwarf_luminosity, dwarf_temperature, _, dwarf_class) =
+                evolve_dwarf(mass);
+            assert_eq!(dwarf_class, StarLuminosityClass::V);
+            for population in [StellarEvolution::Subdwarf, StellarEvolution::Paleodwarf] {
+                let (_, radius, luminosity, temperature, spectral_type, luminosity_class) =
+                    evolve_star(
+                        mass, 1000.0, population, 0, 0, coord, "default", galaxy, settings,
+                    );
+                assert_eq!(luminosity_class, StarLuminosityClass::VI);
+                assert!(radius < dwarf_radius);
+                assert!(luminosity > dwarf_luminosity);
+                assert!(temperature > dwarf_temperature);
+                assert_eq!(spectral_type, calculate_spectral_type(temperature));
+                let stefan_boltzmann_luminosity =
+                    calculate_luminosity_using_temperature(temperature, radius);
+                assert!((stefan_boltzmann_luminosity / luminosity - 1.0).abs() < 0.05);
+
+                let (_, _, same_type_dwarf_luminosity, _, _, _) = (1..=300)
+                    .map(|step| evolve_dwarf(step as f64 / 100.0))
+                    .find(|dwarf| dwarf.4 == spectral_type)
+                    .expect("Should find a main sequence star of the same spectral type.");
+                assert!(luminosity < same_type_dwarf_luminosity);
+            }
+        }
+    }
+
     #[test]
     fn generate_main_sequence_values_approaching_reality() {
         let mut n = 0;
@@ -1061,6 +1846,7 @@ mod tests {
                         star.age,
                         main_lifespan,
                         subgiant_lifespan,
+                        star.population,
                     ),
                     star.age,
                 );
@@ -1167,6 +1953,7 @@ mod tests {
                             star.age,
                             main_lifespan,
                             subgiant_lifespan,
+                            star.population,
                         ),
                         age * 1000.0,
                     );
@@ -1231,6 +2018,7 @@ mod tests {
                         star.age,
                         main_lifespan,
                         subgiant_lifespan,
+                        star.population,
                     ),
                     star.age,
                 );
@@ -1286,7 +2074,8 @@ mod tests {
                     hex,
                     galaxy,
                     &settings,
-                );
+                )
+                .expect("Should generate a star.");
                 generated_star.name = star.name.clone();
 
                 print_real_to_generated_star_comparison(
@@ -1400,7 +2189,8 @@ mod tests {
                 &hex,
                 &galaxy,
                 &settings,
-            );
+            )
+            .expect("Should generate a star.");
 
             generated.push(generated_star);
         }
@@ -1437,7 +2227,7 @@ mod tests {
                 i as u16,
                 i as u16 + 1,
                 coord,
-                &GalacticHex::generate(coord, coord, &mut galaxy),
+                &GalacticHex::generate(coord, coord, &mut galaxy).expect("Should generate a hex."),
                 &galaxy.settings.seed,
                 &galaxy.neighborhood.universe,
             ) / 1000.0;
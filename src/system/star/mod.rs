@@ -20,6 +20,8 @@ pub struct Star {
     pub temperature: u32,
     /// The population this star belongs to.
     pub population: StellarEvolution,
+    /// The abundance of elements heavier than helium in this star, expressed as [Fe/H] in dex. 0 is the Sun's metallicity.
+    pub metallicity: f32,
     /// Spectral type.
     pub spectral_type: StarSpectralType,
     /// Luminosity class.
@@ -43,6 +45,7 @@ impl Star {
         age: f32,
         temperature: u32,
         population: StellarEvolution,
+        metallicity: f32,
         spectral_type: StarSpectralType,
         luminosity_class: StarLuminosityClass,
         special_traits: Vec<StarPeculiarity>,
@@ -58,6 +61,7 @@ impl Star {
             age,
             temperature,
             population,
+            metallicity,
             spectral_type,
             luminosity_class,
             special_traits,
@@ -70,6 +74,7 @@ impl Star {
     /// Returns true if the star is currently in the main sequence phase of its life.
     pub fn is_main_sequence_dwarf(&self) -> bool {
         (self.luminosity_class == StarLuminosityClass::V
+            || self.luminosity_class == StarLuminosityClass::VI
             || self.luminosity_class == StarLuminosityClass::IV)
             && self.is_more_luminous_than_brown_dwarf()
     }
@@ -83,6 +88,7 @@ impl Star {
             || self.luminosity_class == StarLuminosityClass::III
             || self.luminosity_class == StarLuminosityClass::IV
             || self.luminosity_class == StarLuminosityClass::V
+            || self.luminosity_class == StarLuminosityClass::VI
             || self.luminosity_class == StarLuminosityClass::IV)
             && self.is_more_luminous_than_brown_dwarf()
     }
@@ -56,6 +56,26 @@ pub struct StarSettings {
     /// The likelihood of violet stars of bigger size to be generated in star generation.
     #[default = 1]
     pub violet_star_three_gen_chance: u32,
+    /// The specific number of stars of every generated system, if any.
+    pub fixed_number_of_stars: Option<u16>,
+    /// The characteristics to give to the stars of every generated system, the first one describing the first star, and so on.
+    /// Systems have at least as many stars as this list has entries.
+    pub fixed_stars: Vec<FixedStar>,
+}
+
+/// The characteristics to give to a specific [Star] during generation. Its mass and age are chosen so that it matches them, while
+/// whatever is left out is generated as usual.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct FixedStar {
+    /// The spectral type of the star, if any.
+    pub spectral_type: Option<StarSpectralType>,
+    /// Only keeps the letter of the spectral type and generates its subtype, so that [StarSpectralType::K] with any number asks for
+    /// any K-type star.
+    pub random_subtype: bool,
+    /// The luminosity class of the star, if any.
+    pub luminosity_class: Option<StarLuminosityClass>,
+    /// The population of the star, if any.
+    pub population: Option<StellarEvolution>,
 }
 
 #[derive(Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
@@ -284,6 +304,44 @@ pub enum StarSpectralType {
     XBH,
 }
 
+impl StarSpectralType {
+    /// Returns the numeric subtype of this spectral type, or [None] for the types that have no subtype.
+    pub fn get_subtype(&self) -> Option<u8> {
+        match self {
+            StarSpectralType::WR(d)
+            | StarSpectralType::O(d)
+            | StarSpectralType::B(d)
+            | StarSpectralType::A(d)
+            | StarSpectralType::F(d)
+            | StarSpectralType::G(d)
+            | StarSpectralType::K(d)
+            | StarSpectralType::M(d)
+            | StarSpectralType::L(d)
+            | StarSpectralType::T(d)
+            | StarSpectralType::Y(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    /// Returns the same spectral type with the given **subtype**, if the type has subtypes.
+    pub fn with_subtype(&self, subtype: u8) -> Self {
+        match self {
+            StarSpectralType::WR(_) => StarSpectralType::WR(subtype),
+            StarSpectralType::O(_) => StarSpectralType::O(subtype),
+            StarSpectralType::B(_) => StarSpectralType::B(subtype),
+            StarSpectralType::A(_) => StarSpectralType::A(subtype),
+            StarSpectralType::F(_) => StarSpectralType::F(subtype),
+            StarSpectralType::G(_) => StarSpectralType::G(subtype),
+            StarSpectralType::K(_) => StarSpectralType::K(subtype),
+            StarSpectralType::M(_) => StarSpectralType::M(subtype),
+            StarSpectralType::L(_) => StarSpectralType::L(subtype),
+            StarSpectralType::T(_) => StarSpectralType::T(subtype),
+            StarSpectralType::Y(_) => StarSpectralType::Y(subtype),
+            _ => *self,
+        }
+    }
+}
+
 impl Display for StarSpectralType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,347 @@
+use crate::internal::*;
+use crate::prelude::*;
+use crate::system::contents::generator::generate_body_from_type;
+
+/// How many times a body is generated at each candidate distance before trying the next one.
+const BODY_TRIES_PER_DISTANCE: u32 = 8;
+
+impl SystemTemplate {
+    /// Returns the given settings with the main star of this template as their first fixed star, so that the first star of every
+    /// generated system is of the wanted type and class.
+    pub(crate) fn get_generation_settings(
+        &self,
+        settings: &GenerationSettings,
+    ) -> GenerationSettings {
+        let mut settings = settings.clone();
+        if self.main_star_spectral_type.is_some() || self.main_star_luminosity_class.is_some() {
+            let main_star = FixedStar {
+                spectral_type: self.main_star_spectral_type,
+                random_subtype: true,
+                luminosity_class: self.main_star_luminosity_class,
+                population: settings
+                    .star
+                    .fixed_stars
+                    .first()
+                    .and_then(|fixed_star| fixed_star.population),
+            };
+            if settings.star.fixed_stars.is_empty() {
+                settings.star.fixed_stars.push(main_star);
+            } else {
+                settings.star.fixed_stars[0] = main_star;
+            }
+        }
+        settings
+    }
+
+    /// Places around the main star of a freshly generated system every body of this template that the system lacks. Each missing
+    /// body is generated at distances allowed by its template until it matches it, and replaces the bodies too close to its orbit.
+    /// Bodies that could not be made to match are left out.
+    pub(crate) fn place_bodies(
+        &self,
+        system_traits: &Vec<SystemPeculiarity>,
+        system_index: u16,
+        main_star_id: u32,
+        coord: SpaceCoordinates,
+        all_objects: &mut Vec<OrbitalPoint>,
+        settings: &GenerationSettings,
+    ) {
+        let mut placed_ids: Vec<u32> = Vec::new();
+        for body_template in self.get_unmatched_bodies(all_objects) {
+            let Some(star_point) = all_objects.iter().find(|point| point.id == main_star_id) else {
+                return;
+            };
+            let AstronomicalObject::Star(star) = &star_point.object else {
+                return;
+            };
+            let star = star.clone();
+            let star_distance = star
+                .orbit
+                .as_ref()
+                .map_or(0.0, |orbit| orbit.average_distance_from_system_center);
+            let body_id = all_objects.iter().map(|point| point.id).max().unwrap_or(0) + 1;
+            let populated_orbit_index = star_point
+                .orbits
+                .iter()
+                .filter(|orbit| orbit.id.is_some())
+                .count() as u32;
+
+            let placed = body_template
+                .get_candidate_distances(star_distance + f64::from(star.luminosity).sqrt())
+                .into_iter()
+                .flat_map(|distance| {
+                    (0..BODY_TRIES_PER_DISTANCE).map(move |body_try| (distance, body_try))
+                })
+                .find_map(|(distance, body_try)| {
+                    let distance_from_star = (distance - star_distance).max(0.01);
+                    let zone = star
+                        .zones
+                        .iter()
+                        .find(|zone| distance >= zone.start && distance <= zone.end)
+                        .map_or(ZoneType::OuterZone, |zone| zone.zone_type);
+                    // Body generation adds the distance of the orbited star from the center of the system to the body's own.
+                    let orbit = Orbit::new(
+                        main_star_id,
+                        Some(body_id),
+                        zone,
+                        distance_from_star,
+                        0.0,
+                        0.0,
+                        star_distance,
+                        0.0,
+                        0.0,
+                        0.0,
+                        0.0,
+                        0.0,
+                        f32::INFINITY,
+                    );
+                    let settings = GenerationSettings {
+                        seed: format!("{}{}", body_try, settings.seed).into(),
+                        ..settings.clone()
+                    };
+                    let generated = generate_template_body(
+                        &body_template,
+                        &star,
+                        main_star_id,
+                        body_id,
+                        populated_orbit_index,
+                        orbit,
+                        system_traits,
+                        system_index,
+                        coord,
+                        settings,
+                    );
+                    body_template.matches(&generated.0).then_some(generated)
+                });
+
+            if let Some((mut point, mut moons)) = placed {
+                point.update_object_own_orbit();
+                moons
+                    .iter_mut()
+                    .for_each(|moon| moon.update_object_own_orbit());
+                let distance_from_star = point
+                    .own_orbit
+                    .as_ref()
+                    .map_or(0.0, |orbit| orbit.average_distance);
+                make_room_for_body(main_star_id, distance_from_star, &placed_ids, all_objects);
+                if let Some(star_point) = all_objects
+                    .iter_mut()
+                    .find(|point| point.id == main_star_id)
+                {
+                    star_point
+                        .orbits
+                        .push(point.own_orbit.clone().unwrap_or_default());
+                    star_point.orbits.sort_by(|a, b| {
+                        a.average_distance
+                            .partial_cmp(&b.average_distance)
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    });
+                }
+                placed_ids.push(point.id);
+                all_objects.push(point);
+                all_objects.extend(moons);
+            }
+        }
+    }
+}
+
+impl BodyTemplate {
+    /// Returns the distances from the center of the system at which to try to place a body matching this template, the most likely
+    /// first. A body without any distance is placed around the given distance, where it gets as much light as the Earth.
+    fn get_candidate_distances(&self, earth_like_distance: f64) -> Vec<f64> {
+        match (self.min_distance, self.max_distance) {
+            (Some(min), Some(max)) => [0.5, 0.3, 0.7, 0.1, 0.9]
+                .iter()
+                .map(|part| min + (max - min) * part)
+                .collect(),
+            (Some(min), None) => [1.0, 1.2, 1.5, 2.0, 3.0]
+                .iter()
+                .map(|factor| min * factor)
+                .collect(),
+            (None, Some(max)) => [1.0, 0.8, 0.65, 0.5, 0.33]
+                .iter()
+                .map(|factor| max * factor)
+                .collect(),
+            (None, None) => [1.0, 0.9, 1.1, 0.8, 1.25]
+                .iter()
+                .map(|factor| earth_like_distance * factor)
+                .collect(),
+        }
+    }
+
+    /// Returns the composition a body matching this template is made of, or [None] for a gas giant. Volatiles giants are icy.
+    fn get_composition(&self) -> Option<TelluricBodyComposition> {
+        match self.world_type {
+            Some(
+                CelestialBodyWorldType::Ice
+                | CelestialBodyWorldType::Ocean
+                | CelestialBodyWorldType::VolatilesGiant,
+            ) => Some(TelluricBodyComposition::Icy),
+            None if matches!(
+                self.size,
+                Some(
+                    CelestialBodySize::Giant
+                        | CelestialBodySize::Supergiant
+                        | CelestialBodySize::Hypergiant
+                )
+            ) =>
+            {
+                None
+            }
+            _ => Some(TelluricBodyComposition::Rocky),
+        }
+    }
+
+    /// Returns the size a body matching this template must have, deduced from its world type if not given.
+    fn get_size(&self) -> Option<CelestialBodySize> {
+        self.size.or(match self.world_type {
+            Some(
+                CelestialBodyWorldType::Ocean
+                | CelestialBodyWorldType::Terrestrial
+                | CelestialBodyWorldType::Greenhouse
+                | CelestialBodyWorldType::Chthonian
+                | CelestialBodyWorldType::Ammonia,
+            ) => Some(CelestialBodySize::Standard),
+            Some(
+                CelestialBodyWorldType::Hadean
+                | CelestialBodyWorldType::Rock
+                | CelestialBodyWorldType::DirtySnowball
+                | CelestialBodyWorldType::Ice,
+            ) => Some(CelestialBodySize::Small),
+            Some(CelestialBodyWorldType::VolatilesGiant) => Some(CelestialBodySize::Giant),
+            _ => None,
+        })
+    }
+}
+
+/// Generates a body and its moons in the given orbit around the given star, following the given [BodyTemplate].
+fn generate_template_body(
+    body_template: &BodyTemplate,
+    star: &Star,
+    star_id: u32,
+    body_id: u32,
+    populated_orbit_index: u32,
+    orbit: Orbit,
+    system_traits: &Vec<SystemPeculiarity>,
+    system_index: u16,
+    coord: SpaceCoordinates,
+    settings: GenerationSettings,
+) -> (OrbitalPoint, Vec<OrbitalPoint>) {
+    let seed = settings.seed.clone();
+    let orbit_distance = orbit.average_distance;
+    let mut next_id = body_id + 1;
+    let Some(body_type) = body_template.get_composition() else {
+        return GaseousBodyDetails::generate_gas_giant(
+            body_id,
+            system_traits,
+            system_index,
+            star_id,
+            star.name.clone(),
+            star.age,
+            star.mass,
+            &star.spectral_type,
+            &star.luminosity_class,
+            star.luminosity,
+            star.metallicity,
+            &star.special_traits,
+            star.mass,
+            GasGiantArrangement::NoGasGiant,
+            orbit,
+            orbit_distance,
+            populated_orbit_index,
+            &mut next_id,
+            coord,
+            seed,
+            settings,
+        );
+    };
+    let (point, moons) = generate_body_from_type(
+        system_traits,
+        system_index,
+        star_id,
+        star.name.clone(),
+        star.age,
+        star.mass,
+        star.luminosity,
+        star.metallicity,
+        &star.spectral_type,
+        &star.luminosity_class,
+        &star.special_traits,
+        star.mass,
+        coord,
+        &seed,
+        &mut next_id,
+        GasGiantArrangement::NoGasGiant,
+        populated_orbit_index,
+        0,
+        body_type,
+        body_id,
+        Some(orbit),
+        orbit_distance,
+        Vec::new(),
+        settings.clone(),
+        false,
+        body_template.get_size(),
+    );
+    let point = match point.object.clone() {
+        AstronomicalObject::TelluricBody(body) if body.clone().is_stub() => {
+            WorldGenerator::generate_world(
+                coord,
+                system_traits,
+                system_index,
+                star_id,
+                star.age,
+                &star.spectral_type,
+                &star.luminosity_class,
+                &star.special_traits,
+                orbit_distance,
+                populated_orbit_index,
+                body_id,
+                point.own_orbit.clone().unwrap_or_default(),
+                point.orbits.clone(),
+                body,
+                false,
+                &moons,
+                0,
+                seed,
+                settings,
+            )
+        }
+        _ => point,
+    };
+    (point, moons)
+}
+
+/// Removes the objects orbiting the given star too close to the given distance from it for a new body to fit in between, along with
+/// their moons. The bodies already placed from a template are kept.
+fn make_room_for_body(
+    star_id: u32,
+    distance_from_star: f64,
+    kept_ids: &[u32],
+    all_objects: &mut Vec<OrbitalPoint>,
+) {
+    let removed_ids: Vec<u32> = all_objects
+        .iter()
+        .filter(|point| !kept_ids.contains(&point.id))
+        .filter(|point| {
+            point.own_orbit.as_ref().is_some_and(|orbit| {
+                orbit.primary_body_id == star_id
+                    && !matches!(point.object, AstronomicalObject::Star(_))
+                    && orbit.average_distance > distance_from_star / 1.4
+                    && orbit.average_distance < distance_from_star * 1.4
+            })
+        })
+        .map(|point| point.id)
+        .collect();
+    all_objects.retain(|point| {
+        !removed_ids.contains(&point.id)
+            && !point
+                .own_orbit
+                .as_ref()
+                .is_some_and(|orbit| removed_ids.contains(&orbit.primary_body_id))
+    });
+    if let Some(star_point) = all_objects.iter_mut().find(|point| point.id == star_id) {
+        star_point
+            .orbits
+            .retain(|orbit| orbit.id.map_or(true, |id| !removed_ids.contains(&id)));
+    }
+}
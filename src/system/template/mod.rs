@@ -0,0 +1,140 @@
+use crate::internal::*;
+use crate::prelude::*;
+pub mod generator;
+pub mod types;
+
+/// A partial description of a [StarSystem]. Systems generated from a template match everything it describes, while whatever it leaves
+/// out is generated as usual.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct SystemTemplate {
+    /// The name of the system, generated if [None].
+    pub name: Option<Rc<str>>,
+    /// The number of stars in the system, generated if [None].
+    pub number_of_stars: Option<u16>,
+    /// The spectral type of the system's main star, only its letter being checked, so that [StarSpectralType::K] with any number
+    /// matches every K-type star.
+    pub main_star_spectral_type: Option<StarSpectralType>,
+    /// The luminosity class of the system's main star.
+    pub main_star_luminosity_class: Option<StarLuminosityClass>,
+    /// The bodies the system must contain, each of them matched by a different body of the system.
+    pub bodies: Vec<BodyTemplate>,
+    /// The minimum number of gas giants in the system.
+    pub min_gas_giants: Option<u16>,
+    /// The maximum number of gas giants in the system.
+    pub max_gas_giants: Option<u16>,
+    /// The peculiarities the system must have, only their kind being checked, so that [SystemPeculiarity::Nebulae] with any apparent
+    /// size matches every system within a nebula.
+    pub special_traits: Vec<SystemPeculiarity>,
+}
+
+impl Display for SystemTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} system template with {} stars, a main star of type {}{} and {} required bodies",
+            self.name.as_deref().unwrap_or("Unnamed"),
+            self.number_of_stars
+                .map_or("any number of".to_string(), |n| n.to_string()),
+            self.main_star_spectral_type
+                .map_or("any".to_string(), |t| t.to_string()),
+            self.main_star_luminosity_class
+                .map_or(String::new(), |c| format!(" {}", c)),
+            self.bodies.len()
+        )
+    }
+}
+
+impl SystemTemplate {
+    /// Returns true if the given stars, before being arranged into a system, match this template. The most massive one will be the
+    /// system's main star.
+    pub fn matches_stars(&self, stars: &[Star]) -> bool {
+        if self
+            .number_of_stars
+            .is_some_and(|number| number as usize != stars.len())
+        {
+            return false;
+        }
+        stars
+            .iter()
+            .max_by(|a, b| {
+                a.mass
+                    .partial_cmp(&b.mass)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .is_some_and(|star| self.matches_main_star(star))
+    }
+
+    /// Returns true if the given [StarSystem] matches this template.
+    pub fn matches(&self, system: &StarSystem) -> bool {
+        let stars = system
+            .all_objects
+            .iter()
+            .filter(|point| matches!(point.object, AstronomicalObject::Star(_)))
+            .count();
+        if self
+            .number_of_stars
+            .is_some_and(|number| number as usize != stars)
+        {
+            return false;
+        }
+        if let AstronomicalObject::Star(star) = &system.get_main_star().object {
+            if !self.matches_main_star(star) {
+                return false;
+            }
+        }
+
+        let gas_giants = system
+            .all_objects
+            .iter()
+            .filter(|point| matches!(point.object, AstronomicalObject::GaseousBody(_)))
+            .count();
+        if self
+            .min_gas_giants
+            .is_some_and(|min| gas_giants < min as usize)
+            || self
+                .max_gas_giants
+                .is_some_and(|max| gas_giants > max as usize)
+        {
+            return false;
+        }
+        if !self.special_traits.iter().all(|wanted| {
+            system
+                .special_traits
+                .iter()
+                .any(|special_trait| discriminant(special_trait) == discriminant(wanted))
+        }) {
+            return false;
+        }
+
+        self.get_unmatched_bodies(&system.all_objects).is_empty()
+    }
+
+    /// Returns the bodies of this template that are not matched by the given objects, each object matching at most one body.
+    pub(crate) fn get_unmatched_bodies(&self, all_objects: &[OrbitalPoint]) -> Vec<BodyTemplate> {
+        let mut used_points: Vec<u32> = Vec::new();
+        self.bodies
+            .iter()
+            .filter(|body_template| {
+                if let Some(point) = all_objects
+                    .iter()
+                    .find(|point| !used_points.contains(&point.id) && body_template.matches(point))
+                {
+                    used_points.push(point.id);
+                    false
+                } else {
+                    true
+                }
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Returns true if the given star can be the main star of a system matching this template.
+    fn matches_main_star(&self, star: &Star) -> bool {
+        self.main_star_spectral_type.map_or(true, |t| {
+            discriminant(&t) == discriminant(&star.spectral_type)
+        }) && self
+            .main_star_luminosity_class
+            .map_or(true, |c| c == star.luminosity_class)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::internal::*;
+use crate::prelude::*;
+
+/// A partial description of a body that must be part of a system generated from a [SystemTemplate].
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct BodyTemplate {
+    /// The type of the world, for telluric and icy bodies.
+    pub world_type: Option<CelestialBodyWorldType>,
+    /// The size of the body.
+    pub size: Option<CelestialBodySize>,
+    /// The minimum distance between the body and the center of the system, in AU.
+    pub min_distance: Option<f64>,
+    /// The maximum distance between the body and the center of the system, in AU.
+    pub max_distance: Option<f64>,
+}
+
+impl BodyTemplate {
+    /// Returns true if the body found at the given [OrbitalPoint] matches this template. Moons are as far from the center of the
+    /// system as the planet they orbit.
+    pub fn matches(&self, point: &OrbitalPoint) -> bool {
+        let (AstronomicalObject::TelluricBody(body)
+        | AstronomicalObject::IcyBody(body)
+        | AstronomicalObject::GaseousBody(body)) = &point.object
+        else {
+            return false;
+        };
+        let world_type = match &body.details {
+            CelestialBodyDetails::Telluric(details) => Some(details.world_type),
+            CelestialBodyDetails::Icy(details) => Some(details.world_type),
+            _ => None,
+        };
+        let distance = point
+            .own_orbit
+            .as_ref()
+            .map_or(0.0, |orbit| orbit.average_distance_from_system_center);
+
+        self.world_type.map_or(true, |t| Some(t) == world_type)
+            && self.size.map_or(true, |s| s == body.size)
+            && self.min_distance.map_or(true, |min| distance >= min)
+            && self.max_distance.map_or(true, |max| distance <= max)
+    }
+}
+
+/// A star system pinned at specific coordinates of a [Galaxy], see [Galaxy::pin_system].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum PinnedSystem {
+    /// A system that will be placed exactly as written.
+    System(StarSystem),
+    /// A partial description of a system that will be completed by the generator.
+    Template(SystemTemplate),
+}
+
+impl Display for PinnedSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PinnedSystem::System(system) => write!(f, "Pinned system {}", system.name),
+            PinnedSystem::Template(template) => write!(f, "Pinned {}", template),
+        }
+    }
+}
@@ -2,12 +2,24 @@ use crate::internal::*;
 use crate::prelude::*;
 
 /// A list of settings used to configure the [StarSystem] generation.
-#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, SmartDefault, Serialize, Deserialize)]
 pub struct SystemSettings {
-    /// Skip the system generation and just uses a copy of ours.
+    /// Places a copy of our own solar system at the home coordinates of the first galaxy, if it matches the filters, while every other
+    /// system is generated as usual. See [Galaxy::get_home_coordinates].
     pub use_ours: bool,
-    /// Makes sure that only interesting systems are generated.
+    /// Makes sure that only interesting systems, the ones with at least one Terrestrial or Ocean world, are generated.
     pub only_interesting: bool,
+    /// A description every generated system must match, if any.
+    #[serde(default)]
+    pub filter: Option<SystemTemplate>,
+    /// How many times a system is generated again before giving up when it is not accepted by the filters.
+    #[default(5000)]
+    #[serde(default = "default_max_tries")]
+    pub max_tries: u32,
+}
+
+fn default_max_tries() -> u32 {
+    SystemSettings::default().max_tries
 }
 
 /// The population of stars in this system.
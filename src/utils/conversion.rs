@@ -15,6 +15,11 @@ impl ConversionUtils {
         radius * 6371.0 / 149597870.7
     }
 
+    /// Converts a value from Earth radii to kilometers.
+    pub fn earth_radii_to_kilometers(radius: f64) -> f64 {
+        radius * 6371.0
+    }
+
     /// Converts a value from Astronomical units to Earth radii.
     pub fn astronomical_units_to_earth_radii(au: f64) -> f64 {
         au * 23454.706481336
@@ -62,6 +67,13 @@ mod tests {
         assert!((au - (6371.0 / 149597870.7)).abs() < EPSILON);
     }
 
+    #[test]
+    fn test_earth_radii_to_kilometers() {
+        let earth_radii = 2.0;
+        let km = ConversionUtils::earth_radii_to_kilometers(earth_radii);
+        assert!((km - 12742.0).abs() < EPSILON);
+    }
+
     #[test]
     fn test_astronomical_units_to_solar_radii() {
         let au = 1.0; // 1 AU